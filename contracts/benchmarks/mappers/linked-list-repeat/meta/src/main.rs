@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<linked_list_repeat::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<linked_list_repeat::AbiProvider, _>(
+        linked_list_repeat::ContractBuilder,
+    );
 }
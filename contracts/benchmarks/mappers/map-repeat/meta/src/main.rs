@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<map_repeat::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<map_repeat::AbiProvider, _>(
+        map_repeat::ContractBuilder,
+    );
 }
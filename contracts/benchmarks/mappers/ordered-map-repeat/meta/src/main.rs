@@ -0,0 +1,5 @@
+fn main() {
+    elrond_wasm_debug::meta::perform_with_contract::<ordered_map_repeat::AbiProvider, _>(
+        ordered_map_repeat::ContractBuilder,
+    );
+}
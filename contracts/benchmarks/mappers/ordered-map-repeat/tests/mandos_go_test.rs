@@ -0,0 +1,4 @@
+#[test]
+fn ordered_map_repeat_go() {
+    elrond_wasm_debug::mandos_go("mandos/ordered_map_repeat.scen.json");
+}
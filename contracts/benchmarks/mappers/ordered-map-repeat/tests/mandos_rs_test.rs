@@ -0,0 +1,22 @@
+use elrond_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/benchmarks/mappers/ordered-map-repeat");
+
+    blockchain.register_contract(
+        "file:output/ordered-map-repeat.wasm",
+        ordered_map_repeat::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn ordered_map_repeat_struct_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/ordered_map_repeat_struct.scen.json", world());
+}
+
+#[test]
+fn ordered_map_repeat_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/ordered_map_repeat.scen.json", world());
+}
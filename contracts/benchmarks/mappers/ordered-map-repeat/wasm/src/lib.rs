@@ -0,0 +1,29 @@
+// Code generated by the elrond-wasm multi-contract system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Endpoints:                            9
+// Async Callback (empty):               1
+// Total number of exported functions:  11
+
+#![no_std]
+
+elrond_wasm_node::wasm_endpoints! {
+    ordered_map_repeat
+    (
+        add
+        count
+        count_range
+        remove
+        bench
+        add_struct
+        count_struct
+        remove_struct
+        bench_struct
+    )
+}
+
+elrond_wasm_node::wasm_empty_callback! {}
@@ -0,0 +1,5 @@
+fn main() {
+    elrond_wasm_debug::meta::perform_with_contract::<priority_queue_repeat::AbiProvider, _>(
+        priority_queue_repeat::ContractBuilder,
+    );
+}
@@ -0,0 +1,65 @@
+#![no_std]
+
+use benchmark_common::ExampleStruct;
+
+elrond_wasm::imports!();
+
+#[elrond_wasm::contract]
+pub trait PriorityQueueRepeat: benchmark_common::BenchmarkCommon {
+    #[init]
+    fn init(&self) {}
+
+    /// Priorities are increasing, so each new value moves all the way to the top of the heap.
+    #[endpoint]
+    fn add(&self, num_repeats: usize, value: ManagedBuffer) {
+        let mut bench = self.bench();
+        for i in 0..num_repeats {
+            bench.push(i as u64, self.append_index(&value, i));
+        }
+    }
+
+    #[endpoint]
+    fn count(&self, value: ManagedBuffer) -> usize {
+        self.bench().iter().filter(|(_, v)| *v == value).count()
+    }
+
+    #[endpoint]
+    fn remove(&self, num_repeats: usize) {
+        let mut bench = self.bench();
+        for _ in 0..num_repeats {
+            bench.pop();
+        }
+    }
+
+    #[view]
+    #[storage_mapper("benchmark")]
+    fn bench(&self) -> PriorityQueueMapper<u64, ManagedBuffer>;
+
+    #[endpoint]
+    fn add_struct(&self, num_repeats: usize, value: ExampleStruct<Self::Api>) {
+        let mut bench = self.bench_struct();
+        for i in 0..num_repeats {
+            bench.push(i as u64, self.use_index_struct(&value, i));
+        }
+    }
+
+    #[endpoint]
+    fn count_struct(&self, value: ExampleStruct<Self::Api>) -> usize {
+        self.bench_struct()
+            .iter()
+            .filter(|(_, v)| *v == value)
+            .count()
+    }
+
+    #[endpoint]
+    fn remove_struct(&self, num_repeats: usize) {
+        let mut bench = self.bench_struct();
+        for _ in 0..num_repeats {
+            bench.pop();
+        }
+    }
+
+    #[view]
+    #[storage_mapper("bench_struct")]
+    fn bench_struct(&self) -> PriorityQueueMapper<u64, ExampleStruct<Self::Api>>;
+}
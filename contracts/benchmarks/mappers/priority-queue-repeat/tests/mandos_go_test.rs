@@ -0,0 +1,4 @@
+#[test]
+fn priority_queue_repeat_go() {
+    elrond_wasm_debug::mandos_go("mandos/priority_queue_repeat.scen.json");
+}
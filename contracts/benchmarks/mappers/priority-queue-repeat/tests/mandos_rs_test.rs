@@ -0,0 +1,22 @@
+use elrond_wasm_debug::*;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/benchmarks/mappers/priority-queue-repeat");
+
+    blockchain.register_contract(
+        "file:output/priority-queue-repeat.wasm",
+        priority_queue_repeat::ContractBuilder,
+    );
+    blockchain
+}
+
+#[test]
+fn priority_queue_repeat_struct_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/priority_queue_repeat_struct.scen.json", world());
+}
+
+#[test]
+fn priority_queue_repeat_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/priority_queue_repeat.scen.json", world());
+}
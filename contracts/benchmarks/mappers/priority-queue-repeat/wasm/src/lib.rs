@@ -0,0 +1,28 @@
+// Code generated by the elrond-wasm multi-contract system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Endpoints:                            8
+// Async Callback (empty):               1
+// Total number of exported functions:  10
+
+#![no_std]
+
+elrond_wasm_node::wasm_endpoints! {
+    priority_queue_repeat
+    (
+        add
+        count
+        remove
+        bench
+        add_struct
+        count_struct
+        remove_struct
+        bench_struct
+    )
+}
+
+elrond_wasm_node::wasm_empty_callback! {}
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<queue_repeat::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<queue_repeat::AbiProvider, _>(
+        queue_repeat::ContractBuilder,
+    );
 }
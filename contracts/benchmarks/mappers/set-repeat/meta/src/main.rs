@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<set_repeat::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<set_repeat::AbiProvider, _>(
+        set_repeat::ContractBuilder,
+    );
 }
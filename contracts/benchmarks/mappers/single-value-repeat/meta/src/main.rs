@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<single_value_repeat::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<single_value_repeat::AbiProvider, _>(
+        single_value_repeat::ContractBuilder,
+    );
 }
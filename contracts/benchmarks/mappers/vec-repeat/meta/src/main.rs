@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<vec_repeat::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<vec_repeat::AbiProvider, _>(
+        vec_repeat::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<send_tx_repeat::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<send_tx_repeat::AbiProvider, _>(
+        send_tx_repeat::ContractBuilder,
+    );
 }
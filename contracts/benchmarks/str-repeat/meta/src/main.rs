@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<str_repeat::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<str_repeat::AbiProvider, _>(
+        str_repeat::ContractBuilder,
+    );
 }
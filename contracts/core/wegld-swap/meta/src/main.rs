@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<elrond_sc_wegld_swap::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<elrond_sc_wegld_swap::AbiProvider, _>(
+        elrond_sc_wegld_swap::ContractBuilder,
+    );
 }
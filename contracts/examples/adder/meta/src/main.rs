@@ -1,3 +1,3 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<adder::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<adder::AbiProvider, _>(adder::ContractBuilder);
 }
@@ -0,0 +1,99 @@
+use elrond_wasm_debug::{mandos_system::model::*, world_mock::GasSchedule, *};
+
+const OWNER_ADDRESS_EXPR: &str = "address:owner";
+const ADDER_ADDRESS_EXPR: &str = "sc:adder";
+const ADDER_PATH_EXPR: &str = "file:output/adder.wasm";
+
+/// Same layout as the node gas schedule files, irrelevant sections are ignored.
+const GAS_SCHEDULE_TOML: &str = r#"
+[BaseOperationCost]
+    StorePerByte = 10
+    DataCopyPerByte = 1
+    CompilePerByte = 300
+
+[BaseOpsAPICost]
+    StorageLoad = 100
+    StorageStore = 500
+    TransferValue = 1000
+
+[BigIntAPICost]
+    BigIntAdd = 50
+
+[WASMOpcodeCost]
+    Unreachable = 5
+"#;
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/adder");
+
+    blockchain.register_contract(ADDER_PATH_EXPR, adder::ContractBuilder);
+    blockchain.set_gas_schedule(GasSchedule::from_toml_str(GAS_SCHEDULE_TOML));
+    blockchain
+}
+
+fn deploy(world: &mut BlockchainMock) {
+    let ic = world.interpreter_context();
+    world
+        .mandos_set_state(
+            SetStateStep::new()
+                .put_account(OWNER_ADDRESS_EXPR, Account::new().nonce(1))
+                .new_address(OWNER_ADDRESS_EXPR, 1, ADDER_ADDRESS_EXPR),
+        )
+        .mandos_sc_deploy(
+            ScDeployStep::new()
+                .from(OWNER_ADDRESS_EXPR)
+                .contract_code(ADDER_PATH_EXPR, &ic)
+                .argument("5")
+                .gas_limit("5,000,000")
+                .expect(TxExpect::ok().no_result().gas("4,999,490")),
+        );
+}
+
+#[test]
+fn adder_gas_schedule_parse_test() {
+    let gas_schedule = GasSchedule::from_toml_str(GAS_SCHEDULE_TOML);
+    assert_eq!(gas_schedule.base_operation_cost.store_per_byte, 10);
+    assert_eq!(gas_schedule.base_ops_api_cost.storage_load, 100);
+    assert_eq!(gas_schedule.base_ops_api_cost.create_async_call, 0);
+    assert_eq!(gas_schedule.big_int_api_cost.big_int_add, 50);
+    assert_eq!(gas_schedule.storage_store_cost(3), 530);
+}
+
+#[test]
+fn adder_gas_used_test() {
+    let mut world = world();
+    deploy(&mut world);
+
+    // storage load (100 + 1) + addition (50) + storage store (500 + 10)
+    world.mandos_sc_call(
+        ScCallStep::new()
+            .from(OWNER_ADDRESS_EXPR)
+            .to(ADDER_ADDRESS_EXPR)
+            .function("add")
+            .argument("3")
+            .gas_limit("1,000")
+            .expect(TxExpect::ok().no_result().gas("339")),
+    );
+}
+
+#[test]
+fn adder_out_of_gas_test() {
+    let mut world = world();
+    deploy(&mut world);
+
+    world
+        .mandos_sc_call(
+            ScCallStep::new()
+                .from(OWNER_ADDRESS_EXPR)
+                .to(ADDER_ADDRESS_EXPR)
+                .function("add")
+                .argument("3")
+                .gas_limit("600")
+                .expect(TxExpect::err(5, "str:not enough gas").gas("0")),
+        )
+        .mandos_check_state(CheckStateStep::new().put_account(
+            ADDER_ADDRESS_EXPR,
+            CheckAccount::new().check_storage("str:sum", "5"),
+        ));
+}
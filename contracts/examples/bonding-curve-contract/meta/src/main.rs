@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<bonding_curve_contract::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<bonding_curve_contract::AbiProvider, _>(
+        bonding_curve_contract::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<crowdfunding_esdt::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<crowdfunding_esdt::AbiProvider, _>(
+        crowdfunding_esdt::ContractBuilder,
+    );
 }
@@ -0,0 +1,65 @@
+use std::path::{Path, PathBuf};
+
+use elrond_wasm_debug::*;
+
+const CROWDFUNDING_PATH_EXPR: &str = "file:output/crowdfunding-esdt.wasm";
+
+fn world(report_dir: &Path) -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/crowdfunding-esdt");
+
+    blockchain.register_contract(CROWDFUNDING_PATH_EXPR, crowdfunding_esdt::ContractBuilder);
+    blockchain
+        .enable_coverage(report_dir)
+        .register_coverage_abi::<crowdfunding_esdt::AbiProvider>(CROWDFUNDING_PATH_EXPR);
+    blockchain
+}
+
+fn hits(report: &serde_json::Value, section: &str, name: &str) -> Option<u64> {
+    report[section]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|entry| entry["name"] == name)
+        .map(|entry| entry["hits"].as_u64().unwrap())
+}
+
+#[test]
+fn crowdfunding_coverage_test() {
+    let report_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("crowdfunding-coverage");
+    let _ = std::fs::remove_dir_all(&report_dir);
+
+    // both scenarios contribute to the same report
+    elrond_wasm_debug::mandos_rs(
+        "mandos/crowdfunding-claim-too-early.scen.json",
+        world(&report_dir),
+    );
+    elrond_wasm_debug::mandos_rs(
+        "mandos/crowdfunding-fund-too-late.scen.json",
+        world(&report_dir),
+    );
+
+    let report_json =
+        std::fs::read_to_string(report_dir.join("crowdfunding-esdt.coverage.json")).unwrap();
+    let report: serde_json::Value = serde_json::from_str(report_json.as_str()).unwrap();
+
+    assert_eq!(report["contract"], "crowdfunding-esdt");
+    assert_eq!(hits(&report, "endpoints", "init"), Some(2));
+    assert_eq!(hits(&report, "endpoints", "claim"), Some(2));
+    assert_eq!(hits(&report, "endpoints", "fund"), Some(3));
+    assert_eq!(hits(&report, "endpoints", "getTarget"), Some(0));
+    assert_eq!(
+        hits(&report, "errors", "cannot claim before deadline"),
+        Some(2)
+    );
+    assert_eq!(
+        hits(&report, "errors", "cannot fund after deadline"),
+        Some(1)
+    );
+    assert!(report["callbacks"].as_array().unwrap().is_empty());
+    assert!(report["events"].as_array().unwrap().is_empty());
+
+    let report_html =
+        std::fs::read_to_string(report_dir.join("crowdfunding-esdt.coverage.html")).unwrap();
+    assert!(report_html.contains("<td>cannot fund after deadline</td><td>1</td>"));
+}
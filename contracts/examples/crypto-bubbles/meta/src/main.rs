@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<crypto_bubbles::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<crypto_bubbles::AbiProvider, _>(
+        crypto_bubbles::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<kitty_auction::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<kitty_auction::AbiProvider, _>(
+        kitty_auction::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<kitty_genetic_alg::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<kitty_genetic_alg::AbiProvider, _>(
+        kitty_genetic_alg::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<kitty_ownership::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<kitty_ownership::AbiProvider, _>(
+        kitty_ownership::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<digital_cash::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<digital_cash::AbiProvider, _>(
+        digital_cash::ContractBuilder,
+    );
 }
@@ -1,3 +1,3 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<empty::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<empty::AbiProvider, _>(empty::ContractBuilder);
 }
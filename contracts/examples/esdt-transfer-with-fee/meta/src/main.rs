@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<esdt_transfer_with_fee::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<esdt_transfer_with_fee::AbiProvider, _>(
+        esdt_transfer_with_fee::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<factorial::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<factorial::AbiProvider, _>(
+        factorial::ContractBuilder,
+    );
 }
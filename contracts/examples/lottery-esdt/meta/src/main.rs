@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<lottery_esdt::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<lottery_esdt::AbiProvider, _>(
+        lottery_esdt::ContractBuilder,
+    );
 }
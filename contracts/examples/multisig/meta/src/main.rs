@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<multisig::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<multisig::AbiProvider, _>(
+        multisig::ContractBuilder,
+    );
 }
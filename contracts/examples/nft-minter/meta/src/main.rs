@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<nft_minter::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<nft_minter::AbiProvider, _>(
+        nft_minter::ContractBuilder,
+    );
 }
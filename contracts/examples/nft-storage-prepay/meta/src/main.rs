@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<nft_storage_prepay::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<nft_storage_prepay::AbiProvider, _>(
+        nft_storage_prepay::ContractBuilder,
+    );
 }
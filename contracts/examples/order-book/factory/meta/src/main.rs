@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<order_book_factory::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<order_book_factory::AbiProvider, _>(
+        order_book_factory::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<order_book_pair::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<order_book_pair::AbiProvider, _>(
+        order_book_pair::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<ping_pong_egld::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<ping_pong_egld::AbiProvider, _>(
+        ping_pong_egld::ContractBuilder,
+    );
 }
@@ -1,3 +1,11 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<proxy_pause::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract_and_world_setup::<proxy_pause::AbiProvider, _, _>(
+        proxy_pause::ContractBuilder,
+        |world| {
+            world.register_contract(
+                "file:../../feature-tests/use-module/output/use-module.wasm",
+                use_module::ContractBuilder,
+            );
+        },
+    );
 }
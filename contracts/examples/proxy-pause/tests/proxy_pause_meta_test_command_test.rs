@@ -0,0 +1,56 @@
+use std::path::PathBuf;
+
+use elrond_wasm::contract_base::ContractAbiProvider;
+use elrond_wasm_debug::{
+    meta::{output_contract::OutputContractConfig, *},
+    *,
+};
+
+const PAUSE_SCENARIO: &str = "mandos/pause-and-unpause.scen.json";
+
+fn contract_dir() -> PathBuf {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/examples/proxy-pause");
+    blockchain.current_dir
+}
+
+fn output_contracts_world_factory(register_use_module: bool) -> impl Fn() -> BlockchainMock {
+    move || {
+        let abi = proxy_pause::AbiProvider::abi();
+        let mut world = output_contracts_world(
+            contract_dir(),
+            &OutputContractConfig::default_config(&abi),
+            &BuildArgs::default(),
+            &proxy_pause::ContractBuilder,
+        );
+        if register_use_module {
+            world.register_contract(
+                "file:../../feature-tests/use-module/output/use-module.wasm",
+                use_module::ContractBuilder,
+            );
+        }
+        world
+    }
+}
+
+/// The scenario deploys another contract, which needs to be registered besides the output contracts.
+#[test]
+fn multi_contract_scenario_test() {
+    let scenario_paths = vec![PathBuf::from(PAUSE_SCENARIO)];
+
+    let results = run_scenarios(
+        contract_dir().as_path(),
+        scenario_paths.as_slice(),
+        Some(&output_contracts_world_factory(true)),
+        false,
+    );
+    assert_eq!(results[0].rs, ScenarioOutcome::Passed);
+
+    let results = run_scenarios(
+        contract_dir().as_path(),
+        scenario_paths.as_slice(),
+        Some(&output_contracts_world_factory(false)),
+        false,
+    );
+    assert!(results[0].rs.is_failed());
+}
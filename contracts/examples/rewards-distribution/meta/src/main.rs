@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<rewards_distribution::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<rewards_distribution::AbiProvider, _>(
+        rewards_distribution::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<seed_nft_minter::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<seed_nft_minter::AbiProvider, _>(
+        seed_nft_minter::ContractBuilder,
+    );
 }
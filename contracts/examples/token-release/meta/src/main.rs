@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<token_release::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<token_release::AbiProvider, _>(
+        token_release::ContractBuilder,
+    );
 }
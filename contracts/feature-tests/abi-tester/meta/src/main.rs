@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<abi_tester::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<abi_tester::AbiProvider, _>(
+        abi_tester::ContractBuilder,
+    );
 }
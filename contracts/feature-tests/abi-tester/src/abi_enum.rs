@@ -9,3 +9,23 @@ pub enum AbiEnum {
     SomethingMore(u8, OnlyShowsUpAsNested08),
     SomeStruct { a: u16, b: OnlyShowsUpAsNested09 },
 }
+
+/// Tests that explicit discriminants are used for encoding and show up in the ABI.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+pub enum ExplicitDiscriminant {
+    Zero,
+    Thirty = 30,
+    ThirtyOne,
+    Fifty = 50,
+}
+
+/// Explicit discriminants on data-carrying enums require a primitive representation.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+#[repr(u8)]
+pub enum ExplicitDiscriminantMixed {
+    Zero,
+    Unit = 3,
+    Tuple(u16),
+    Five = 5,
+    Struct { a: u8, b: i32 } = 1,
+}
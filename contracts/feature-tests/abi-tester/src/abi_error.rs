@@ -0,0 +1,12 @@
+elrond_wasm::derive_imports!();
+
+/// Errors declared here show up in the ABI.
+#[derive(ContractError, Clone, Copy)]
+pub enum AbiTesterError {
+    /// Error docs.
+    #[error(code = 1, message = "Something went wrong")]
+    Generic,
+
+    #[error(code = 100, message = "Value {} is out of range")]
+    OutOfRange,
+}
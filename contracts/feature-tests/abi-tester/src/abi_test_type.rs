@@ -25,6 +25,20 @@ pub struct AbiManagedType<M: ManagedTypeApi> {
     pub big_uint: BigUint<M>,
     pub integer: i32,
     pub managed_buffer: ManagedBuffer<M>,
+
+    /// Not serialized, so it should not appear in the ABI.
+    #[codec(skip)]
+    pub skipped: u64,
+}
+
+/// Tests that the struct version and the versions in which fields were added show up in the ABI.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, TypeAbi)]
+#[codec(version = 2)]
+pub struct AbiVersionedType {
+    pub value: u32,
+
+    #[codec(since = 2)]
+    pub added_later: bool,
 }
 
 /// Its only purpose is to test that the ABI generator works fine.
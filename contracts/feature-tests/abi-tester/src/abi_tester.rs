@@ -3,19 +3,24 @@
 elrond_wasm::imports!();
 
 mod abi_enum;
+mod abi_error;
 mod abi_test_type;
 mod only_nested;
 
 use abi_enum::*;
+use abi_error::*;
 use abi_test_type::*;
 use only_nested::*;
 
+pub type SampleId = u64;
+
 /// Contract whose sole purpose is to verify that
 /// the ABI generation framework works sa expected.
 ///
 /// Note: any change in this contract must also be reflected in `abi_test_expected.abi.json`,
 /// including Rust docs.
 #[elrond_wasm::contract]
+#[errors(AbiTesterError)]
 pub trait AbiTester {
     /// Contract constructor.
     #[init]
@@ -36,6 +41,30 @@ pub trait AbiTester {
         e
     }
 
+    #[endpoint]
+    fn echo_explicit_discriminant(&self, e: ExplicitDiscriminant) -> ExplicitDiscriminant {
+        e
+    }
+
+    #[endpoint]
+    fn echo_explicit_discriminant_mixed(
+        &self,
+        e: ExplicitDiscriminantMixed,
+    ) -> ExplicitDiscriminantMixed {
+        e
+    }
+
+    #[endpoint]
+    fn echo_versioned_type(&self, v: AbiVersionedType) -> AbiVersionedType {
+        v
+    }
+
+    #[endpoint]
+    fn check_range(&self, value: u32) {
+        require!(value != 0, AbiTesterError::Generic);
+        require!(value <= 10, AbiTesterError::OutOfRange, value);
+    }
+
     #[endpoint]
     #[only_owner]
     fn take_managed_type(&self, _arg: AbiManagedType<Self::Api>) {}
@@ -111,6 +140,26 @@ pub trait AbiTester {
     #[storage_mapper("sample_storage_mapper")]
     fn sample_storage_mapper(&self) -> SingleValueMapper<OnlyShowsUpAsNestedInSingleValueMapper>;
 
+    /// Storage entries appear in the ABI, even when not exposed as views.
+    #[storage_mapper("sample_map_mapper")]
+    fn sample_map_mapper(
+        &self,
+        key_arg: u32,
+    ) -> MapMapper<OnlyShowsUpInStorageKey, OnlyShowsUpInStorageValue>;
+
+    /// Type aliases show up as the type they stand for.
+    #[storage_mapper("sample_alias_mapper")]
+    fn sample_alias_mapper(&self, id: SampleId) -> SingleValueMapper<SampleId>;
+
+    #[storage_mapper("sample_opaque_mapper")]
+    fn sample_opaque_mapper(&self) -> VecMapper<StorageValueWithoutAbi>;
+
+    #[storage_get("sample_storage_get_set")]
+    fn sample_storage_get(&self, address: &ManagedAddress) -> BigUint;
+
+    #[storage_set("sample_storage_get_set")]
+    fn sample_storage_set(&self, address: &ManagedAddress, value: &BigUint);
+
     #[view]
     fn item_for_vec(&self) -> Vec<OnlyShowsUpAsNestedInVec> {
         Vec::new()
@@ -150,6 +199,15 @@ pub trait AbiTester {
         None
     }
 
+    /// Decimal types are described as the number types they are encoded as.
+    #[view]
+    fn decimal_types(
+        &self,
+        _price: ManagedDecimal<Self::Api, 2>,
+    ) -> ManagedDecimalSigned<Self::Api, 18> {
+        ManagedDecimalSigned::zero()
+    }
+
     #[endpoint]
     #[payable("EGLD")]
     fn payable_egld(&self) {}
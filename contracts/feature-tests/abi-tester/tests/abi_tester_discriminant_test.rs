@@ -0,0 +1,48 @@
+use elrond_wasm::{
+    abi::{TypeAbi, TypeContents, TypeDescriptionContainerImpl},
+    elrond_codec::test_util::dep_encode_to_vec_or_panic,
+};
+
+elrond_wasm::derive_imports!();
+
+#[derive(NestedEncode, NestedDecode, TypeAbi)]
+#[repr(u8)]
+enum Discriminants {
+    Zero,
+    Three = 3,
+    Four(u16),
+    Ten = 10,
+    Eleven { a: u8 },
+    One = 1,
+    Two,
+}
+
+/// The ABI discriminants come from a copy of the codec derive logic, so they are checked against the encoding.
+#[test]
+fn abi_discriminants_match_encoding_test() {
+    let mut type_descriptions = TypeDescriptionContainerImpl::default();
+    Discriminants::provide_type_descriptions(&mut type_descriptions);
+    let variants = match &type_descriptions.0[&Discriminants::type_name()].contents {
+        TypeContents::Enum(variants) => variants,
+        _ => panic!("enum expected"),
+    };
+
+    let values = [
+        Discriminants::Zero,
+        Discriminants::Three,
+        Discriminants::Four(5),
+        Discriminants::Ten,
+        Discriminants::Eleven { a: 5 },
+        Discriminants::One,
+        Discriminants::Two,
+    ];
+    assert_eq!(variants.len(), values.len());
+    for (variant, value) in variants.iter().zip(values.iter()) {
+        let encoded = dep_encode_to_vec_or_panic(value);
+        assert_eq!(
+            variant.discriminant, encoded[0] as usize,
+            "discriminant mismatch for variant {}",
+            variant.name
+        );
+    }
+}
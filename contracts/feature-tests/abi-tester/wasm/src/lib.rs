@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           27
+// Endpoints:                           32
 // Async Callback (empty):               1
-// Total number of exported functions:  29
+// Total number of exported functions:  34
 
 #![no_std]
 
@@ -16,6 +16,10 @@ elrond_wasm_node::wasm_endpoints! {
     (
         echo_abi_test_type
         echo_enum
+        echo_explicit_discriminant
+        echo_explicit_discriminant_mixed
+        echo_versioned_type
+        check_range
         take_managed_type
         multi_result_3
         multi_result_4
@@ -38,6 +42,7 @@ elrond_wasm_node::wasm_endpoints! {
         item_for_ref
         item_for_slice
         item_for_option
+        decimal_types
         payable_egld
         payable_some_token
         payable_any_token
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<alloc_features::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<alloc_features::AbiProvider, _>(
+        alloc_features::ContractBuilder,
+    );
 }
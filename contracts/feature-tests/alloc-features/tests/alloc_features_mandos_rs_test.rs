@@ -16,40 +16,40 @@ fn boxed_bytes_zeros_rs() {
     elrond_wasm_debug::mandos_rs("mandos/boxed_bytes_zeros.scen.json", world());
 }
 
-// #[test]
-// fn crypto_elliptic_curves_legacy_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/crypto_elliptic_curves_legacy.scen.json", world());
-// }
+#[test]
+fn crypto_elliptic_curves_legacy_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/crypto_elliptic_curves_legacy.scen.json", world());
+}
 
 #[test]
 fn crypto_keccak256_legacy_alloc_rs() {
     elrond_wasm_debug::mandos_rs("mandos/crypto_keccak256_legacy_alloc.scen.json", world());
 }
 
-// #[test]
-// fn crypto_ripemd160_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/crypto_ripemd160.scen.json", world());
-// }
+#[test]
+fn crypto_ripemd160_legacy_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/crypto_ripemd160_legacy.scen.json", world());
+}
 
 #[test]
 fn crypto_sha256_legacy_alloc_rs() {
     elrond_wasm_debug::mandos_rs("mandos/crypto_sha256_legacy_alloc.scen.json", world());
 }
 
-// #[test]
-// fn crypto_verify_bls_legacy_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/crypto_verify_bls_legacy.scen.json", world());
-// }
+#[test]
+fn crypto_verify_bls_legacy_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/crypto_verify_bls_legacy.scen.json", world());
+}
 
 #[test]
 fn crypto_verify_ed25519_legacy_rs() {
     elrond_wasm_debug::mandos_rs("mandos/crypto_verify_ed25519_legacy.scen.json", world());
 }
 
-// #[test]
-// fn crypto_verify_secp256k1_legacy_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/crypto_verify_secp256k1_legacy.scen.json", world());
-// }
+#[test]
+fn crypto_verify_secp256k1_legacy_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/crypto_verify_secp256k1_legacy.scen.json", world());
+}
 
 #[test]
 fn echo_async_result_empty_rs() {
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<basic_features::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<basic_features::AbiProvider, _>(
+        basic_features::ContractBuilder,
+    );
 }
@@ -66,10 +66,10 @@ fn count_ones_rs() {
     elrond_wasm_debug::mandos_rs("mandos/count_ones.scen.json", world());
 }
 
-// #[test]
-// fn crypto_elliptic_curves_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/crypto_elliptic_curves.scen.json", world());
-// }
+#[test]
+fn crypto_elliptic_curves_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/crypto_elliptic_curves.scen.json", world());
+}
 
 #[test]
 fn crypto_keccak256_rs() {
@@ -81,10 +81,10 @@ fn crypto_keccak256_legacy_managed_rs() {
     elrond_wasm_debug::mandos_rs("mandos/crypto_keccak256_legacy_managed.scen.json", world());
 }
 
-// #[test]
-// fn crypto_ripemd160_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/crypto_ripemd160.scen.json", world());
-// }
+#[test]
+fn crypto_ripemd160_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/crypto_ripemd160.scen.json", world());
+}
 
 #[test]
 fn crypto_sha256_rs() {
@@ -96,20 +96,20 @@ fn crypto_sha256_legacy_managed_rs() {
     elrond_wasm_debug::mandos_rs("mandos/crypto_sha256_legacy_managed.scen.json", world());
 }
 
-// #[test]
-// fn crypto_verify_bls_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/crypto_verify_bls.scen.json", world());
-// }
+#[test]
+fn crypto_verify_bls_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/crypto_verify_bls.scen.json", world());
+}
 
 #[test]
 fn crypto_verify_ed25519_rs() {
     elrond_wasm_debug::mandos_rs("mandos/crypto_verify_ed25519.scen.json", world());
 }
 
-// #[test]
-// fn crypto_verify_secp256k1_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/crypto_verify_secp256k1.scen.json", world());
-// }
+#[test]
+fn crypto_verify_secp256k1_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/crypto_verify_secp256k1.scen.json", world());
+}
 
 #[test]
 fn echo_array_u8_rs() {
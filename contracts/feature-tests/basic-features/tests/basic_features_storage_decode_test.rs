@@ -0,0 +1,134 @@
+use elrond_wasm::contract_base::ContractAbiProvider;
+use elrond_wasm_debug::storage_decode::{load_storage_dump, DecodedStorageEntry, StorageDecoder};
+
+fn storage_decoder() -> StorageDecoder {
+    StorageDecoder::new(&basic_features::AbiProvider::abi())
+}
+
+fn decoded(key: &str, value: &str) -> DecodedStorageEntry {
+    DecodedStorageEntry {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn decode_single_value_storage_test() {
+    let decoder = storage_decoder();
+
+    assert_eq!(
+        decoder.decode_entry(b"big_uint", &[1, 0]),
+        decoded("big_uint", "256")
+    );
+    assert_eq!(
+        decoder.decode_entry(b"opt_addr", &[]),
+        decoded("opt_addr", "None")
+    );
+    assert_eq!(
+        decoder.decode_entry(b"ser_2", &[2, 0, 0, 0, 1, 0, 0, 0, 2]),
+        decoded("ser_2", "ExampleEnumWithFields::Tuple(1, 2)")
+    );
+    assert_eq!(
+        decoder.decode_entry(b"ser_2", &[3, 0, 0, 0, 5]),
+        decoded("ser_2", "ExampleEnumWithFields::Struct { a: 5 }")
+    );
+
+    let mut map2_key = b"map2".to_vec();
+    map2_key.extend_from_slice(&[1u8; 32]);
+    map2_key.extend_from_slice(&[2u8; 32]);
+    assert_eq!(
+        decoder.decode_entry(map2_key.as_slice(), &[5]),
+        decoded(
+            format!(
+                "map2(addr1: 0x{}, addr2: 0x{})",
+                "01".repeat(32),
+                "02".repeat(32)
+            )
+            .as_str(),
+            "5"
+        )
+    );
+}
+
+#[test]
+fn decode_mapper_storage_test() {
+    let decoder = storage_decoder();
+
+    assert_eq!(
+        decoder.decode_entry(b"vec_mapper.len", &[2]),
+        decoded("vec_mapper.len", "2")
+    );
+    assert_eq!(
+        decoder.decode_entry(b"vec_mapper.item\x00\x00\x00\x02", &[111]),
+        decoded("vec_mapper.item(2)", "111")
+    );
+    assert_eq!(
+        decoder.decode_entry(b"map_mapper.mapped\x00\x00\x00\x7b", &[1, 200]),
+        decoded("map_mapper.mapped(123)", "456")
+    );
+    assert_eq!(
+        decoder.decode_entry(b"map_mapper.node_id\x00\x00\x00\x7b", &[1]),
+        decoded("map_mapper.node_id(123)", "1")
+    );
+    assert_eq!(
+        decoder.decode_entry(
+            b"list_mapper.node\x00\x00\x00\x01",
+            &[0, 0, 0, 123, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0]
+        ),
+        decoded(
+            "list_mapper.node(1)",
+            "LinkedListNode<u32> { value: 123, node_id: 1, next_id: 2, prev_id: 0 }"
+        )
+    );
+    assert_eq!(
+        decoder.decode_entry(
+            b"list_mapper.info",
+            &[0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 2]
+        ),
+        decoded(
+            "list_mapper.info",
+            "LinkedListInfo { len: 2, front: 1, back: 2, new: 2 }"
+        )
+    );
+    assert_eq!(
+        decoder.decode_entry(b"whitelistMapper\x00\x00\x00\x02ab", &[1]),
+        decoded("whitelistMapper(str:ab)", "true")
+    );
+}
+
+#[test]
+fn decode_unknown_storage_test() {
+    let decoder = storage_decoder();
+
+    assert_eq!(
+        decoder.decode_entry(b"unknown_key", &[1, 2]),
+        decoded("str:unknown_key", "0x0102")
+    );
+    assert_eq!(
+        decoder.decode_entry(b"u64", &[1, 2, 3, 4, 5, 6, 7, 8, 9]),
+        decoded(
+            "u64",
+            "0x010203040506070809 (could not decode as u64: input too long)"
+        )
+    );
+}
+
+#[test]
+fn decode_mandos_storage_test() {
+    let account_dumps = load_storage_dump("mandos/storage_mapper_vec.scen.json");
+    assert_eq!(account_dumps.len(), 1);
+    assert_eq!(
+        account_dumps[0].account.as_deref(),
+        Some("sc:basic-features")
+    );
+
+    let decoded_storage = storage_decoder().decode_storage(&account_dumps[0].storage);
+    assert_eq!(
+        decoded_storage,
+        vec![
+            decoded("vec_mapper.item(1)", "123"),
+            decoded("vec_mapper.item(2)", "111"),
+            decoded("vec_mapper.len", "2"),
+        ]
+    );
+}
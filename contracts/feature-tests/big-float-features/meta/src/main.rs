@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<big_float_features::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<big_float_features::AbiProvider, _>(
+        big_float_features::ContractBuilder,
+    );
 }
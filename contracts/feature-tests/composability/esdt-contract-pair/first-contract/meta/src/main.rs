@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<first_contract::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<first_contract::AbiProvider, _>(
+        first_contract::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<second_contract::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<second_contract::AbiProvider, _>(
+        second_contract::ContractBuilder,
+    );
 }
@@ -1,3 +1,3 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<child::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<child::AbiProvider, _>(child::ContractBuilder);
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<parent::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<parent::AbiProvider, _>(
+        parent::ContractBuilder,
+    );
 }
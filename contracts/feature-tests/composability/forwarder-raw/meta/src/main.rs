@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<forwarder_raw::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<forwarder_raw::AbiProvider, _>(
+        forwarder_raw::ContractBuilder,
+    );
 }
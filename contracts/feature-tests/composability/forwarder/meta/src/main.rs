@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<forwarder::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<forwarder::AbiProvider, _>(
+        forwarder::ContractBuilder,
+    );
 }
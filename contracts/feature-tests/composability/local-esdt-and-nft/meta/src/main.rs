@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<local_esdt_and_nft::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<local_esdt_and_nft::AbiProvider, _>(
+        local_esdt_and_nft::ContractBuilder,
+    );
 }
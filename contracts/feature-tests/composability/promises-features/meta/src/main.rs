@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<promises_features::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<promises_features::AbiProvider, _>(
+        promises_features::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<proxy_test_second::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<proxy_test_second::AbiProvider, _>(
+        proxy_test_second::ContractBuilder,
+    );
 }
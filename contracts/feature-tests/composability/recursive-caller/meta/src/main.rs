@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<recursive_caller::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<recursive_caller::AbiProvider, _>(
+        recursive_caller::ContractBuilder,
+    );
 }
@@ -0,0 +1,194 @@
+use elrond_wasm_debug::{
+    mandos::interpret_trait::InterpretableFrom, mandos_system::model::*, world_mock::ShardConfig, *,
+};
+
+const USER_ADDRESS_EXPR: &str = "address:a_user#00";
+const FORWARDER_ADDRESS_EXPR: &str = "sc:forwarder#00";
+const VAULT_ADDRESS_EXPR: &str = "sc:vault#01";
+
+const TOKEN_ID_EXPR: &str = "str:TOKEN-123456";
+
+const FORWARDER_PATH_EXPR: &str = "file:forwarder-raw/output/forwarder-raw.wasm";
+const VAULT_PATH_EXPR: &str = "file:vault/output/vault.wasm";
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("contracts/feature-tests/composability");
+
+    blockchain.register_contract(FORWARDER_PATH_EXPR, forwarder_raw::ContractBuilder);
+    blockchain.register_contract(VAULT_PATH_EXPR, vault::ContractBuilder);
+    blockchain.set_num_shards(2);
+    blockchain
+}
+
+fn setup(world: &mut BlockchainMock) {
+    let ic = world.interpreter_context();
+    world
+        .mandos_set_state(
+            SetStateStep::new()
+                .put_account(
+                    USER_ADDRESS_EXPR,
+                    Account::new()
+                        .nonce(0)
+                        .balance("1000")
+                        .esdt_balance(TOKEN_ID_EXPR, "1000"),
+                )
+                .new_address(USER_ADDRESS_EXPR, 0, FORWARDER_ADDRESS_EXPR)
+                .new_address(USER_ADDRESS_EXPR, 1, VAULT_ADDRESS_EXPR),
+        )
+        .mandos_sc_deploy(
+            ScDeployStep::new()
+                .from(USER_ADDRESS_EXPR)
+                .contract_code(FORWARDER_PATH_EXPR, &ic)
+                .expect(TxExpect::ok().no_result()),
+        )
+        .mandos_sc_deploy(
+            ScDeployStep::new()
+                .from(USER_ADDRESS_EXPR)
+                .contract_code(VAULT_PATH_EXPR, &ic)
+                .expect(TxExpect::ok().no_result()),
+        );
+}
+
+#[test]
+fn cross_shard_async_call_test() {
+    let mut world = world();
+    setup(&mut world);
+
+    world
+        .mandos_sc_call(
+            ScCallStep::new()
+                .from(USER_ADDRESS_EXPR)
+                .to(FORWARDER_ADDRESS_EXPR)
+                .egld_value("1000")
+                .function("forward_async_call")
+                .argument(VAULT_ADDRESS_EXPR)
+                .argument("str:accept_funds")
+                .expect(TxExpect::ok().no_result()),
+        )
+        .mandos_check_state(
+            CheckStateStep::new()
+                .put_account(USER_ADDRESS_EXPR, CheckAccount::new().balance("0"))
+                .put_account(FORWARDER_ADDRESS_EXPR, CheckAccount::new().balance("0"))
+                .put_account(VAULT_ADDRESS_EXPR, CheckAccount::new().balance("0")),
+        );
+    // the funds are in transit, along with the call
+    assert_eq!(world.pending_cross_shard_calls().count(), 1);
+
+    // the async call reaches the vault, the callback gets scheduled
+    let tx_result = world.execute_next_cross_shard_call().unwrap();
+    assert_eq!(tx_result.result_status, 0);
+    world.mandos_check_state(
+        CheckStateStep::new()
+            .put_account(FORWARDER_ADDRESS_EXPR, CheckAccount::new().balance("0"))
+            .put_account(
+                VAULT_ADDRESS_EXPR,
+                CheckAccount::new()
+                    .balance("1000")
+                    .check_storage("str:call_counts|nested:str:accept_funds", "1"),
+            ),
+    );
+    assert_eq!(world.pending_cross_shard_calls().count(), 1);
+
+    // the callback returns to the forwarder
+    let tx_result = world.execute_next_cross_shard_call().unwrap();
+    assert_eq!(tx_result.result_status, 0);
+    world.mandos_check_state(
+        CheckStateStep::new().put_account(
+            FORWARDER_ADDRESS_EXPR,
+            CheckAccount::new()
+                .balance("0")
+                .check_storage("str:callback_args.len", "1")
+                .check_storage("str:callback_args.item|u32:1", "nested:0x00"),
+        ),
+    );
+    assert!(!world.has_pending_cross_shard_calls());
+    assert!(world.execute_next_cross_shard_call().is_none());
+}
+
+#[test]
+fn cross_shard_async_call_refund_test() {
+    let mut world = world();
+    setup(&mut world);
+
+    world
+        .mandos_sc_call(
+            ScCallStep::new()
+                .from(USER_ADDRESS_EXPR)
+                .to(FORWARDER_ADDRESS_EXPR)
+                .esdt_transfer(TOKEN_ID_EXPR, 0, "1000")
+                .function("forward_async_call")
+                .argument(VAULT_ADDRESS_EXPR)
+                .argument("str:reject_funds")
+                .expect(TxExpect::ok().no_result()),
+        )
+        .mandos_check_state(
+            CheckStateStep::new()
+                .put_account(
+                    USER_ADDRESS_EXPR,
+                    CheckAccount::new().esdt_balance(TOKEN_ID_EXPR, "0"),
+                )
+                .put_account(
+                    FORWARDER_ADDRESS_EXPR,
+                    CheckAccount::new().esdt_balance(TOKEN_ID_EXPR, "0"),
+                )
+                .put_account(
+                    VAULT_ADDRESS_EXPR,
+                    CheckAccount::new().esdt_balance(TOKEN_ID_EXPR, "0"),
+                ),
+        );
+
+    // the async call fails on the vault, the funds stay in transit until the callback
+    let tx_result = world.execute_next_cross_shard_call().unwrap();
+    assert_eq!(tx_result.result_status, 4);
+    world.mandos_check_state(
+        CheckStateStep::new()
+            .put_account(
+                FORWARDER_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(TOKEN_ID_EXPR, "0"),
+            )
+            .put_account(
+                VAULT_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(TOKEN_ID_EXPR, "0"),
+            ),
+    );
+
+    // the callback refunds the forwarder
+    let tx_result = world.execute_next_cross_shard_call().unwrap();
+    assert_eq!(tx_result.result_status, 0);
+    world.mandos_check_state(
+        CheckStateStep::new()
+            .put_account(
+                FORWARDER_ADDRESS_EXPR,
+                CheckAccount::new()
+                    .esdt_balance(TOKEN_ID_EXPR, "1000")
+                    .check_storage("str:callback_args.len", "1")
+                    .check_storage(
+                        "str:callback_args.item|u32:1",
+                        "nested:0x04|nested:str:reject_funds",
+                    ),
+            )
+            .put_account(
+                VAULT_ADDRESS_EXPR,
+                CheckAccount::new().esdt_balance(TOKEN_ID_EXPR, "0"),
+            ),
+    );
+    assert!(!world.has_pending_cross_shard_calls());
+}
+
+#[test]
+fn shard_of_address_test() {
+    let world = world();
+    let ic = world.interpreter_context();
+    let address_in_shard = |expr: &str| {
+        let address = AddressValue::interpret_from(expr, &ic);
+        world.get_shard_of_address(&address.value)
+    };
+    assert_eq!(address_in_shard(USER_ADDRESS_EXPR), 0);
+    assert_eq!(address_in_shard(FORWARDER_ADDRESS_EXPR), 0);
+    assert_eq!(address_in_shard(VAULT_ADDRESS_EXPR), 1);
+    assert_eq!(address_in_shard("address:other#03"), 1);
+
+    assert_eq!(ShardConfig::new(3).shard_of_address(&[3u8; 32].into()), 1);
+    assert_eq!(ShardConfig::new(3).shard_of_address(&[2u8; 32].into()), 2);
+}
@@ -85,6 +85,16 @@ fn forw_raw_sync_readonly_go() {
     elrond_wasm_debug::mandos_go("mandos/forw_raw_sync_readonly.scen.json");
 }
 
+#[test]
+fn forw_raw_sync_readonly_write_go() {
+    elrond_wasm_debug::mandos_go("mandos/forw_raw_sync_readonly_write.scen.json");
+}
+
+#[test]
+fn forw_raw_sync_readonly_esdt_go() {
+    elrond_wasm_debug::mandos_go("mandos/forw_raw_sync_readonly_esdt.scen.json");
+}
+
 #[test]
 fn forw_raw_sync_same_context_go() {
     elrond_wasm_debug::mandos_go("mandos/forw_raw_sync_same_context.scen.json");
@@ -124,20 +124,30 @@ fn forw_raw_sync_egld_rs() {
     elrond_wasm_debug::mandos_rs("mandos/forw_raw_sync_egld.scen.json", world());
 }
 
-// #[test]
-// fn forw_raw_sync_readonly_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/forw_raw_sync_readonly.scen.json", world());
-// }
+#[test]
+fn forw_raw_sync_readonly_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/forw_raw_sync_readonly.scen.json", world());
+}
 
-// #[test]
-// fn forw_raw_sync_same_context_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/forw_raw_sync_same_context.scen.json", world());
-// }
+#[test]
+fn forw_raw_sync_readonly_write_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/forw_raw_sync_readonly_write.scen.json", world());
+}
 
-// #[test]
-// fn forw_raw_sync_same_context_egld_rs() {
-//     elrond_wasm_debug::mandos_rs("mandos/forw_raw_sync_same_context_egld.scen.json", world());
-// }
+#[test]
+fn forw_raw_sync_readonly_esdt_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/forw_raw_sync_readonly_esdt.scen.json", world());
+}
+
+#[test]
+fn forw_raw_sync_same_context_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/forw_raw_sync_same_context.scen.json", world());
+}
+
+#[test]
+fn forw_raw_sync_same_context_egld_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/forw_raw_sync_same_context_egld.scen.json", world());
+}
 
 #[test]
 fn forw_raw_transf_exec_accept_egld_rs() {
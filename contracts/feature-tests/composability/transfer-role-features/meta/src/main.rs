@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<transfer_role_features::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<transfer_role_features::AbiProvider, _>(
+        transfer_role_features::ContractBuilder,
+    );
 }
@@ -1,3 +1,3 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<vault::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<vault::AbiProvider, _>(vault::ContractBuilder);
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<crowdfunding_erc20::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<crowdfunding_erc20::AbiProvider, _>(
+        crowdfunding_erc20::ContractBuilder,
+    );
 }
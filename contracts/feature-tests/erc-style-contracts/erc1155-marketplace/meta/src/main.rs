@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<erc1155_marketplace::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<erc1155_marketplace::AbiProvider, _>(
+        erc1155_marketplace::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<erc1155::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<erc1155::AbiProvider, _>(
+        erc1155::ContractBuilder,
+    );
 }
@@ -1,3 +1,3 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<erc20::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<erc20::AbiProvider, _>(erc20::ContractBuilder);
 }
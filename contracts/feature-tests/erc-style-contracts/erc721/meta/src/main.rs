@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<erc721::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<erc721::AbiProvider, _>(
+        erc721::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<lottery_erc20::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<lottery_erc20::AbiProvider, _>(
+        lottery_erc20::ContractBuilder,
+    );
 }
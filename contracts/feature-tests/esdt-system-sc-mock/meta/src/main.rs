@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<esdt_system_sc_mock::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<esdt_system_sc_mock::AbiProvider, _>(
+        esdt_system_sc_mock::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<formatted_message_features::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<formatted_message_features::AbiProvider, _>(
+        formatted_message_features::ContractBuilder,
+    );
 }
@@ -1,8 +1,23 @@
 #![no_std]
 
 elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Errors with stable codes, which clients can rely on instead of the message text.
+#[derive(ContractError, Clone, Copy)]
+pub enum MessageFeaturesError {
+    /// Signaled without arguments.
+    #[error(code = 1, message = "Static contract error")]
+    Static,
+
+    #[error(code = 42, message = "Amount {} is below the minimum of {}")]
+    AmountTooLow,
+}
+
+pub const MIN_AMOUNT: u64 = 100;
 
 #[elrond_wasm::contract]
+#[errors(MessageFeaturesError)]
 pub trait FormattedMessageFeatures {
     #[init]
     fn init(&self) {}
@@ -51,6 +66,21 @@ pub trait FormattedMessageFeatures {
         sc_panic!(DecodeError::UNSUPPORTED_OPERATION,);
     }
 
+    #[endpoint]
+    fn contract_error_static(&self) {
+        sc_panic!(MessageFeaturesError::Static);
+    }
+
+    #[endpoint]
+    fn contract_error_require(&self, amount: BigUint) {
+        require!(
+            amount >= MIN_AMOUNT,
+            MessageFeaturesError::AmountTooLow,
+            amount,
+            MIN_AMOUNT,
+        );
+    }
+
     /// TODO: figure out a way to test this.
     #[endpoint]
     fn print_message(&self, x: i32) {
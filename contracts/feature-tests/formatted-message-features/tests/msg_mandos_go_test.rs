@@ -7,3 +7,8 @@ fn managed_error_message_go() {
 fn sc_format_go() {
     elrond_wasm_debug::mandos_go("mandos/sc_format.scen.json");
 }
+
+#[test]
+fn contract_error_go() {
+    elrond_wasm_debug::mandos_go("mandos/contract_error.scen.json");
+}
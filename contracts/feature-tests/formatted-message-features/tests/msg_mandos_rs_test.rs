@@ -1,4 +1,4 @@
-use elrond_wasm_debug::*;
+use elrond_wasm_debug::{mandos_system::model::*, *};
 
 fn world() -> BlockchainMock {
     let mut blockchain = BlockchainMock::new();
@@ -21,3 +21,42 @@ fn managed_error_message_rs() {
 fn sc_format_rs() {
     elrond_wasm_debug::mandos_rs("mandos/sc_format.scen.json", world());
 }
+
+#[test]
+fn contract_error_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/contract_error.scen.json", world());
+}
+
+/// The error code can only be checked from Rust, scenario files only check the full message.
+#[test]
+fn contract_error_code_rs() {
+    let mut world = world();
+    let ic = world.interpreter_context();
+    world
+        .mandos_set_state(
+            SetStateStep::new()
+                .put_account("address:an_account", Account::new().nonce(1))
+                .new_address("address:an_account", 1, "sc:msg-features"),
+        )
+        .mandos_sc_deploy(
+            ScDeployStep::new()
+                .from("address:an_account")
+                .contract_code("file:output/formatted-message-features.wasm", &ic)
+                .expect(TxExpect::ok().no_result()),
+        )
+        .mandos_sc_call(
+            ScCallStep::new()
+                .from("address:an_account")
+                .to("sc:msg-features")
+                .function("contract_error_static")
+                .expect(TxExpect::err_code(1)),
+        )
+        .mandos_sc_call(
+            ScCallStep::new()
+                .from("address:an_account")
+                .to("sc:msg-features")
+                .function("contract_error_require")
+                .argument("99")
+                .expect(TxExpect::err_code(42)),
+        );
+}
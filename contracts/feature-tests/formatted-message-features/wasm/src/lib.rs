@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           15
+// Endpoints:                           17
 // Async Callback (empty):               1
-// Total number of exported functions:  17
+// Total number of exported functions:  19
 
 #![no_std]
 
@@ -20,6 +20,8 @@ elrond_wasm_node::wasm_endpoints! {
         dynamic_message_multiple
         dynamic_message_ascii
         decode_error_message
+        contract_error_static
+        contract_error_require
         print_message
         print_message_hex
         print_message_binary
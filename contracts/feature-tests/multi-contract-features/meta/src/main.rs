@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<multi_contract_features::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<multi_contract_features::AbiProvider, _>(
+        multi_contract_features::ContractBuilder,
+    );
 }
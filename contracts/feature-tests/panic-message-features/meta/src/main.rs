@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<panic_message_features::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<panic_message_features::AbiProvider, _>(
+        panic_message_features::ContractBuilder,
+    );
 }
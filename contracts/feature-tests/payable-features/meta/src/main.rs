@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<payable_features::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<payable_features::AbiProvider, _>(
+        payable_features::ContractBuilder,
+    );
 }
@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<rust_testing_framework_tester::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<rust_testing_framework_tester::AbiProvider, _>(
+        rust_testing_framework_tester::ContractBuilder,
+    );
 }
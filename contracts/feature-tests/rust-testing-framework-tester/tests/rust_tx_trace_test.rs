@@ -0,0 +1,168 @@
+use elrond_wasm_debug::{
+    managed_address, managed_biguint, rust_biguint,
+    testing_framework::*,
+    tx_mock::{bytes_to_mandos_expr, TxTraceCallType, TxTraceEntry},
+};
+use rust_testing_framework_tester::*;
+
+const SC_WASM_PATH: &str = "output/rust-testing-framework-tester.wasm";
+
+#[test]
+fn tx_trace_disabled_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    let tx_result = wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+        sc.total_value().set(&managed_biguint!(5));
+    });
+    tx_result.assert_ok();
+    assert!(tx_result.trace.is_none());
+}
+
+#[test]
+fn tx_trace_transfers_test() {
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user_addr = wrapper.create_user_account(&rust_biguint!(1_000));
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    wrapper.enable_tx_trace();
+
+    let tx_result = wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_biguint!(1_000), |sc| {
+        sc.recieve_egld_half();
+    });
+    tx_result.assert_ok();
+
+    let trace = tx_result.trace.unwrap();
+    let transfers: Vec<&TxTraceEntry> = trace.transfers().collect();
+    assert_eq!(
+        transfers,
+        vec![
+            &TxTraceEntry::Transfer {
+                from: user_addr.clone(),
+                to: sc_wrapper.address_ref().clone(),
+                token_identifier: Vec::new(),
+                nonce: 0,
+                amount: rust_biguint!(1_000),
+            },
+            &TxTraceEntry::Transfer {
+                from: sc_wrapper.address_ref().clone(),
+                to: user_addr.clone(),
+                token_identifier: Vec::new(),
+                nonce: 0,
+                amount: rust_biguint!(500),
+            },
+        ]
+    );
+    assert!(trace.calls().all(|call| matches!(
+        call,
+        TxTraceEntry::Call {
+            call_type: TxTraceCallType::TransferExecute,
+            ..
+        }
+    )));
+
+    assert_eq!(trace.state_diff.len(), 2);
+    for account_diff in &trace.state_diff {
+        if &account_diff.address == sc_wrapper.address_ref() {
+            assert_eq!(
+                account_diff.egld_balance,
+                Some((rust_biguint!(0), rust_biguint!(500)))
+            );
+        } else {
+            assert_eq!(account_diff.address, user_addr);
+            assert_eq!(
+                account_diff.egld_balance,
+                Some((rust_biguint!(1_000), rust_biguint!(500)))
+            );
+        }
+    }
+}
+
+#[test]
+fn tx_trace_nested_call_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut wrapper = BlockchainStateWrapper::new();
+    let user_addr = wrapper.create_user_account(&rust_zero);
+    let sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+    let other_sc_wrapper = wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        rust_testing_framework_tester::contract_obj,
+        SC_WASM_PATH,
+    );
+
+    wrapper
+        .execute_tx(&user_addr, &other_sc_wrapper, &rust_zero, |sc| {
+            sc.total_value().set(&managed_biguint!(5));
+        })
+        .assert_ok();
+
+    wrapper.enable_tx_trace();
+    let tx_result = wrapper.execute_tx(&user_addr, &sc_wrapper, &rust_zero, |sc| {
+        sc.execute_on_dest_add_value(
+            managed_address!(other_sc_wrapper.address_ref()),
+            managed_biguint!(5),
+        );
+    });
+    tx_result.assert_ok();
+    let trace = tx_result.trace.unwrap();
+
+    let calls: Vec<&TxTraceEntry> = trace.calls().collect();
+    assert_eq!(
+        calls,
+        vec![&TxTraceEntry::Call {
+            call_type: TxTraceCallType::ExecuteOnDestContext,
+            from: sc_wrapper.address_ref().clone(),
+            to: other_sc_wrapper.address_ref().clone(),
+            endpoint: b"addValue".to_vec(),
+            args: vec![vec![5]],
+        }]
+    );
+
+    // the nested call reads and writes the storage of the other contract
+    assert!(trace.entries.contains(&TxTraceEntry::StorageRead {
+        address: other_sc_wrapper.address_ref().clone(),
+        key: b"totalValue".to_vec(),
+        value: vec![5],
+    }));
+    assert!(trace.storage_writes().any(|entry| entry
+        == &TxTraceEntry::StorageWrite {
+            address: other_sc_wrapper.address_ref().clone(),
+            key: b"totalValue".to_vec(),
+            old_value: vec![5],
+            new_value: vec![10],
+        }));
+
+    assert_eq!(trace.state_diff.len(), 1);
+    let account_diff = &trace.state_diff[0];
+    assert_eq!(&account_diff.address, other_sc_wrapper.address_ref());
+    assert_eq!(account_diff.storage.len(), 2);
+    let storage_keys: Vec<String> = account_diff
+        .storage
+        .iter()
+        .map(|storage_diff| bytes_to_mandos_expr(&storage_diff.key))
+        .collect();
+    assert!(storage_keys.contains(&"str:totalValue".to_string()));
+    assert!(storage_keys
+        .iter()
+        .any(|key| key.starts_with("str:valuePerCaller|0x")));
+
+    let trace_str = trace.to_string();
+    assert!(trace_str.contains("str:totalValue: 0x05 -> 0x0a"));
+}
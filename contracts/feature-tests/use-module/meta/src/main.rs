@@ -1,3 +1,5 @@
 fn main() {
-    elrond_wasm_debug::meta::perform::<use_module::AbiProvider>();
+    elrond_wasm_debug::meta::perform_with_contract::<use_module::AbiProvider, _>(
+        use_module::ContractBuilder,
+    );
 }
@@ -0,0 +1,10 @@
+use elrond_wasm_modules::roles;
+
+elrond_wasm::imports!();
+
+#[elrond_wasm::module]
+pub trait OnlyRoleTestModule: roles::RolesModule {
+    #[only_role("MINTER")]
+    #[endpoint]
+    fn only_minter_mod_endpoint(&self) {}
+}
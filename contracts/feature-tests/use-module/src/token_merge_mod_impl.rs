@@ -6,6 +6,7 @@ use core::marker::PhantomData;
 use elrond_wasm_modules::token_merge::{
     custom_merged_token_attributes::{
         AllMergeScTraits, DefaultMergedAttributesWrapper, MergedTokenAttributesCreator,
+        MixedCreatorsMergedAttributesWrapper,
     },
     merged_token_instances::MergedTokenInstances,
 };
@@ -39,6 +40,14 @@ pub trait TokenMergeModImpl:
         self.merge_tokens(payments, &attributes_creator)
     }
 
+    #[payable("*")]
+    #[endpoint(mergeTokensMixedCreators)]
+    fn merge_tokens_mixed_creators_endpoint(&self) -> EsdtTokenPayment {
+        let payments = self.call_value().all_esdt_transfers();
+        let attributes_creator = MixedCreatorsMergedAttributesWrapper::new();
+        self.merge_tokens(payments, &attributes_creator)
+    }
+
     #[payable("*")]
     #[endpoint(splitTokens)]
     fn split_tokens_endpoint(&self) -> ManagedVec<EsdtTokenPayment> {
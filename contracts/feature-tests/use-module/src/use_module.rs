@@ -10,6 +10,7 @@ mod only_admin_derived_mod;
 mod only_admin_mod;
 mod only_owner_derived_mod;
 mod only_owner_mod;
+mod only_role_mod;
 pub mod token_merge_mod_impl;
 
 elrond_wasm::imports!();
@@ -20,7 +21,13 @@ elrond_wasm::imports!();
 /// - FeaturesModule
 /// - EsdtModule
 /// - GovernanceModule
+/// - MerkleAirdropModule
 /// - PauseModule
+/// - RateLimitModule
+/// - RolesModule
+/// - RoyaltiesModule
+/// - TimelockModule
+/// - VestingModule
 #[elrond_wasm::contract]
 pub trait UseModule:
     internal_mod_a::InternalModuleA
@@ -31,6 +38,7 @@ pub trait UseModule:
     + only_owner_derived_mod::OnlyOwnerDerivedTestModule
     + only_admin_mod::OnlyAdminTestModule
     + only_admin_derived_mod::OnlyAdminDerivedTestModule
+    + only_role_mod::OnlyRoleTestModule
     + ongoing_operation_mod_example::OngoingOperationModExample
     + token_merge_mod_impl::TokenMergeModImpl
     + elrond_wasm_modules::claim_developer_rewards::ClaimDeveloperRewardsModule
@@ -40,12 +48,18 @@ pub trait UseModule:
     + elrond_wasm_modules::governance::GovernanceModule
     + elrond_wasm_modules::governance::governance_configurable::GovernanceConfigurablePropertiesModule
     + elrond_wasm_modules::governance::governance_events::GovernanceEventsModule
+    + elrond_wasm_modules::merkle_airdrop::MerkleAirdropModule
     + elrond_wasm_modules::pause::PauseModule
+    + elrond_wasm_modules::rate_limit::RateLimitModule
     + elrond_wasm_modules::staking::StakingModule
+    + elrond_wasm_modules::timelock::TimelockModule
     + elrond_wasm_modules::token_merge::TokenMergeModule
     + elrond_wasm_modules::token_merge::merged_token_setup::MergedTokenSetupModule
+    + elrond_wasm_modules::vesting::VestingModule
     + elrond_wasm_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + elrond_wasm_modules::only_admin::OnlyAdminModule
+    + elrond_wasm_modules::roles::RolesModule
+    + elrond_wasm_modules::royalties::RoyaltiesModule
     + elrond_wasm_modules::ongoing_operation::OngoingOperationModule
 {
     /// Validates that the "featureName" feature is on.
@@ -55,6 +69,13 @@ pub trait UseModule:
         self.check_feature_on(b"featureName", true);
     }
 
+    /// Counts towards the "rateLimitedCall" quota.
+    /// Uses the `require_within_call_quota` helper.
+    #[endpoint(rateLimitedCall)]
+    fn rate_limited_call(&self) {
+        self.require_within_call_quota(b"rateLimitedCall");
+    }
+
     #[endpoint(checkPause)]
     fn check_pause(&self) -> SCResult<bool> {
         Ok(self.is_paused())
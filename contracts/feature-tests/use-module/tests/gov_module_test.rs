@@ -504,3 +504,206 @@ fn gov_cancel_defeated_proposal_test() {
     gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
     gov_setup.cancel(&second_user_addr, proposal_id).assert_ok();
 }
+
+#[test]
+fn gov_snapshot_voting_power_test() {
+    let mut gov_setup = GovSetup::new(use_module::contract_obj);
+
+    let owner_addr = gov_setup.owner.clone();
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let third_user_addr = gov_setup.third_user.clone();
+    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
+
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &owner_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init_snapshot_voting_power();
+                sc.update_voting_power(&managed_address!(&first_user_addr), &managed_biguint!(800));
+                sc.update_voting_power(
+                    &managed_address!(&second_user_addr),
+                    &managed_biguint!(1_000),
+                );
+            },
+        )
+        .assert_ok();
+
+    // the snapshot is taken at the end of the block before the proposal
+    gov_setup.increment_block_nonce(1);
+    let (result, proposal_id) = gov_setup.propose(
+        &first_user_addr,
+        500,
+        &sc_addr,
+        b"changeQuorum",
+        vec![1_000u64.to_be_bytes().to_vec()],
+    );
+    result.assert_ok();
+
+    // second user moves their voting power to the third user after the proposal was created
+    gov_setup.increment_block_nonce(1);
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &owner_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.update_voting_power(&managed_address!(&second_user_addr), &managed_biguint!(0));
+                sc.update_voting_power(
+                    &managed_address!(&third_user_addr),
+                    &managed_biguint!(1_000),
+                );
+            },
+        )
+        .assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_DELAY_BLOCKS);
+
+    // tokens are not accepted anymore
+    gov_setup
+        .vote(&second_user_addr, proposal_id, 100)
+        .assert_user_error("No payment accepted when voting with snapshot voting power");
+
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &third_user_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.vote(proposal_id, VoteType::DownVote);
+            },
+        )
+        .assert_user_error("No voting power at proposal creation");
+
+    for voter in [&first_user_addr, &second_user_addr] {
+        gov_setup
+            .b_mock
+            .execute_tx(voter, &gov_setup.gov_wrapper, &rust_biguint!(0), |sc| {
+                sc.vote(proposal_id, VoteType::UpVote);
+            })
+            .assert_ok();
+    }
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            let votes = sc.proposal_votes(proposal_id).get();
+            assert_eq!(votes.up_votes, managed_biguint!(1_800));
+            assert_eq!(
+                sc.get_voting_power_at(managed_address!(&second_user_addr), 11),
+                managed_biguint!(1_000)
+            );
+            assert_eq!(
+                sc.get_voting_power_at(managed_address!(&second_user_addr), 12),
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+    gov_setup.queue(proposal_id).assert_ok();
+
+    // voting did not lock any tokens
+    gov_setup.b_mock.check_esdt_balance(
+        &second_user_addr,
+        GOV_TOKEN_ID,
+        &rust_biguint!(INITIAL_GOV_TOKEN_BALANCE),
+    );
+}
+
+#[test]
+fn gov_snapshot_voting_power_same_block_test() {
+    let mut gov_setup = GovSetup::new(use_module::contract_obj);
+
+    let owner_addr = gov_setup.owner.clone();
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let third_user_addr = gov_setup.third_user.clone();
+    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
+
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &owner_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init_snapshot_voting_power();
+                sc.update_voting_power(&managed_address!(&first_user_addr), &managed_biguint!(800));
+                sc.update_voting_power(
+                    &managed_address!(&second_user_addr),
+                    &managed_biguint!(1_000),
+                );
+            },
+        )
+        .assert_ok();
+
+    gov_setup.increment_block_nonce(1);
+    let (result, proposal_id) = gov_setup.propose(
+        &first_user_addr,
+        500,
+        &sc_addr,
+        b"changeQuorum",
+        vec![1_000u64.to_be_bytes().to_vec()],
+    );
+    result.assert_ok();
+
+    // in the same block as the proposal, the second user moves their voting power to the third user,
+    // while the third user gets some more voting power of their own
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &owner_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.update_voting_power(&managed_address!(&second_user_addr), &managed_biguint!(0));
+                sc.update_voting_power(
+                    &managed_address!(&third_user_addr),
+                    &managed_biguint!(1_000),
+                );
+                sc.update_voting_power(
+                    &managed_address!(&first_user_addr),
+                    &managed_biguint!(5_000),
+                );
+            },
+        )
+        .assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_DELAY_BLOCKS);
+
+    // none of the updates from the proposal block count
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &third_user_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.vote(proposal_id, VoteType::UpVote);
+            },
+        )
+        .assert_user_error("No voting power at proposal creation");
+
+    for voter in [&first_user_addr, &second_user_addr] {
+        gov_setup
+            .b_mock
+            .execute_tx(voter, &gov_setup.gov_wrapper, &rust_biguint!(0), |sc| {
+                sc.vote(proposal_id, VoteType::UpVote);
+            })
+            .assert_ok();
+    }
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            let votes = sc.proposal_votes(proposal_id).get();
+            assert_eq!(votes.up_votes, managed_biguint!(1_800));
+        })
+        .assert_ok();
+}
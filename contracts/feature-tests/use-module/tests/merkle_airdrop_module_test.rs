@@ -0,0 +1,118 @@
+use elrond_wasm::types::{
+    Address, EgldOrEsdtTokenIdentifier, ManagedByteArray, MerkleHashAlgorithm, MultiValueEncoded,
+};
+use elrond_wasm_debug::{
+    managed_biguint, rust_biguint, testing_framework::BlockchainStateWrapper, DebugApi, MerkleTree,
+};
+use elrond_wasm_modules::merkle_airdrop::MerkleAirdropModule;
+
+const ALICE_AMOUNT: u64 = 1_000;
+const BOB_AMOUNT: u64 = 2_500;
+const CAROL_AMOUNT: u64 = 700;
+
+/// Same encoding as the one used by the module: `index | address | amount`.
+fn airdrop_leaf(index: u64, address: &Address, amount: u64) -> Vec<u8> {
+    let amount_bytes = rust_biguint!(amount).to_bytes_be();
+    let mut leaf = index.to_be_bytes().to_vec();
+    leaf.extend_from_slice(address.as_bytes());
+    leaf.extend_from_slice(&(amount_bytes.len() as u32).to_be_bytes());
+    leaf.extend_from_slice(&amount_bytes);
+    leaf
+}
+
+fn proof_arg(
+    tree: &MerkleTree,
+    index: usize,
+) -> MultiValueEncoded<DebugApi, ManagedByteArray<DebugApi, 32>> {
+    let mut proof = MultiValueEncoded::new();
+    for hash in tree.proof(index) {
+        proof.push(ManagedByteArray::new_from_bytes(&hash));
+    }
+    proof
+}
+
+#[test]
+fn merkle_airdrop_module_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let alice = b_mock.create_user_account(&rust_zero);
+    let bob = b_mock.create_user_account(&rust_zero);
+    let carol = b_mock.create_user_account(&rust_zero);
+    let airdrop_sc = b_mock.create_sc_account(
+        &rust_biguint!(ALICE_AMOUNT + BOB_AMOUNT + CAROL_AMOUNT),
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    let tree = MerkleTree::new(
+        MerkleHashAlgorithm::Keccak256,
+        [
+            airdrop_leaf(0, &alice, ALICE_AMOUNT),
+            airdrop_leaf(1, &bob, BOB_AMOUNT),
+            airdrop_leaf(2, &carol, CAROL_AMOUNT),
+        ],
+    );
+
+    // claim before setup
+    b_mock
+        .execute_tx(&alice, &airdrop_sc, &rust_zero, |sc| {
+            sc.claim_airdrop(0, managed_biguint!(ALICE_AMOUNT), proof_arg(&tree, 0));
+        })
+        .assert_user_error("Airdrop not set up");
+
+    b_mock
+        .execute_tx(&owner, &airdrop_sc, &rust_zero, |sc| {
+            sc.init_merkle_airdrop_module(
+                &EgldOrEsdtTokenIdentifier::egld(),
+                &ManagedByteArray::new_from_bytes(&tree.root()),
+            );
+        })
+        .assert_ok();
+
+    // wrong amount
+    b_mock
+        .execute_tx(&alice, &airdrop_sc, &rust_zero, |sc| {
+            sc.claim_airdrop(0, managed_biguint!(ALICE_AMOUNT + 1), proof_arg(&tree, 0));
+        })
+        .assert_user_error("Invalid Merkle proof");
+
+    // someone else's leaf
+    b_mock
+        .execute_tx(&carol, &airdrop_sc, &rust_zero, |sc| {
+            sc.claim_airdrop(1, managed_biguint!(BOB_AMOUNT), proof_arg(&tree, 1));
+        })
+        .assert_user_error("Invalid Merkle proof");
+
+    b_mock
+        .execute_tx(&alice, &airdrop_sc, &rust_zero, |sc| {
+            sc.claim_airdrop(0, managed_biguint!(ALICE_AMOUNT), proof_arg(&tree, 0));
+        })
+        .assert_ok();
+    b_mock.check_egld_balance(&alice, &rust_biguint!(ALICE_AMOUNT));
+
+    // claim twice
+    b_mock
+        .execute_tx(&alice, &airdrop_sc, &rust_zero, |sc| {
+            sc.claim_airdrop(0, managed_biguint!(ALICE_AMOUNT), proof_arg(&tree, 0));
+        })
+        .assert_user_error("Airdrop already claimed");
+
+    // the last leaf has no sibling on the first level
+    b_mock
+        .execute_tx(&carol, &airdrop_sc, &rust_zero, |sc| {
+            sc.claim_airdrop(2, managed_biguint!(CAROL_AMOUNT), proof_arg(&tree, 2));
+        })
+        .assert_ok();
+    b_mock.check_egld_balance(&carol, &rust_biguint!(CAROL_AMOUNT));
+
+    b_mock
+        .execute_query(&airdrop_sc, |sc| {
+            assert!(sc.is_airdrop_claimed(0));
+            assert!(!sc.is_airdrop_claimed(1));
+            assert!(sc.is_airdrop_claimed(2));
+        })
+        .assert_ok();
+    b_mock.check_egld_balance(airdrop_sc.address_ref(), &rust_biguint!(BOB_AMOUNT));
+}
@@ -0,0 +1,387 @@
+use elrond_wasm::types::ManagedBuffer;
+use elrond_wasm_debug::{
+    managed_address, managed_biguint, managed_buffer, rust_biguint,
+    testing_framework::BlockchainStateWrapper,
+};
+use elrond_wasm_modules::rate_limit::{
+    RateLimitAlgorithm, RateLimitModule, RateLimitScope, RateLimitWindowUnit,
+};
+use use_module::UseModule;
+
+static RATE_LIMITED_CALL_KEY: &[u8] = b"rateLimitedCall";
+static TRANSFER_KEY: &[u8] = b"transfer";
+
+#[test]
+fn rate_limit_fixed_window_per_address_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let first_user = b_mock.create_user_account(&rust_zero);
+    let second_user = b_mock.create_user_account(&rust_zero);
+    let rate_limit_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    // no limit set
+    b_mock
+        .execute_tx(&first_user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.rate_limited_call();
+            assert!(sc
+                .get_remaining_address_quota(
+                    managed_buffer!(RATE_LIMITED_CALL_KEY),
+                    managed_address!(&first_user)
+                )
+                .into_option()
+                .is_none());
+        })
+        .assert_ok();
+
+    b_mock.set_block_epoch(5);
+    b_mock
+        .execute_tx(&owner, &rate_limit_sc, &rust_zero, |sc| {
+            sc.set_rate_limit(
+                managed_buffer!(RATE_LIMITED_CALL_KEY),
+                RateLimitScope::PerAddress,
+                RateLimitAlgorithm::FixedWindow,
+                RateLimitWindowUnit::Epochs,
+                10,
+                managed_biguint!(2),
+            );
+        })
+        .assert_ok();
+
+    for _ in 0..2 {
+        b_mock
+            .execute_tx(&first_user, &rate_limit_sc, &rust_zero, |sc| {
+                sc.rate_limited_call();
+            })
+            .assert_ok();
+    }
+    b_mock
+        .execute_tx(&first_user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.rate_limited_call();
+        })
+        .assert_user_error("Address rate limit exceeded for rateLimitedCall");
+
+    // other addresses have their own quota
+    b_mock
+        .execute_tx(&second_user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.rate_limited_call();
+        })
+        .assert_ok();
+
+    // the window ends at epoch 10, not 15
+    b_mock.set_block_epoch(10);
+    b_mock
+        .execute_query(&rate_limit_sc, |sc| {
+            let remaining = sc.get_remaining_address_quota(
+                managed_buffer!(RATE_LIMITED_CALL_KEY),
+                managed_address!(&first_user),
+            );
+            assert_eq!(remaining.into_option(), Some(managed_biguint!(2)));
+        })
+        .assert_ok();
+    b_mock
+        .execute_tx(&first_user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.rate_limited_call();
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &rate_limit_sc, &rust_zero, |sc| {
+            sc.remove_rate_limit(
+                managed_buffer!(RATE_LIMITED_CALL_KEY),
+                RateLimitScope::PerAddress,
+            );
+        })
+        .assert_ok();
+    for _ in 0..3 {
+        b_mock
+            .execute_tx(&first_user, &rate_limit_sc, &rust_zero, |sc| {
+                sc.rate_limited_call();
+            })
+            .assert_ok();
+    }
+}
+
+#[test]
+fn rate_limit_token_bucket_global_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let first_user = b_mock.create_user_account(&rust_zero);
+    let second_user = b_mock.create_user_account(&rust_zero);
+    let rate_limit_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    b_mock.set_block_timestamp(1_000);
+    b_mock
+        .execute_tx(&owner, &rate_limit_sc, &rust_zero, |sc| {
+            sc.set_rate_limit(
+                managed_buffer!(TRANSFER_KEY),
+                RateLimitScope::Global,
+                RateLimitAlgorithm::TokenBucket,
+                RateLimitWindowUnit::Seconds,
+                100,
+                managed_biguint!(1_000),
+            );
+        })
+        .assert_ok();
+
+    // the bucket starts full
+    b_mock
+        .execute_tx(&first_user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.require_within_quota(TRANSFER_KEY, &managed_biguint!(1_000));
+        })
+        .assert_ok();
+    b_mock
+        .execute_tx(&second_user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.require_within_quota(TRANSFER_KEY, &managed_biguint!(1));
+        })
+        .assert_user_error("Global rate limit exceeded for transfer");
+
+    // refills at 1_000 per 100 seconds
+    b_mock.set_block_timestamp(1_030);
+    b_mock
+        .execute_tx(&second_user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.require_within_quota(TRANSFER_KEY, &managed_biguint!(301));
+        })
+        .assert_user_error("Global rate limit exceeded for transfer");
+    b_mock
+        .execute_tx(&second_user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.require_within_quota(TRANSFER_KEY, &managed_biguint!(200));
+            assert_eq!(
+                sc.get_remaining_global_quota(managed_buffer!(TRANSFER_KEY))
+                    .into_option(),
+                Some(managed_biguint!(100))
+            );
+        })
+        .assert_ok();
+
+    // never more than the maximum
+    b_mock.set_block_timestamp(5_000);
+    b_mock
+        .execute_query(&rate_limit_sc, |sc| {
+            assert_eq!(
+                sc.get_remaining_global_quota(managed_buffer!(TRANSFER_KEY))
+                    .into_option(),
+                Some(managed_biguint!(1_000))
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn rate_limit_token_bucket_partial_refill_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let user = b_mock.create_user_account(&rust_zero);
+    let rate_limit_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    // refills at 3 per 10 seconds, i.e. one token every 3.33 seconds
+    b_mock.set_block_timestamp(1_000);
+    b_mock
+        .execute_tx(&owner, &rate_limit_sc, &rust_zero, |sc| {
+            sc.set_rate_limit(
+                managed_buffer!(TRANSFER_KEY),
+                RateLimitScope::Global,
+                RateLimitAlgorithm::TokenBucket,
+                RateLimitWindowUnit::Seconds,
+                10,
+                managed_biguint!(3),
+            );
+        })
+        .assert_ok();
+    b_mock
+        .execute_tx(&user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.require_within_quota(TRANSFER_KEY, &managed_biguint!(3));
+        })
+        .assert_ok();
+
+    // 5 seconds refill 1 token, the remaining 1.67 seconds are not lost
+    b_mock.set_block_timestamp(1_005);
+    b_mock
+        .execute_tx(&user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.require_within_quota(TRANSFER_KEY, &managed_biguint!(1));
+        })
+        .assert_ok();
+
+    b_mock.set_block_timestamp(1_007);
+    b_mock
+        .execute_query(&rate_limit_sc, |sc| {
+            assert_eq!(
+                sc.get_remaining_global_quota(managed_buffer!(TRANSFER_KEY))
+                    .into_option(),
+                Some(managed_biguint!(1))
+            );
+        })
+        .assert_ok();
+}
+
+/// Polling every second must not grant more than `max_amount` per window, nor less.
+#[test]
+fn rate_limit_token_bucket_full_window_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let user = b_mock.create_user_account(&rust_zero);
+    let rate_limit_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    b_mock.set_block_timestamp(1_000);
+    b_mock
+        .execute_tx(&owner, &rate_limit_sc, &rust_zero, |sc| {
+            sc.set_rate_limit(
+                managed_buffer!(TRANSFER_KEY),
+                RateLimitScope::Global,
+                RateLimitAlgorithm::TokenBucket,
+                RateLimitWindowUnit::Seconds,
+                10,
+                managed_biguint!(3),
+            );
+        })
+        .assert_ok();
+    b_mock
+        .execute_tx(&user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.require_within_quota(TRANSFER_KEY, &managed_biguint!(3));
+        })
+        .assert_ok();
+
+    let mut total_granted = 0u64;
+    for timestamp in 1_001..=1_100 {
+        b_mock.set_block_timestamp(timestamp);
+        b_mock
+            .execute_tx(&user, &rate_limit_sc, &rust_zero, |sc| {
+                let available = sc
+                    .get_remaining_global_quota(managed_buffer!(TRANSFER_KEY))
+                    .into_option()
+                    .unwrap()
+                    .to_u64()
+                    .unwrap();
+                if available > 0 {
+                    sc.require_within_quota(TRANSFER_KEY, &managed_biguint!(available));
+                    total_granted += available;
+                }
+            })
+            .assert_ok();
+        if timestamp % 10 == 0 {
+            assert_eq!(total_granted, (timestamp - 1_000) * 3 / 10);
+        }
+    }
+}
+
+#[test]
+fn rate_limit_global_and_per_address_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let first_user = b_mock.create_user_account(&rust_zero);
+    let second_user = b_mock.create_user_account(&rust_zero);
+    let rate_limit_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    b_mock
+        .execute_tx(&owner, &rate_limit_sc, &rust_zero, |sc| {
+            sc.set_rate_limit(
+                managed_buffer!(TRANSFER_KEY),
+                RateLimitScope::Global,
+                RateLimitAlgorithm::FixedWindow,
+                RateLimitWindowUnit::Rounds,
+                100,
+                managed_biguint!(500),
+            );
+            sc.set_rate_limit(
+                managed_buffer!(TRANSFER_KEY),
+                RateLimitScope::PerAddress,
+                RateLimitAlgorithm::FixedWindow,
+                RateLimitWindowUnit::Rounds,
+                100,
+                managed_biguint!(300),
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&first_user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.require_within_quota(TRANSFER_KEY, &managed_biguint!(301));
+        })
+        .assert_user_error("Address rate limit exceeded for transfer");
+    b_mock
+        .execute_tx(&first_user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.require_within_quota(TRANSFER_KEY, &managed_biguint!(300));
+        })
+        .assert_ok();
+    b_mock
+        .execute_tx(&second_user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.require_within_quota(TRANSFER_KEY, &managed_biguint!(250));
+        })
+        .assert_user_error("Global rate limit exceeded for transfer");
+
+    // setting a new limit starts from scratch
+    b_mock.set_block_round(50);
+    b_mock
+        .execute_tx(&owner, &rate_limit_sc, &rust_zero, |sc| {
+            sc.set_rate_limit(
+                managed_buffer!(TRANSFER_KEY),
+                RateLimitScope::PerAddress,
+                RateLimitAlgorithm::FixedWindow,
+                RateLimitWindowUnit::Rounds,
+                100,
+                managed_biguint!(400),
+            );
+        })
+        .assert_ok();
+    b_mock
+        .execute_tx(&first_user, &rate_limit_sc, &rust_zero, |sc| {
+            sc.require_within_quota(TRANSFER_KEY, &managed_biguint!(200));
+            assert_eq!(
+                sc.get_remaining_address_quota(
+                    managed_buffer!(TRANSFER_KEY),
+                    managed_address!(&first_user)
+                )
+                .into_option(),
+                Some(managed_biguint!(200))
+            );
+            assert_eq!(
+                sc.get_remaining_global_quota(managed_buffer!(TRANSFER_KEY))
+                    .into_option(),
+                Some(managed_biguint!(0))
+            );
+            let config = sc
+                .get_rate_limit(managed_buffer!(TRANSFER_KEY), RateLimitScope::Global)
+                .into_option()
+                .unwrap();
+            assert_eq!(config.max_amount, managed_biguint!(500));
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(&rate_limit_sc, |sc| {
+            assert!(sc
+                .get_rate_limit(ManagedBuffer::new(), RateLimitScope::Global)
+                .into_option()
+                .is_none());
+        })
+        .assert_ok();
+}
@@ -0,0 +1,321 @@
+use elrond_wasm::{
+    contract_base::ContractAbiProvider,
+    types::{Address, ManagedBuffer, ManagedVec, MultiValueEncoded},
+};
+use elrond_wasm_debug::{
+    managed_address, managed_biguint, managed_buffer, rust_biguint,
+    testing_framework::BlockchainStateWrapper, DebugApi,
+};
+use elrond_wasm_modules::{
+    roles::RolesModule,
+    timelock::{
+        TimelockCall, TimelockModule, TimelockOperationStatus, TIMELOCK_CANCELLER_ROLE,
+        TIMELOCK_EXECUTOR_ROLE, TIMELOCK_PROPOSER_ROLE,
+    },
+};
+
+const MIN_DELAY: u64 = 100;
+const START_TIMESTAMP: u64 = 1_000;
+const GAS_LIMIT: u64 = 1_000_000;
+
+// Role restrictions are only enforced by the endpoint wrappers,
+// so they are covered by the `use_module_timelock` scenario instead.
+
+fn egld_transfer_call(to: &Address, amount: u64) -> TimelockCall<DebugApi> {
+    TimelockCall {
+        to: managed_address!(to),
+        egld_amount: managed_biguint!(amount),
+        esdt_payments: ManagedVec::new(),
+        endpoint_name: ManagedBuffer::new(),
+        arguments: ManagedVec::new(),
+        gas_limit: GAS_LIMIT,
+    }
+}
+
+fn update_min_delay_call(sc_address: &Address, new_min_delay: u64) -> TimelockCall<DebugApi> {
+    TimelockCall {
+        to: managed_address!(sc_address),
+        egld_amount: managed_biguint!(0),
+        esdt_payments: ManagedVec::new(),
+        endpoint_name: managed_buffer!(b"updateTimelockMinDelay"),
+        arguments: ManagedVec::from_single_item(ManagedBuffer::new_from_bytes(
+            &new_min_delay.to_be_bytes(),
+        )),
+        gas_limit: GAS_LIMIT,
+    }
+}
+
+#[test]
+fn timelock_module_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let proposer = b_mock.create_user_account(&rust_zero);
+    let executor = b_mock.create_user_account(&rust_zero);
+    let canceller = b_mock.create_user_account(&rust_zero);
+    let recipient = b_mock.create_user_account(&rust_zero);
+    let timelock_sc = b_mock.create_sc_account(
+        &rust_biguint!(1_000),
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+    let sc_address = timelock_sc.address_ref().clone();
+
+    b_mock
+        .execute_tx(&owner, &timelock_sc, &rust_zero, |sc| {
+            sc.init_timelock_module(MIN_DELAY);
+            sc.grant_role(
+                managed_buffer!(TIMELOCK_PROPOSER_ROLE),
+                managed_address!(&proposer),
+            );
+            sc.grant_role(
+                managed_buffer!(TIMELOCK_EXECUTOR_ROLE),
+                managed_address!(&executor),
+            );
+            sc.grant_role(
+                managed_buffer!(TIMELOCK_CANCELLER_ROLE),
+                managed_address!(&canceller),
+            );
+        })
+        .assert_ok();
+    b_mock.set_block_timestamp(START_TIMESTAMP);
+
+    b_mock
+        .execute_tx(&proposer, &timelock_sc, &rust_zero, |sc| {
+            sc.schedule_call(MIN_DELAY - 1, egld_transfer_call(&recipient, 300));
+        })
+        .assert_user_error("Delay is lower than the minimum delay");
+
+    b_mock
+        .execute_tx(&proposer, &timelock_sc, &rust_zero, |sc| {
+            let operation_id = sc.schedule_call(MIN_DELAY, egld_transfer_call(&recipient, 300));
+            assert_eq!(operation_id, 1);
+            assert_eq!(
+                sc.get_timelock_operation_status(operation_id),
+                TimelockOperationStatus::Waiting
+            );
+        })
+        .assert_ok();
+
+    // too early
+    b_mock.set_block_timestamp(START_TIMESTAMP + MIN_DELAY - 1);
+    b_mock
+        .execute_tx(&executor, &timelock_sc, &rust_zero, |sc| {
+            sc.execute_operation(1);
+        })
+        .assert_user_error("Operation is not ready");
+
+    b_mock.set_block_timestamp(START_TIMESTAMP + MIN_DELAY);
+    b_mock
+        .execute_tx(&executor, &timelock_sc, &rust_zero, |sc| {
+            sc.execute_operation(1);
+        })
+        .assert_ok();
+    b_mock.check_egld_balance(&recipient, &rust_biguint!(300));
+
+    // operations can only be executed once
+    b_mock
+        .execute_tx(&executor, &timelock_sc, &rust_zero, |sc| {
+            assert_eq!(
+                sc.get_timelock_operation_status(1),
+                TimelockOperationStatus::None
+            );
+            sc.execute_operation(1);
+        })
+        .assert_user_error("Operation is not ready");
+
+    // the min delay can only be changed through the timelock
+    b_mock
+        .execute_tx(&owner, &timelock_sc, &rust_zero, |sc| {
+            sc.update_timelock_min_delay(0);
+        })
+        .assert_user_error("Only the SC itself may call this function");
+
+    b_mock
+        .execute_tx(&proposer, &timelock_sc, &rust_zero, |sc| {
+            let mut calls = MultiValueEncoded::new();
+            calls.push(update_min_delay_call(&sc_address, 2 * MIN_DELAY));
+            calls.push(egld_transfer_call(&recipient, 100));
+            assert_eq!(sc.schedule_batch(MIN_DELAY, calls), 2);
+        })
+        .assert_ok();
+
+    b_mock.set_block_timestamp(START_TIMESTAMP + 2 * MIN_DELAY);
+    b_mock
+        .execute_tx(&executor, &timelock_sc, &rust_zero, |sc| {
+            sc.execute_operation(2);
+        })
+        .assert_ok();
+    b_mock.check_egld_balance(&recipient, &rust_biguint!(400));
+    b_mock
+        .execute_query(&timelock_sc, |sc| {
+            assert_eq!(sc.timelock_min_delay().get(), 2 * MIN_DELAY);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn timelock_module_cancel_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let proposer = b_mock.create_user_account(&rust_zero);
+    let canceller = b_mock.create_user_account(&rust_zero);
+    let timelock_sc = b_mock.create_sc_account(
+        &rust_biguint!(1_000),
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    b_mock
+        .execute_tx(&owner, &timelock_sc, &rust_zero, |sc| {
+            sc.init_timelock_module(MIN_DELAY);
+            sc.grant_role(
+                managed_buffer!(TIMELOCK_PROPOSER_ROLE),
+                managed_address!(&proposer),
+            );
+            sc.grant_role(
+                managed_buffer!(TIMELOCK_CANCELLER_ROLE),
+                managed_address!(&canceller),
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&proposer, &timelock_sc, &rust_zero, |sc| {
+            sc.schedule_call(MIN_DELAY, egld_transfer_call(&proposer, 1_000));
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&canceller, &timelock_sc, &rust_zero, |sc| {
+            sc.cancel_operation(1);
+            assert_eq!(
+                sc.get_timelock_operation_status(1),
+                TimelockOperationStatus::None
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&canceller, &timelock_sc, &rust_zero, |sc| {
+            sc.cancel_operation(1);
+        })
+        .assert_user_error("Operation does not exist");
+}
+
+#[test]
+fn timelock_module_gas_overflow_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let recipient = b_mock.create_user_account(&rust_zero);
+    let timelock_sc = b_mock.create_sc_account(
+        &rust_biguint!(1_000),
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    b_mock
+        .execute_tx(&owner, &timelock_sc, &rust_zero, |sc| {
+            sc.init_timelock_module(MIN_DELAY);
+            sc.grant_role(
+                managed_buffer!(TIMELOCK_PROPOSER_ROLE),
+                managed_address!(&owner),
+            );
+            sc.grant_role(
+                managed_buffer!(TIMELOCK_EXECUTOR_ROLE),
+                managed_address!(&owner),
+            );
+        })
+        .assert_ok();
+    b_mock.set_block_timestamp(START_TIMESTAMP);
+
+    b_mock
+        .execute_tx(&owner, &timelock_sc, &rust_zero, |sc| {
+            let mut calls = MultiValueEncoded::new();
+            for _ in 0..2 {
+                let mut call = egld_transfer_call(&recipient, 100);
+                call.gas_limit = u64::MAX / 2 + 1;
+                calls.push(call);
+            }
+            assert_eq!(sc.schedule_batch(MIN_DELAY, calls), 1);
+        })
+        .assert_ok();
+
+    b_mock.set_block_timestamp(START_TIMESTAMP + MIN_DELAY);
+    b_mock
+        .execute_tx(&owner, &timelock_sc, &rust_zero, |sc| {
+            sc.execute_operation(1);
+        })
+        .assert_user_error("Total gas limit of the calls overflows");
+    b_mock.check_egld_balance(&recipient, &rust_zero);
+}
+
+#[test]
+fn timelock_module_delay_overflow_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let recipient = b_mock.create_user_account(&rust_zero);
+    let timelock_sc = b_mock.create_sc_account(
+        &rust_biguint!(1_000),
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    b_mock
+        .execute_tx(&owner, &timelock_sc, &rust_zero, |sc| {
+            sc.init_timelock_module(MIN_DELAY);
+            sc.grant_role(
+                managed_buffer!(TIMELOCK_PROPOSER_ROLE),
+                managed_address!(&owner),
+            );
+        })
+        .assert_ok();
+    b_mock.set_block_timestamp(START_TIMESTAMP);
+
+    // would wrap around to a timestamp in the past, making the operation ready at once
+    b_mock
+        .execute_tx(&owner, &timelock_sc, &rust_zero, |sc| {
+            let mut calls = MultiValueEncoded::new();
+            calls.push(egld_transfer_call(&recipient, 100));
+            sc.schedule_batch(u64::MAX, calls);
+        })
+        .assert_user_error("Delay is too large");
+
+    b_mock
+        .execute_query(&timelock_sc, |sc| {
+            assert!(sc.timelock_operation(1).is_empty());
+        })
+        .assert_ok();
+}
+
+/// `#[only_role]` takes a literal, so the role constants must be kept in sync with it by hand.
+#[test]
+fn timelock_module_role_constants_test() {
+    let abi = use_module::AbiProvider::abi();
+    let endpoint_role = |endpoint_name: &str| {
+        abi.endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == endpoint_name)
+            .unwrap_or_else(|| panic!("endpoint {endpoint_name} not found"))
+            .only_role
+            .map(str::as_bytes)
+    };
+
+    assert_eq!(endpoint_role("scheduleCall"), Some(TIMELOCK_PROPOSER_ROLE));
+    assert_eq!(endpoint_role("scheduleBatch"), Some(TIMELOCK_PROPOSER_ROLE));
+    assert_eq!(
+        endpoint_role("executeOperation"),
+        Some(TIMELOCK_EXECUTOR_ROLE)
+    );
+    assert_eq!(
+        endpoint_role("cancelOperation"),
+        Some(TIMELOCK_CANCELLER_ROLE)
+    );
+}
@@ -3,14 +3,21 @@ use elrond_wasm::{
     contract_base::ContractBase,
     elrond_codec::Empty,
     storage::mappers::StorageTokenWrapper,
-    types::{EsdtLocalRole, EsdtTokenPayment, ManagedVec},
+    types::{
+        EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment, EsdtLocalRole, EsdtTokenPayment,
+        ManagedVec,
+    },
 };
 use elrond_wasm_debug::{
     managed_address, managed_biguint, managed_token_id, rust_biguint,
-    testing_framework::BlockchainStateWrapper, tx_mock::TxInputESDT,
+    testing_framework::BlockchainStateWrapper, tx_mock::TxInputESDT, DebugApi,
 };
-use elrond_wasm_modules::token_merge::{
-    merged_token_instances::MergedTokenInstances, merged_token_setup::MergedTokenSetupModule,
+use elrond_wasm_modules::{
+    royalties::{CreatorRoyaltyShare, CreatorRoyaltyShares, RoyaltiesModule},
+    token_merge::{
+        merged_token_instances::MergedTokenInstances,
+        merged_token_setup::{MergedTokenSetupModule, DIFFERENT_CREATOR_ERR_MSG},
+    },
 };
 use use_module::token_merge_mod_impl::{CustomAttributes, TokenMergeModImpl};
 
@@ -714,6 +721,165 @@ fn custom_attributes_test() {
     );
 }
 
+#[test]
+fn mixed_creators_merge_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let user = b_mock.create_user_account(&rust_zero);
+    let buyer = b_mock.create_user_account(&rust_biguint!(20_000));
+    let first_creator = b_mock.create_user_account(&rust_zero);
+    let second_creator = b_mock.create_user_account(&rust_zero);
+    let merging_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    b_mock
+        .execute_tx(&owner, &merging_sc, &rust_zero, |sc| {
+            sc.merged_token()
+                .set_token_id(managed_token_id!(MERGED_TOKEN_ID));
+            let _ = sc
+                .mergeable_tokens_whitelist()
+                .insert(managed_token_id!(NFT_TOKEN_ID));
+            let _ = sc
+                .mergeable_tokens_whitelist()
+                .insert(managed_token_id!(FUNGIBLE_TOKEN_ID));
+        })
+        .assert_ok();
+    b_mock.set_esdt_local_roles(
+        merging_sc.address_ref(),
+        MERGED_TOKEN_ID,
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftBurn],
+    );
+
+    b_mock.set_esdt_balance(&user, FUNGIBLE_TOKEN_ID, &rust_biguint!(FUNGIBLE_AMOUNT));
+    b_mock.set_nft_balance_all_properties(
+        &user,
+        NFT_TOKEN_ID,
+        FIRST_NFT_NONCE,
+        &rust_biguint!(NFT_AMOUNT),
+        &FIRST_ATTRIBUTES.to_vec(),
+        FIRST_ROYALTIES,
+        Some(&first_creator),
+        None,
+        None,
+        &uris_to_vec(FIRST_URIS),
+    );
+    b_mock.set_nft_balance_all_properties(
+        &user,
+        NFT_TOKEN_ID,
+        SECOND_NFT_NONCE,
+        &rust_biguint!(NFT_AMOUNT),
+        &SECOND_ATTRIBUTES.to_vec(),
+        SECOND_ROYALTIES,
+        Some(&second_creator),
+        None,
+        None,
+        &uris_to_vec(SECOND_URIS),
+    );
+
+    let transfers = vec![
+        TxInputESDT {
+            token_identifier: NFT_TOKEN_ID.to_vec(),
+            nonce: FIRST_NFT_NONCE,
+            value: rust_biguint!(NFT_AMOUNT),
+        },
+        TxInputESDT {
+            token_identifier: NFT_TOKEN_ID.to_vec(),
+            nonce: SECOND_NFT_NONCE,
+            value: rust_biguint!(NFT_AMOUNT),
+        },
+        TxInputESDT {
+            token_identifier: FUNGIBLE_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: rust_biguint!(FUNGIBLE_AMOUNT),
+        },
+    ];
+
+    // the default mode still requires a single creator
+    b_mock
+        .execute_esdt_multi_transfer(&user, &merging_sc, &transfers, |sc| {
+            let _ = sc.merge_tokens_endpoint();
+        })
+        .assert_user_error(core::str::from_utf8(DIFFERENT_CREATOR_ERR_MSG).unwrap());
+
+    b_mock
+        .execute_esdt_multi_transfer(&user, &merging_sc, &transfers, |sc| {
+            let merged_token = sc.merge_tokens_mixed_creators_endpoint();
+            assert_eq!(merged_token.token_nonce, 1);
+
+            let merged_token_data = sc.blockchain().get_esdt_token_data(
+                &managed_address!(&user),
+                &managed_token_id!(MERGED_TOKEN_ID),
+                1,
+            );
+            let mut expected_shares = CreatorRoyaltyShares::<DebugApi>::new();
+            expected_shares.push(CreatorRoyaltyShare {
+                creator: managed_address!(&first_creator),
+                share: managed_biguint!(FIRST_ROYALTIES),
+            });
+            expected_shares.push(CreatorRoyaltyShare {
+                creator: managed_address!(&second_creator),
+                share: managed_biguint!(SECOND_ROYALTIES),
+            });
+            let actual_shares: CreatorRoyaltyShares<DebugApi> =
+                merged_token_data.decode_attributes();
+            assert_eq!(expected_shares, actual_shares);
+
+            assert_eq!(
+                merged_token_data.royalties,
+                managed_biguint!(SECOND_ROYALTIES)
+            );
+        })
+        .assert_ok();
+
+    // selling the merged token: 50% royalties, split 1:5 between the two creators
+    b_mock
+        .execute_tx(&buyer, &merging_sc, &rust_biguint!(10_000), |sc| {
+            let merged_token_data = sc.blockchain().get_esdt_token_data(
+                &managed_address!(&user),
+                &managed_token_id!(MERGED_TOKEN_ID),
+                1,
+            );
+            let payment = EgldOrEsdtTokenPayment::new(
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                managed_biguint!(10_000),
+            );
+            let remainder = sc.send_royalties_by_shares(
+                &merged_token_data.royalties,
+                &merged_token_data.decode_attributes(),
+                &payment,
+            );
+            assert_eq!(remainder, managed_biguint!(5_000));
+        })
+        .assert_ok();
+    b_mock.check_egld_balance(&first_creator, &rust_biguint!(833));
+    b_mock.check_egld_balance(&second_creator, &rust_biguint!(4_167));
+
+    // selling a regular NFT: 10% royalties, all to its creator
+    b_mock
+        .execute_tx(&buyer, &merging_sc, &rust_biguint!(10_000), |sc| {
+            let token_data = sc.blockchain().get_esdt_token_data(
+                &sc.blockchain().get_sc_address(),
+                &managed_token_id!(NFT_TOKEN_ID),
+                FIRST_NFT_NONCE,
+            );
+            let payment = EgldOrEsdtTokenPayment::new(
+                EgldOrEsdtTokenIdentifier::egld(),
+                0,
+                managed_biguint!(10_000),
+            );
+            let remainder = sc.send_royalties(&token_data, &payment);
+            assert_eq!(remainder, managed_biguint!(9_000));
+        })
+        .assert_ok();
+    b_mock.check_egld_balance(&first_creator, &rust_biguint!(1_833));
+}
+
 fn uris_to_vec(uris: &[&[u8]]) -> Vec<Vec<u8>> {
     let mut out = Vec::new();
     for uri in uris {
@@ -28,6 +28,26 @@ fn use_module_only_admin_go() {
     elrond_wasm_debug::mandos_go("mandos/use_module_only_admin.scen.json");
 }
 
+#[test]
+fn use_module_only_role_go() {
+    elrond_wasm_debug::mandos_go("mandos/use_module_only_role.scen.json");
+}
+
+#[test]
+fn use_module_rate_limit_go() {
+    elrond_wasm_debug::mandos_go("mandos/use_module_rate_limit.scen.json");
+}
+
+#[test]
+fn use_module_timelock_go() {
+    elrond_wasm_debug::mandos_go("mandos/use_module_timelock.scen.json");
+}
+
+#[test]
+fn use_module_vesting_go() {
+    elrond_wasm_debug::mandos_go("mandos/use_module_vesting.scen.json");
+}
+
 #[test]
 fn use_module_no_endpoint_go() {
     elrond_wasm_debug::mandos_go("mandos/use_module_no_endpoint.scen.json");
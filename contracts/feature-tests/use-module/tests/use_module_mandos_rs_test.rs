@@ -76,6 +76,26 @@ fn use_module_only_admin_rs() {
     elrond_wasm_debug::mandos_rs("mandos/use_module_only_admin.scen.json", world());
 }
 
+#[test]
+fn use_module_only_role_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/use_module_only_role.scen.json", world());
+}
+
+#[test]
+fn use_module_rate_limit_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/use_module_rate_limit.scen.json", world());
+}
+
+#[test]
+fn use_module_timelock_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/use_module_timelock.scen.json", world());
+}
+
+#[test]
+fn use_module_vesting_rs() {
+    elrond_wasm_debug::mandos_rs("mandos/use_module_vesting.scen.json", world());
+}
+
 #[test]
 fn use_module_no_endpoint_rs() {
     elrond_wasm_debug::mandos_rs("mandos/use_module_no_endpoint.scen.json", world());
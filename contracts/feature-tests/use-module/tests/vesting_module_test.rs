@@ -0,0 +1,320 @@
+use elrond_wasm::types::BigUint;
+use elrond_wasm_debug::{
+    managed_address, managed_biguint, rust_biguint, testing_framework::BlockchainStateWrapper,
+    DebugApi,
+};
+use elrond_wasm_modules::vesting::{VestingModule, VestingSchedule};
+
+static VESTED_TOKEN_ID: &[u8] = b"VEST-123456";
+
+#[test]
+fn vesting_linear_egld_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(1_000));
+    let alice = b_mock.create_user_account(&rust_zero);
+    let bob = b_mock.create_user_account(&rust_zero);
+    let vesting_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    b_mock
+        .execute_tx(&owner, &vesting_sc, &rust_biguint!(1_000), |sc| {
+            let grant_id = sc.create_vesting_grant(
+                managed_address!(&alice),
+                VestingSchedule::Linear {
+                    start_timestamp: 100,
+                    duration: 1_000,
+                },
+                false,
+            );
+            assert_eq!(grant_id, 1);
+        })
+        .assert_ok();
+
+    b_mock.set_block_timestamp(50);
+    b_mock
+        .execute_tx(&alice, &vesting_sc, &rust_zero, |sc| {
+            sc.claim_vested(1);
+        })
+        .assert_user_error("Nothing to claim");
+
+    b_mock.set_block_timestamp(600);
+    b_mock
+        .execute_query(&vesting_sc, |sc| {
+            assert_eq!(sc.get_vested_amount(1, 50), managed_biguint!(0));
+            assert_eq!(sc.get_locked_amount(1, 50), managed_biguint!(1_000));
+            assert_eq!(sc.get_claimable_amount(1, 600), managed_biguint!(500));
+            assert_eq!(sc.get_locked_amount(1, 600), managed_biguint!(500));
+            assert_eq!(sc.get_claimable_amount(1, 1_100), managed_biguint!(1_000));
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&bob, &vesting_sc, &rust_zero, |sc| {
+            sc.claim_vested(1);
+        })
+        .assert_user_error("Only the beneficiary can claim");
+
+    b_mock
+        .execute_tx(&alice, &vesting_sc, &rust_zero, |sc| {
+            let payment = sc.claim_vested(1);
+            assert_eq!(payment.amount, managed_biguint!(500));
+        })
+        .assert_ok();
+    b_mock.check_egld_balance(&alice, &rust_biguint!(500));
+
+    b_mock.set_block_timestamp(2_000);
+    b_mock
+        .execute_tx(&alice, &vesting_sc, &rust_zero, |sc| {
+            sc.claim_vested(1);
+        })
+        .assert_ok();
+    b_mock.check_egld_balance(&alice, &rust_biguint!(1_000));
+
+    b_mock
+        .execute_tx(&alice, &vesting_sc, &rust_zero, |sc| {
+            sc.claim_vested(1);
+        })
+        .assert_user_error("Nothing to claim");
+}
+
+#[test]
+fn vesting_cliff_and_periodic_esdt_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    b_mock.set_esdt_balance(&owner, VESTED_TOKEN_ID, &rust_biguint!(2_200));
+    let bob = b_mock.create_user_account(&rust_zero);
+    let vesting_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    b_mock
+        .execute_esdt_transfer(
+            &owner,
+            &vesting_sc,
+            VESTED_TOKEN_ID,
+            0,
+            &rust_biguint!(1_200),
+            |sc| {
+                sc.create_vesting_grant(
+                    managed_address!(&bob),
+                    VestingSchedule::CliffLinear {
+                        start_timestamp: 0,
+                        cliff_duration: 300,
+                        duration: 1_200,
+                    },
+                    false,
+                );
+            },
+        )
+        .assert_ok();
+    b_mock
+        .execute_esdt_transfer(
+            &owner,
+            &vesting_sc,
+            VESTED_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.create_vesting_grant(
+                    managed_address!(&bob),
+                    VestingSchedule::Periodic {
+                        start_timestamp: 0,
+                        period: 100,
+                        num_periods: 4,
+                    },
+                    false,
+                );
+            },
+        )
+        .assert_ok();
+
+    b_mock
+        .execute_query(&vesting_sc, |sc| {
+            assert_eq!(sc.get_vested_amount(1, 299), managed_biguint!(0));
+            assert_eq!(sc.get_vested_amount(1, 300), managed_biguint!(300));
+            assert_eq!(sc.get_vested_amount(2, 99), managed_biguint!(0));
+            assert_eq!(sc.get_vested_amount(2, 250), managed_biguint!(500));
+            assert_eq!(sc.get_vested_amount(2, 1_000), managed_biguint!(1_000));
+        })
+        .assert_ok();
+
+    b_mock.set_block_timestamp(50);
+    b_mock
+        .execute_tx(&bob, &vesting_sc, &rust_zero, |sc| {
+            sc.claim_all_vested();
+        })
+        .assert_user_error("Nothing to claim");
+
+    b_mock.set_block_timestamp(300);
+    b_mock
+        .execute_tx(&bob, &vesting_sc, &rust_zero, |sc| {
+            assert_eq!(sc.claim_all_vested().len(), 2);
+        })
+        .assert_ok();
+    b_mock.check_esdt_balance(&bob, VESTED_TOKEN_ID, &rust_biguint!(300 + 750));
+}
+
+#[test]
+fn vesting_revoke_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(2_000));
+    let alice = b_mock.create_user_account(&rust_zero);
+    let vesting_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    let linear_schedule = VestingSchedule::Linear {
+        start_timestamp: 0,
+        duration: 1_000,
+    };
+    b_mock
+        .execute_tx(&owner, &vesting_sc, &rust_biguint!(1_000), |sc| {
+            sc.create_vesting_grant(managed_address!(&alice), linear_schedule.clone(), true);
+        })
+        .assert_ok();
+    b_mock
+        .execute_tx(&owner, &vesting_sc, &rust_biguint!(1_000), |sc| {
+            sc.create_vesting_grant(managed_address!(&alice), linear_schedule.clone(), false);
+        })
+        .assert_ok();
+    b_mock
+        .execute_tx(&owner, &vesting_sc, &rust_zero, |sc| {
+            sc.create_vesting_grant(
+                managed_address!(&alice),
+                VestingSchedule::Periodic {
+                    start_timestamp: 0,
+                    period: 0,
+                    num_periods: 4,
+                },
+                true,
+            );
+        })
+        .assert_user_error("Vesting amount must be positive");
+    b_mock
+        .execute_tx(&owner, &vesting_sc, &rust_biguint!(0), |sc| {
+            sc.revoke_vesting_grant(3);
+        })
+        .assert_user_error("Vesting grant does not exist");
+
+    b_mock.set_block_timestamp(400);
+    b_mock
+        .execute_tx(&owner, &vesting_sc, &rust_zero, |sc| {
+            sc.revoke_vesting_grant(2);
+        })
+        .assert_user_error("Vesting grant is not revocable");
+    b_mock
+        .execute_tx(&owner, &vesting_sc, &rust_zero, |sc| {
+            let returned_amount = sc.revoke_vesting_grant(1);
+            assert_eq!(returned_amount, managed_biguint!(600));
+        })
+        .assert_ok();
+    b_mock.check_egld_balance(&owner, &rust_biguint!(600));
+
+    b_mock
+        .execute_tx(&owner, &vesting_sc, &rust_zero, |sc| {
+            sc.revoke_vesting_grant(1);
+        })
+        .assert_user_error("Vesting grant already revoked");
+
+    // what was unlocked before revoking can still be claimed, nothing more
+    b_mock.set_block_timestamp(5_000);
+    b_mock
+        .execute_tx(&alice, &vesting_sc, &rust_zero, |sc| {
+            assert_eq!(sc.get_locked_amount(1, 5_000), managed_biguint!(0));
+            let payment = sc.claim_vested(1);
+            assert_eq!(payment.amount, managed_biguint!(400));
+        })
+        .assert_ok();
+    b_mock.check_egld_balance(&alice, &rust_biguint!(400));
+}
+
+#[test]
+fn vesting_invalid_schedule_test() {
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_biguint!(1_000));
+    let vesting_sc = b_mock.create_sc_account(
+        &rust_biguint!(0),
+        Some(&owner),
+        use_module::contract_obj,
+        "wasm path",
+    );
+
+    for schedule in [
+        VestingSchedule::Linear {
+            start_timestamp: 0,
+            duration: 0,
+        },
+        VestingSchedule::CliffLinear {
+            start_timestamp: 0,
+            cliff_duration: 200,
+            duration: 100,
+        },
+        VestingSchedule::Periodic {
+            start_timestamp: 0,
+            period: 0,
+            num_periods: 4,
+        },
+        // schedules that would end after the largest timestamp
+        VestingSchedule::Linear {
+            start_timestamp: u64::MAX - 10,
+            duration: 100,
+        },
+        VestingSchedule::CliffLinear {
+            start_timestamp: u64::MAX - 10,
+            cliff_duration: 5,
+            duration: 100,
+        },
+        VestingSchedule::Periodic {
+            start_timestamp: 1_000,
+            period: u64::MAX / 2,
+            num_periods: 2,
+        },
+    ] {
+        b_mock
+            .execute_tx(&owner, &vesting_sc, &rust_biguint!(1_000), |sc| {
+                sc.create_vesting_grant(managed_address!(&owner), schedule, false);
+            })
+            .assert_user_error("Invalid vesting schedule");
+    }
+}
+
+#[test]
+fn vesting_schedule_end_of_time_test() {
+    let _ = DebugApi::dummy();
+    let total: BigUint<DebugApi> = managed_biguint!(1_000);
+    let end_of_time = VestingSchedule::Linear {
+        start_timestamp: u64::MAX - 100,
+        duration: 100,
+    };
+    assert!(end_of_time.is_valid());
+    assert_eq!(
+        end_of_time.vested_amount(&total, u64::MAX - 50),
+        managed_biguint!(500)
+    );
+    assert_eq!(end_of_time.vested_amount(&total, u64::MAX), total);
+
+    let cliff_at_end_of_time = VestingSchedule::CliffLinear {
+        start_timestamp: u64::MAX - 100,
+        cliff_duration: 100,
+        duration: 100,
+    };
+    assert!(cliff_at_end_of_time.is_valid());
+    assert_eq!(
+        cliff_at_end_of_time.vested_amount(&total, u64::MAX - 1),
+        managed_biguint!(0)
+    );
+    assert_eq!(cliff_at_end_of_time.vested_amount(&total, u64::MAX), total);
+}
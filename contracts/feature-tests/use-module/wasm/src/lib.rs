@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           60
+// Endpoints:                           98
 // Async Callback:                       1
-// Total number of exported functions:  62
+// Total number of exported functions: 100
 
 #![no_std]
 
@@ -15,6 +15,7 @@ elrond_wasm_node::wasm_endpoints! {
     use_module
     (
         checkFeatureGuard
+        rateLimitedCall
         checkPause
         call_mod_a
         call_mod_b
@@ -23,9 +24,11 @@ elrond_wasm_node::wasm_endpoints! {
         call_derived_not_owner_only
         only_admin_mod_endpoint
         call_derived_not_admin_only
+        only_minter_mod_endpoint
         countTo100
         mergeTokens
         mergeTokensCustomAttributes
+        mergeTokensMixedCreators
         splitTokens
         splitTokenPartial
         claimDeveloperRewards
@@ -43,6 +46,7 @@ elrond_wasm_node::wasm_endpoints! {
         getProposer
         getProposalDescription
         getProposalActions
+        getVotingPowerAt
         getProposalVotes
         getTotalVotes
         getTotalDownvotes
@@ -52,28 +56,62 @@ elrond_wasm_node::wasm_endpoints! {
         changeVotingPeriodInBlocks
         changeLockTimeAfterVotingEndsInBlocks
         getGovernanceTokenId
+        isSnapshotVotingPower
         getQuorum
         getMinFeeForPropose
         getMinTokenBalanceForProposing
         getVotingDelayInBlocks
         getVotingPeriodInBlocks
         getLockTimeAfterVotingEndsInBlocks
+        claimAirdrop
+        isAirdropClaimed
+        getAirdropToken
+        getAirdropMerkleRoot
         pause
         unpause
         isPaused
+        setRateLimit
+        removeRateLimit
+        getRateLimit
+        getRemainingGlobalQuota
+        getRemainingAddressQuota
         stake
         unstake
         voteSlashMember
         slashMember
+        scheduleCall
+        scheduleBatch
+        executeOperation
+        cancelOperation
+        updateTimelockMinDelay
+        getTimelockOperationStatus
+        getTimelockMinDelay
+        getTimelockOperation
         issueMergedToken
         addMergeableTokensToWhitelist
         removeMergeableTokensFromWhitelist
         getMergedTokenId
         getMergeableTokensWhitelist
+        createVestingGrant
+        claimVested
+        claimAllVested
+        revokeVestingGrant
+        getVestedAmount
+        getClaimableAmount
+        getLockedAmount
+        getBeneficiaryVestingGrants
+        getVestingGrant
         isAdmin
         addAdmin
         removeAdmin
         getAdmins
+        hasRole
+        grantRole
+        revokeRole
+        renounceRole
+        setRoleAdmin
+        getRoleMembers
+        getRoleAdmin
         callBack
     )
 }
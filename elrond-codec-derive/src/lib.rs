@@ -13,42 +13,42 @@ use top_en_derive::{top_encode_impl, top_encode_or_default_impl};
 
 use proc_macro::TokenStream;
 
-#[proc_macro_derive(NestedEncode)]
+#[proc_macro_derive(NestedEncode, attributes(codec))]
 pub fn nested_encode_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
 
     nested_encode_impl(&ast)
 }
 
-#[proc_macro_derive(TopEncode)]
+#[proc_macro_derive(TopEncode, attributes(codec))]
 pub fn top_encode_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
 
     top_encode_impl(&ast)
 }
 
-#[proc_macro_derive(TopEncodeOrDefault)]
+#[proc_macro_derive(TopEncodeOrDefault, attributes(codec))]
 pub fn top_encode_or_default_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
 
     top_encode_or_default_impl(&ast)
 }
 
-#[proc_macro_derive(NestedDecode)]
+#[proc_macro_derive(NestedDecode, attributes(codec))]
 pub fn nested_decode_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
 
     nested_decode_impl(&ast)
 }
 
-#[proc_macro_derive(TopDecode)]
+#[proc_macro_derive(TopDecode, attributes(codec))]
 pub fn top_decode_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
 
     top_decode_impl(&ast)
 }
 
-#[proc_macro_derive(TopDecodeOrDefault)]
+#[proc_macro_derive(TopDecodeOrDefault, attributes(codec))]
 pub fn top_decode_or_default_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
 
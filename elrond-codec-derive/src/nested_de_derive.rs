@@ -20,6 +20,83 @@ pub fn dep_decode_snippet(
     }
 }
 
+/// Decodes a struct field, taking its codec attributes into account.
+/// Missing trailing fields (`#[codec(default)]`) can only be detected when top-decoding,
+/// so the top-level input is only provided then.
+fn struct_field_dep_decode_expr(
+    field: &syn::Field,
+    input_value: &proc_macro2::TokenStream,
+    top_level_input: Option<&proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+    let ty = &field.ty;
+    let attributes = field_codec_attributes(field);
+    let default_expr = quote! {
+        <#ty as core::default::Default>::default()
+    };
+    if attributes.skip {
+        return default_expr;
+    }
+
+    let mut decode_expr = quote! {
+        <#ty as elrond_codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)?
+    };
+    if let (Some(top_level_input), true) = (top_level_input, attributes.default) {
+        decode_expr = quote! {
+            if elrond_codec::NestedDecodeInput::is_depleted(#top_level_input) {
+                #default_expr
+            } else {
+                #decode_expr
+            }
+        };
+    }
+    if let Some(since) = attributes.since {
+        decode_expr = quote! {
+            if codec_version >= #since {
+                #decode_expr
+            } else {
+                #default_expr
+            }
+        };
+    }
+    decode_expr
+}
+
+/// Returns the statements that read and check the version byte of versioned structs,
+/// and the field declarations used to build the struct.
+pub fn struct_dep_decode_snippets(
+    ast: &syn::DeriveInput,
+    data_struct: &syn::DataStruct,
+    input_value: &proc_macro2::TokenStream,
+    top_level_input: Option<&proc_macro2::TokenStream>,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let version = struct_codec_version(ast);
+    validate_struct_codec_attributes(&data_struct.fields, version);
+
+    let version_snippet = if let Some(version) = version {
+        quote! {
+            let codec_version = <u8 as elrond_codec::NestedDecode>::dep_decode_or_handle_err(#input_value, h)?;
+            if codec_version > #version {
+                return core::result::Result::Err(h.handle_error(elrond_codec::DecodeError::UNSUPPORTED_VERSION));
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let field_dep_decode_snippets = fields_decl_syntax(&data_struct.fields, |_, field| {
+        let decode_expr = struct_field_dep_decode_expr(field, input_value, top_level_input);
+        if let Some(ident) = &field.ident {
+            quote! {
+                #ident: #decode_expr
+            }
+        } else {
+            decode_expr
+        }
+    });
+
+    (version_snippet, field_dep_decode_snippets)
+}
+
 pub fn variant_dep_decode_snippets(
     name: &syn::Ident,
     data_enum: &syn::DataEnum,
@@ -28,15 +105,14 @@ pub fn variant_dep_decode_snippets(
     data_enum
 		.variants
 		.iter()
-		.enumerate()
-		.map(|(variant_index, variant)| {
-			let variant_index_u8 = variant_index as u8;
+		.zip(enum_discriminants(data_enum))
+		.map(|(variant, discriminant)| {
 			let variant_ident = &variant.ident;
 			let variant_field_snippets = fields_decl_syntax(&variant.fields, |index, field| {
 				dep_decode_snippet(index, field, input_value)
 			});
 			quote! {
-				#variant_index_u8 => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
+				#discriminant => core::result::Result::Ok( #name::#variant_ident #variant_field_snippets ),
 			}
 		})
 		.collect()
@@ -47,10 +123,8 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
-            let field_dep_decode_snippets =
-                fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_snippet(index, field, &quote! {input})
-                });
+            let (version_snippet, field_dep_decode_snippets) =
+                struct_dep_decode_snippets(ast, data_struct, &quote! {input}, None);
             quote! {
                 impl #impl_generics elrond_codec::NestedDecode for #name #ty_generics #where_clause {
                     fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> core::result::Result<Self, H::HandledErr>
@@ -58,6 +132,7 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
                         I: elrond_codec::NestedDecodeInput,
                         H: elrond_codec::DecodeErrorHandler,
                     {
+                        #version_snippet
                         core::result::Result::Ok(
                             #name #field_dep_decode_snippets
                         )
@@ -70,6 +145,7 @@ pub fn nested_decode_impl(ast: &syn::DeriveInput) -> TokenStream {
                 data_enum.variants.len() < 256,
                 "enums with more than 256 variants not supported"
             );
+            validate_enum_codec_attributes(ast, data_enum);
             let variant_dep_decode_snippets =
                 variant_dep_decode_snippets(name, data_enum, &quote! {input});
 
@@ -8,6 +8,28 @@ pub fn dep_encode_snippet(value: &proc_macro2::TokenStream) -> proc_macro2::Toke
     }
 }
 
+/// Encodes the version byte of versioned structs, followed by all fields that are not skipped.
+pub fn struct_dep_encode_snippets(
+    ast: &syn::DeriveInput,
+    data_struct: &syn::DataStruct,
+) -> Vec<proc_macro2::TokenStream> {
+    let version = struct_codec_version(ast);
+    validate_struct_codec_attributes(&data_struct.fields, version);
+
+    let mut snippets = Vec::new();
+    if let Some(version) = version {
+        snippets.push(dep_encode_snippet(&quote! { #version }));
+    }
+    snippets.extend(fields_snippets(&data_struct.fields, |index, field| {
+        if field_codec_attributes(field).skip {
+            quote! {}
+        } else {
+            dep_encode_snippet(&self_field_expr(index, field))
+        }
+    }));
+    snippets
+}
+
 fn variant_dep_encode_snippets(
     name: &syn::Ident,
     data_enum: &syn::DataEnum,
@@ -15,9 +37,8 @@ fn variant_dep_encode_snippets(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(enum_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             let local_var_declarations =
                 fields_decl_syntax(&variant.fields, local_variable_for_field);
@@ -26,7 +47,7 @@ fn variant_dep_encode_snippets(
             });
             quote! {
                 #name::#variant_ident #local_var_declarations => {
-                    elrond_codec::NestedEncode::dep_encode_or_handle_err(&#variant_index_u8, dest, h)?;
+                    elrond_codec::NestedEncode::dep_encode_or_handle_err(&#discriminant, dest, h)?;
                     #(#variant_field_snippets)*
                 },
             }
@@ -39,9 +60,7 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = &ast.generics.split_for_impl();
     let gen = match &ast.data {
         syn::Data::Struct(data_struct) => {
-            let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
-            });
+            let field_dep_encode_snippets = struct_dep_encode_snippets(ast, data_struct);
             quote! {
                 impl #impl_generics elrond_codec::NestedEncode for #name #ty_generics #where_clause {
                     fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> core::result::Result<(), H::HandledErr>
@@ -60,6 +79,7 @@ pub fn nested_encode_impl(ast: &syn::DeriveInput) -> TokenStream {
                 data_enum.variants.len() < 256,
                 "enums with more than 256 variants not supported"
             );
+            validate_enum_codec_attributes(ast, data_enum);
             let variant_dep_encode_snippets = variant_dep_encode_snippets(name, data_enum);
 
             quote! {
@@ -10,19 +10,19 @@ fn fieldless_enum_match_arm_result_ok(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(enum_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             quote! {
-                #variant_index_u8 => core::result::Result::Ok( #name::#variant_ident ),
+                #discriminant => core::result::Result::Ok( #name::#variant_ident ),
             }
         })
         .collect()
 }
 
 /// Generates a default-value deserializer snippet automatically.
-/// Currently only does so for enums whose first variant is fieldless.
+/// Currently only does so for enums whose variant with discriminant 0 is fieldless,
+/// since that variant is top-encoded as an empty value.
 /// Not called for TopDecodeOrDefault, since that one already provides an explicit default.
 fn auto_default(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
@@ -31,15 +31,22 @@ fn auto_default(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
             !data_enum.variants.is_empty(),
             "cannot deserialize enums without variants"
         );
-        let first_variant = &data_enum.variants[0];
-        if first_variant.fields.is_empty() {
-            let first_variant_ident = &first_variant.ident;
-            let auto_default = quote! {
-                if top_input.byte_len() == 0 {
-                    return core::result::Result::Ok(#name::#first_variant_ident);
-                }
-            };
-            return auto_default;
+        let zero_variant = data_enum
+            .variants
+            .iter()
+            .zip(enum_discriminants(data_enum))
+            .find(|(_, discriminant)| *discriminant == 0)
+            .map(|(variant, _)| variant);
+        if let Some(zero_variant) = zero_variant {
+            if zero_variant.fields.is_empty() {
+                let zero_variant_ident = &zero_variant.ident;
+                let auto_default = quote! {
+                    if top_input.byte_len() == 0 {
+                        return core::result::Result::Ok(#name::#zero_variant_ident);
+                    }
+                };
+                return auto_default;
+            }
         }
     }
 
@@ -52,13 +59,16 @@ fn top_decode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
     match &ast.data {
         syn::Data::Struct(data_struct) => {
-            let field_dep_decode_snippets =
-                fields_decl_syntax(&data_struct.fields, |index, field| {
-                    dep_decode_snippet(index, field, &quote! {&mut nested_buffer})
-                });
+            let (version_snippet, field_dep_decode_snippets) = struct_dep_decode_snippets(
+                ast,
+                data_struct,
+                &quote! {&mut nested_buffer},
+                Some(&quote! {&nested_buffer}),
+            );
 
             quote! {
                 let mut nested_buffer = top_input.into_nested_buffer();
+                #version_snippet
                 let result = #name #field_dep_decode_snippets ;
                 if !elrond_codec::NestedDecodeInput::is_depleted(&nested_buffer) {
                     return core::result::Result::Err(h.handle_error(elrond_codec::DecodeError::INPUT_TOO_LONG));
@@ -71,6 +81,7 @@ fn top_decode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
                 data_enum.variants.len() < 256,
                 "enums with more than 256 variants not supported"
             );
+            validate_enum_codec_attributes(ast, data_enum);
             if is_fieldless_enum(data_enum) {
                 // fieldless enums are special, they can be top-decoded as u8 directly
                 let top_decode_arms = fieldless_enum_match_arm_result_ok(name, data_enum);
@@ -1,7 +1,10 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
-use crate::{nested_en_derive::dep_encode_snippet, util::*};
+use crate::{
+    nested_en_derive::{dep_encode_snippet, struct_dep_encode_snippets},
+    util::*,
+};
 
 pub fn variant_top_encode_snippets(
     name: &syn::Ident,
@@ -10,15 +13,14 @@ pub fn variant_top_encode_snippets(
     data_enum
         .variants
         .iter()
-        .enumerate()
-        .map(|(variant_index, variant)| {
-            let variant_index_u8 = variant_index as u8;
+        .zip(enum_discriminants(data_enum))
+        .map(|(variant, discriminant)| {
             let variant_ident = &variant.ident;
             if variant.fields.is_empty() {
                 // top-encode discriminant directly
                 quote! {
                     #name::#variant_ident =>
-                        elrond_codec::TopEncode::top_encode_or_handle_err(&#variant_index_u8, output, h),
+                        elrond_codec::TopEncode::top_encode_or_handle_err(&#discriminant, output, h),
                 }
             } else {
                 // dep-encode to buffer first
@@ -31,7 +33,7 @@ pub fn variant_top_encode_snippets(
                     #name::#variant_ident #local_var_declarations => {
                         let mut buffer = output.start_nested_encode();
                         let dest = &mut buffer;
-                        elrond_codec::NestedEncode::dep_encode_or_handle_err(&#variant_index_u8, dest, h)?;
+                        elrond_codec::NestedEncode::dep_encode_or_handle_err(&#discriminant, dest, h)?;
                         #(#variant_field_snippets)*
                         output.finalize_nested_encode(buffer);
                         core::result::Result::Ok(())
@@ -47,9 +49,7 @@ fn top_encode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let name = &ast.ident;
     match &ast.data {
         syn::Data::Struct(data_struct) => {
-            let field_dep_encode_snippets = fields_snippets(&data_struct.fields, |index, field| {
-                dep_encode_snippet(&self_field_expr(index, field))
-            });
+            let field_dep_encode_snippets = struct_dep_encode_snippets(ast, data_struct);
             quote! {
                 let mut buffer = output.start_nested_encode();
                 let dest = &mut buffer;
@@ -63,6 +63,7 @@ fn top_encode_method_body(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
                 data_enum.variants.len() < 256,
                 "enums with more than 256 variants not supported"
             );
+            validate_enum_codec_attributes(ast, data_enum);
             let variant_top_encode_snippets = variant_top_encode_snippets(name, data_enum);
 
             quote! {
@@ -7,6 +7,48 @@ pub fn is_fieldless_enum(data_enum: &syn::DataEnum) -> bool {
         .all(|variant| variant.fields.is_empty())
 }
 
+/// The discriminant of each variant, as serialized.
+/// Follows the Rust rules: explicit discriminants (`Variant = 5`) are used as they are,
+/// other variants get the discriminant of the previous variant plus one, starting from 0.
+///
+/// The ABI derives in `elrond-wasm-derive` have a copy of this, keep the two in sync.
+pub fn enum_discriminants(data_enum: &syn::DataEnum) -> Vec<u8> {
+    let mut discriminants = Vec::<u8>::new();
+    let mut next_discriminant = 0u64;
+    for variant in data_enum.variants.iter() {
+        let discriminant = match &variant.discriminant {
+            Some((_, expr)) => parse_discriminant(expr),
+            None => next_discriminant,
+        };
+        assert!(
+            discriminant <= u8::MAX as u64,
+            "enum discriminants must fit in a u8, found {discriminant} for variant {}",
+            variant.ident
+        );
+        assert!(
+            !discriminants.contains(&(discriminant as u8)),
+            "duplicate enum discriminant {discriminant} for variant {}",
+            variant.ident
+        );
+        discriminants.push(discriminant as u8);
+        next_discriminant = discriminant + 1;
+    }
+    discriminants
+}
+
+fn parse_discriminant(expr: &syn::Expr) -> u64 {
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(lit_int),
+        ..
+    }) = expr
+    {
+        if let Ok(value) = lit_int.base10_parse::<u64>() {
+            return value;
+        }
+    }
+    panic!("only integer literals are supported as enum discriminants")
+}
+
 pub fn self_field_expr(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
     if let Some(ident) = &field.ident {
         quote! {
@@ -85,3 +127,127 @@ where
         syn::Fields::Unit => quote! {},
     }
 }
+
+const CODEC_ATTR_NAME: &str = "codec";
+
+/// Field-level `#[codec(...)]` attributes.
+#[derive(Default)]
+pub struct FieldCodecAttributes {
+    /// `#[codec(skip)]`: the field is not serialized, it always decodes as its default value.
+    pub skip: bool,
+
+    /// `#[codec(default)]`: when top-decoding, a missing trailing field decodes as its default value.
+    pub default: bool,
+
+    /// `#[codec(since = N)]`: in versioned structs, the field decodes as its default value in versions lower than N.
+    pub since: Option<u8>,
+}
+
+fn codec_attribute_items(attrs: &[syn::Attribute]) -> Vec<syn::NestedMeta> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident(CODEC_ATTR_NAME))
+        .flat_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::List(meta_list)) => meta_list.nested.into_iter(),
+            _ => panic!("codec attribute should be of the form #[codec(...)]"),
+        })
+        .collect()
+}
+
+fn parse_u8_lit(lit: &syn::Lit, attr_name: &str) -> u8 {
+    if let syn::Lit::Int(lit_int) = lit {
+        if let Ok(value) = lit_int.base10_parse::<u8>() {
+            return value;
+        }
+    }
+    panic!("codec attribute `{attr_name}` expects an integer between 0 and 255")
+}
+
+pub fn field_codec_attributes(field: &syn::Field) -> FieldCodecAttributes {
+    let mut result = FieldCodecAttributes::default();
+    for item in codec_attribute_items(field.attrs.as_slice()) {
+        match item {
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("skip") => {
+                result.skip = true;
+            },
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("default") => {
+                result.default = true;
+            },
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident("since") =>
+            {
+                result.since = Some(parse_u8_lit(&name_value.lit, "since"));
+            },
+            _ => panic!("unknown codec field attribute, expected `skip`, `default` or `since = N`"),
+        }
+    }
+    result
+}
+
+/// The `#[codec(version = N)]` struct attribute.
+/// Versioned structs are serialized with a leading version byte.
+pub fn struct_codec_version(ast: &syn::DeriveInput) -> Option<u8> {
+    let mut version = None;
+    for item in codec_attribute_items(ast.attrs.as_slice()) {
+        match item {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident("version") =>
+            {
+                version = Some(parse_u8_lit(&name_value.lit, "version"));
+            },
+            _ => panic!("unknown codec attribute, expected `version = N`"),
+        }
+    }
+    version
+}
+
+fn all_fields(fields: &syn::Fields) -> Vec<&syn::Field> {
+    match fields {
+        syn::Fields::Named(fields_named) => fields_named.named.iter().collect(),
+        syn::Fields::Unnamed(fields_unnamed) => fields_unnamed.unnamed.iter().collect(),
+        syn::Fields::Unit => Vec::new(),
+    }
+}
+
+/// Checks that the codec attributes of the fields are consistent with each other and with the struct version.
+pub fn validate_struct_codec_attributes(fields: &syn::Fields, version: Option<u8>) {
+    let mut default_found = false;
+    for field in all_fields(fields) {
+        let attributes = field_codec_attributes(field);
+        if attributes.skip {
+            continue;
+        }
+
+        if attributes.default {
+            default_found = true;
+        } else if default_found {
+            panic!("only trailing fields can be marked #[codec(default)]");
+        }
+
+        if let Some(since) = attributes.since {
+            match version {
+                Some(version) => assert!(
+                    since <= version,
+                    "field cannot be added in a version later than the struct version"
+                ),
+                None => panic!("#[codec(since = N)] requires a #[codec(version = N)] struct"),
+            }
+        }
+    }
+}
+
+/// Field attributes are only supported for structs.
+pub fn validate_enum_codec_attributes(ast: &syn::DeriveInput, data_enum: &syn::DataEnum) {
+    assert!(
+        struct_codec_version(ast).is_none(),
+        "#[codec(version = N)] is only supported for structs"
+    );
+    for variant in data_enum.variants.iter() {
+        for field in all_fields(&variant.fields) {
+            assert!(
+                codec_attribute_items(field.attrs.as_slice()).is_empty(),
+                "codec field attributes are only supported for structs"
+            );
+        }
+    }
+}
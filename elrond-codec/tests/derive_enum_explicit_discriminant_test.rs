@@ -0,0 +1,104 @@
+extern crate elrond_codec_derive;
+use elrond_codec_derive::*;
+
+use elrond_codec::{
+    test_util::{check_dep_encode_decode, check_top_encode, check_top_encode_decode},
+    DecodeError, NestedDecode, TopDecode,
+};
+
+/// Fieldless enum with gaps between discriminants.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+enum Status {
+    Unknown = 1,
+    Active = 5,
+    Retired,
+}
+
+#[test]
+fn fieldless_explicit_discriminant_test() {
+    check_top_encode_decode(Status::Unknown, &[1]);
+    check_top_encode_decode(Status::Active, &[5]);
+    check_top_encode_decode(Status::Retired, &[6]);
+
+    check_dep_encode_decode(Status::Unknown, &[1]);
+    check_dep_encode_decode(Status::Active, &[5]);
+    check_dep_encode_decode(Status::Retired, &[6]);
+
+    // the values in the gaps do not correspond to any variant
+    assert_eq!(
+        Status::top_decode(&[0u8][..]),
+        Err(DecodeError::INVALID_VALUE)
+    );
+    assert_eq!(
+        Status::top_decode(&[2u8][..]),
+        Err(DecodeError::INVALID_VALUE)
+    );
+    assert_eq!(
+        Status::dep_decode(&mut &[7u8][..]),
+        Err(DecodeError::INVALID_VALUE)
+    );
+
+    // no variant has discriminant 0, so empty input is not accepted either
+    assert_eq!(Status::top_decode(&[][..]), Err(DecodeError::INVALID_VALUE));
+}
+
+/// Data-carrying enum, with variants declared out of order.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+#[repr(u8)]
+enum Shape {
+    Circle(u16) = 3,
+    Point,
+    Rectangle { width: u16, height: u16 } = 1,
+    Empty = 0,
+}
+
+#[test]
+fn data_carrying_explicit_discriminant_test() {
+    check_top_encode_decode(Shape::Empty, &[]);
+    check_top_encode_decode(
+        Shape::Rectangle {
+            width: 2,
+            height: 3,
+        },
+        &[1, 0, 2, 0, 3],
+    );
+    check_top_encode_decode(Shape::Circle(5), &[3, 0, 5]);
+    check_top_encode_decode(Shape::Point, &[4]);
+
+    check_dep_encode_decode(Shape::Empty, &[0]);
+    check_dep_encode_decode(Shape::Circle(5), &[3, 0, 5]);
+    check_dep_encode_decode(Shape::Point, &[4]);
+
+    assert_eq!(
+        Shape::top_decode(&[2u8][..]),
+        Err(DecodeError::INVALID_VALUE)
+    );
+    assert_eq!(
+        Shape::dep_decode(&mut &[2u8, 0, 5][..]),
+        Err(DecodeError::INVALID_VALUE)
+    );
+}
+
+/// Same discriminants as `Status`, declared in a different order.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+enum ReorderedStatus {
+    Retired = 6,
+    Active = 5,
+    Unknown = 1,
+}
+
+#[test]
+fn reordered_variants_explicit_discriminant_test() {
+    assert_eq!(
+        check_top_encode(&ReorderedStatus::Unknown),
+        check_top_encode(&Status::Unknown)
+    );
+    assert_eq!(
+        check_top_encode(&ReorderedStatus::Active),
+        check_top_encode(&Status::Active)
+    );
+    assert_eq!(
+        check_top_encode(&ReorderedStatus::Retired),
+        check_top_encode(&Status::Retired)
+    );
+}
@@ -0,0 +1,226 @@
+extern crate elrond_codec_derive;
+use elrond_codec_derive::*;
+
+use elrond_codec::{
+    test_util::{
+        check_dep_decode, check_dep_encode_decode, check_top_decode, check_top_encode_decode,
+    },
+    DecodeError, NestedDecode, TopDecode,
+};
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct StructWithSkippedField {
+    pub int: u16,
+    #[codec(skip)]
+    pub cache: u32,
+    pub another_byte: u8,
+}
+
+#[test]
+fn struct_skip_test() {
+    let s = StructWithSkippedField {
+        int: 0x42,
+        cache: 0,
+        another_byte: 0x6,
+    };
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* int */ 0, 0x42,
+        /* another_byte */ 6,
+    ];
+
+    check_top_encode_decode(s.clone(), bytes);
+    check_dep_encode_decode(s, bytes);
+
+    // skipped fields are never serialized, and decode as default
+    let with_cache = StructWithSkippedField {
+        int: 0x42,
+        cache: 5,
+        another_byte: 0x6,
+    };
+    assert_eq!(
+        elrond_codec::top_encode_to_vec_u8(&with_cache).unwrap(),
+        bytes.to_vec()
+    );
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct StructV1 {
+    pub int: u16,
+    pub seq: Vec<u8>,
+}
+
+/// `StructV1`, with 2 fields added later.
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+pub struct StructWithDefaultFields {
+    pub int: u16,
+    pub seq: Vec<u8>,
+    #[codec(default)]
+    pub uint_32: u32,
+    #[codec(default)]
+    pub opt: Option<u8>,
+}
+
+#[test]
+fn struct_default_fields_test() {
+    let s = StructWithDefaultFields {
+        int: 0x42,
+        seq: vec![0x1, 0x2],
+        uint_32: 0x12345,
+        opt: Some(7),
+    };
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* int */ 0, 0x42,
+        /* seq */ 0, 0, 0, 2, 1, 2,
+        /* uint_32 */ 0x00, 0x01, 0x23, 0x45,
+        /* opt */ 1, 7,
+    ];
+    check_top_encode_decode(s.clone(), bytes);
+    check_dep_encode_decode(s, bytes);
+
+    // decoding values stored before the fields were added
+    let old_bytes = elrond_codec::top_encode_to_vec_u8(&StructV1 {
+        int: 0x42,
+        seq: vec![0x1, 0x2],
+    })
+    .unwrap();
+    assert_eq!(
+        check_top_decode::<StructWithDefaultFields>(&old_bytes),
+        StructWithDefaultFields {
+            int: 0x42,
+            seq: vec![0x1, 0x2],
+            uint_32: 0,
+            opt: None,
+        }
+    );
+
+    // only some of them
+    #[rustfmt::skip]
+    let partial_bytes = &[
+        /* int */ 0, 0x42,
+        /* seq */ 0, 0, 0, 2, 1, 2,
+        /* uint_32 */ 0x00, 0x01, 0x23, 0x45,
+    ];
+    assert_eq!(
+        check_top_decode::<StructWithDefaultFields>(partial_bytes),
+        StructWithDefaultFields {
+            int: 0x42,
+            seq: vec![0x1, 0x2],
+            uint_32: 0x12345,
+            opt: None,
+        }
+    );
+
+    // when nested, the fields are always expected
+    assert_eq!(
+        StructWithDefaultFields::dep_decode(&mut &old_bytes[..]),
+        Err(DecodeError::INPUT_TOO_SHORT)
+    );
+}
+
+#[derive(NestedEncode, NestedDecode, TopEncode, TopDecode, PartialEq, Eq, Clone, Debug)]
+#[codec(version = 3)]
+pub struct VersionedStruct {
+    pub int: u16,
+    #[codec(since = 2)]
+    pub uint_32: u32,
+    #[codec(skip)]
+    pub cache: u8,
+    #[codec(since = 3)]
+    pub seq: Vec<u8>,
+}
+
+#[test]
+fn versioned_struct_test() {
+    let s = VersionedStruct {
+        int: 0x42,
+        uint_32: 0x12345,
+        cache: 0,
+        seq: vec![0x1, 0x2],
+    };
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* version */ 3,
+        /* int */ 0, 0x42,
+        /* uint_32 */ 0x00, 0x01, 0x23, 0x45,
+        /* seq */ 0, 0, 0, 2, 1, 2,
+    ];
+    check_top_encode_decode(s.clone(), bytes);
+    check_dep_encode_decode(s, bytes);
+
+    #[rustfmt::skip]
+    let bytes_v1 = &[
+        /* version */ 1,
+        /* int */ 0, 0x42,
+    ];
+    let expected_v1 = VersionedStruct {
+        int: 0x42,
+        uint_32: 0,
+        cache: 0,
+        seq: Vec::new(),
+    };
+    assert_eq!(check_top_decode::<VersionedStruct>(bytes_v1), expected_v1);
+    assert_eq!(check_dep_decode::<VersionedStruct>(bytes_v1), expected_v1);
+
+    #[rustfmt::skip]
+    let bytes_v2 = &[
+        /* version */ 2,
+        /* int */ 0, 0x42,
+        /* uint_32 */ 0x00, 0x01, 0x23, 0x45,
+    ];
+    assert_eq!(
+        check_dep_decode::<VersionedStruct>(bytes_v2),
+        VersionedStruct {
+            int: 0x42,
+            uint_32: 0x12345,
+            cache: 0,
+            seq: Vec::new(),
+        }
+    );
+
+    // versions from the future cannot be decoded
+    assert_eq!(
+        VersionedStruct::top_decode(&[4u8, 0, 0x42][..]),
+        Err(DecodeError::UNSUPPORTED_VERSION)
+    );
+}
+
+#[test]
+fn versioned_struct_nested_test() {
+    let v = vec![
+        VersionedStruct {
+            int: 1,
+            uint_32: 2,
+            cache: 0,
+            seq: vec![3],
+        },
+        VersionedStruct {
+            int: 4,
+            uint_32: 5,
+            cache: 0,
+            seq: Vec::new(),
+        },
+    ];
+
+    #[rustfmt::skip]
+    let bytes = &[
+        /* first */ 3, 0, 1, 0, 0, 0, 2, 0, 0, 0, 1, 3,
+        /* second */ 3, 0, 4, 0, 0, 0, 5, 0, 0, 0, 0,
+    ];
+    check_top_encode_decode(v, bytes);
+
+    // an older entry followed by a newer one
+    #[rustfmt::skip]
+    let mixed_bytes = &[
+        /* first, v1 */ 1, 0, 1,
+        /* second */ 3, 0, 4, 0, 0, 0, 5, 0, 0, 0, 0,
+    ];
+    let decoded = check_top_decode::<Vec<VersionedStruct>>(mixed_bytes);
+    assert_eq!(decoded[0].int, 1);
+    assert_eq!(decoded[0].uint_32, 0);
+    assert_eq!(decoded[1].uint_32, 5);
+}
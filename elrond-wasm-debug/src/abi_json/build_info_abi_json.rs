@@ -7,6 +7,9 @@ pub struct BuildInfoAbiJson {
     pub rustc: RustcAbiJson,
     pub contract_crate: ContractCrateBuildAbiJson,
     pub framework: FrameworkBuildAbiJson,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_build: Option<ContractBuildAbiJson>,
 }
 
 impl From<&BuildInfoAbi> for BuildInfoAbiJson {
@@ -15,6 +18,7 @@ impl From<&BuildInfoAbi> for BuildInfoAbiJson {
             rustc: RustcAbiJson::create(),
             contract_crate: ContractCrateBuildAbiJson::from(&abi.contract_crate),
             framework: FrameworkBuildAbiJson::from(&abi.framework),
+            contract_build: None,
         }
     }
 }
@@ -47,6 +51,7 @@ impl RustcAbiJson {
 pub struct ContractCrateBuildAbiJson {
     pub name: String,
     pub version: String,
+    #[serde(default)]
     #[serde(skip_serializing_if = "String::is_empty")]
     pub git_version: String,
 }
@@ -75,3 +80,15 @@ impl From<&FrameworkBuildAbi> for FrameworkBuildAbiJson {
         }
     }
 }
+
+/// Only known after the contract was built, so it is only added to the ABI by the build.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractBuildAbiJson {
+    /// Hex-encoded, same as the code hash of the deployed contract.
+    pub code_hash: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub cargo_lock_hash: String,
+    pub build_flags: Vec<String>,
+}
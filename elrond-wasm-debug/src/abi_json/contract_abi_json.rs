@@ -8,6 +8,7 @@ use std::collections::BTreeMap;
 #[serde(rename_all = "camelCase")]
 pub struct ContractAbiJson {
     pub build_info: BuildInfoAbiJson,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
@@ -15,6 +16,12 @@ pub struct ContractAbiJson {
     pub constructor: Option<ConstructorAbiJson>,
     pub endpoints: Vec<EndpointAbiJson>,
     pub events: Vec<EventAbiJson>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub storage: Vec<StorageAbiJson>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ErrorAbiJson>,
     pub has_callback: bool,
     pub types: BTreeMap<String, TypeDescriptionJson>,
 }
@@ -28,6 +35,8 @@ impl From<&ContractAbi> for ContractAbiJson {
             constructor: abi.constructors.get(0).map(ConstructorAbiJson::from),
             endpoints: abi.endpoints.iter().map(EndpointAbiJson::from).collect(),
             events: abi.events.iter().map(EventAbiJson::from).collect(),
+            storage: abi.storage.iter().map(StorageAbiJson::from).collect(),
+            errors: abi.errors.iter().map(ErrorAbiJson::from).collect(),
             has_callback: abi.has_callback,
             types: BTreeMap::new(),
         };
@@ -0,0 +1,24 @@
+use alloc::vec::Vec;
+use elrond_wasm::abi::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct ErrorAbiJson {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    pub code: u32,
+    pub name: String,
+    pub message: String,
+}
+
+impl From<&ErrorAbi> for ErrorAbiJson {
+    fn from(abi: &ErrorAbi) -> Self {
+        ErrorAbiJson {
+            docs: abi.docs.iter().map(|d| d.to_string()).collect(),
+            code: abi.code,
+            name: abi.name.to_string(),
+            message: abi.message.to_string(),
+        }
+    }
+}
@@ -1,14 +1,18 @@
 mod build_info_abi_json;
 mod contract_abi_json;
 mod endpoint_abi_json;
+mod error_abi_json;
 mod event_abi_json;
+mod storage_abi_json;
 mod type_abi_json;
 
-pub use build_info_abi_json::{BuildInfoAbiJson, RustcAbiJson};
+pub use build_info_abi_json::{BuildInfoAbiJson, ContractBuildAbiJson, RustcAbiJson};
 pub use contract_abi_json::*;
 use elrond_wasm::{abi::ContractAbi, contract_base::ContractAbiProvider};
 pub use endpoint_abi_json::*;
+pub use error_abi_json::*;
 pub use event_abi_json::*;
+pub use storage_abi_json::*;
 pub use type_abi_json::*;
 
 /// Function provided for convenience.
@@ -7,14 +7,26 @@ pub struct TypeDescriptionJson {
     #[serde(rename = "type")]
     pub content_type: String,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u8>,
+
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub variants: Vec<EnumVariantDescriptionJson>,
 
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
+
+    #[serde(rename = "underlyingType")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub underlying_type: String,
 }
 
 impl From<&TypeDescription> for TypeDescriptionJson {
@@ -23,12 +35,15 @@ impl From<&TypeDescription> for TypeDescriptionJson {
             TypeContents::NotSpecified => "not_specified",
             TypeContents::Enum(_) => "enum",
             TypeContents::Struct(_) => "struct",
+            TypeContents::Transparent(_) => "transparent",
         };
         let mut type_desc_json = TypeDescriptionJson {
             content_type: content_type.to_string(),
             docs: abi.docs.iter().map(|line| line.to_string()).collect(),
+            version: abi.version,
             variants: Vec::new(),
             fields: Vec::new(),
+            underlying_type: String::new(),
         };
         match &abi.contents {
             TypeContents::Struct(fields) => {
@@ -45,7 +60,10 @@ impl From<&TypeDescription> for TypeDescriptionJson {
                         .push(EnumVariantDescriptionJson::from(variant));
                 }
             },
-            _ => {},
+            TypeContents::Transparent(underlying_type) => {
+                type_desc_json.underlying_type = underlying_type.clone();
+            },
+            TypeContents::NotSpecified => {},
         }
 
         type_desc_json
@@ -54,11 +72,15 @@ impl From<&TypeDescription> for TypeDescriptionJson {
 
 #[derive(Serialize, Deserialize)]
 pub struct StructFieldDescriptionJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
     #[serde(rename = "type")]
     pub field_type: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<u8>,
 }
 
 impl From<&StructFieldDescription> for StructFieldDescriptionJson {
@@ -67,16 +89,19 @@ impl From<&StructFieldDescription> for StructFieldDescriptionJson {
             docs: abi.docs.iter().map(|d| d.to_string()).collect(),
             name: abi.name.to_string(),
             field_type: abi.field_type.clone(),
+            since: abi.since,
         }
     }
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct EnumVariantDescriptionJson {
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub docs: Vec<String>,
     pub name: String,
     pub discriminant: usize,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<StructFieldDescriptionJson>,
 }
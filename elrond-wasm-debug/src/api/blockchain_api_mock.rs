@@ -38,8 +38,8 @@ impl BlockchainApiImpl for DebugApi {
         })
     }
 
-    fn get_shard_of_address_legacy(&self, _address: &Address) -> u32 {
-        panic!("get_shard_of_address not implemented")
+    fn get_shard_of_address_legacy(&self, address: &Address) -> u32 {
+        self.blockchain_ref().get_shard_of_address(address)
     }
 
     fn is_smart_contract_legacy(&self, address: &Address) -> bool {
@@ -64,7 +64,7 @@ impl BlockchainApiImpl for DebugApi {
     }
 
     fn get_gas_left(&self) -> u64 {
-        self.input_ref().gas_limit
+        self.gas_left()
     }
 
     fn get_block_timestamp(&self) -> u64 {
@@ -1,3 +1,4 @@
+use super::{crypto_bls_util, crypto_secp256k1_util};
 use crate::DebugApi;
 use ed25519_dalek::*;
 use elrond_wasm::{
@@ -7,6 +8,7 @@ use elrond_wasm::{
     },
     types::{heap::BoxedBytes, MessageHashType},
 };
+use ripemd160::Ripemd160;
 use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 
@@ -53,29 +55,36 @@ impl CryptoApiImpl for DebugApi {
         self.mb_overwrite(dest, &result_bytes[..]);
     }
 
-    fn ripemd160_legacy(&self, _data: &[u8]) -> [u8; RIPEMD_RESULT_LEN] {
-        panic!("ripemd160 not implemented yet!")
+    fn ripemd160_legacy(&self, data: &[u8]) -> [u8; RIPEMD_RESULT_LEN] {
+        let mut hasher = Ripemd160::new();
+        hasher.update(data);
+        hasher.finalize().into()
     }
 
     fn ripemd160_managed(
         &self,
-        _dest: Self::ManagedBufferHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        dest: Self::ManagedBufferHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ripemd160 not implemented yet!")
+        let result_bytes = self.ripemd160_legacy(self.mb_to_boxed_bytes(data_handle).as_slice());
+        self.mb_overwrite(dest, &result_bytes[..]);
     }
 
-    fn verify_bls_legacy(&self, _key: &[u8], _message: &[u8], _signature: &[u8]) -> bool {
-        panic!("verify_bls not implemented yet!")
+    fn verify_bls_legacy(&self, key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        crypto_bls_util::verify_bls(key, message, signature)
     }
 
     fn verify_bls_managed(
         &self,
-        _key: Self::ManagedBufferHandle,
-        _message: Self::ManagedBufferHandle,
-        _signature: Self::ManagedBufferHandle,
+        key: Self::ManagedBufferHandle,
+        message: Self::ManagedBufferHandle,
+        signature: Self::ManagedBufferHandle,
     ) -> bool {
-        panic!("verify_bls not implemented yet!")
+        self.verify_bls_legacy(
+            self.mb_to_boxed_bytes(key).as_slice(),
+            self.mb_to_boxed_bytes(message).as_slice(),
+            self.mb_to_boxed_bytes(signature).as_slice(),
+        )
     }
 
     fn verify_ed25519_legacy(&self, key: &[u8], message: &[u8], signature: &[u8]) -> bool {
@@ -105,49 +114,67 @@ impl CryptoApiImpl for DebugApi {
         )
     }
 
-    fn verify_secp256k1_legacy(&self, _key: &[u8], _message: &[u8], _signature: &[u8]) -> bool {
-        panic!("verify_secp256k1 not implemented yet!")
+    fn verify_secp256k1_legacy(&self, key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        self.verify_custom_secp256k1_legacy(
+            key,
+            message,
+            signature,
+            MessageHashType::ECDSADoubleSha256,
+        )
     }
 
     fn verify_secp256k1_managed(
         &self,
-        _key: Self::ManagedBufferHandle,
-        _message: Self::ManagedBufferHandle,
-        _signature: Self::ManagedBufferHandle,
+        key: Self::ManagedBufferHandle,
+        message: Self::ManagedBufferHandle,
+        signature: Self::ManagedBufferHandle,
     ) -> bool {
-        panic!("verify_secp256k1 not implemented yet!")
+        self.verify_secp256k1_legacy(
+            self.mb_to_boxed_bytes(key).as_slice(),
+            self.mb_to_boxed_bytes(message).as_slice(),
+            self.mb_to_boxed_bytes(signature).as_slice(),
+        )
     }
 
     fn verify_custom_secp256k1_legacy(
         &self,
-        _key: &[u8],
-        _message: &[u8],
-        _signature: &[u8],
-        _hash_type: MessageHashType,
+        key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+        hash_type: MessageHashType,
     ) -> bool {
-        panic!("verify_custom_secp256k1 not implemented yet!")
+        crypto_secp256k1_util::verify_secp256k1(key, message, signature, hash_type)
     }
 
     fn verify_custom_secp256k1_managed(
         &self,
-        _key: Self::ManagedBufferHandle,
-        _message: Self::ManagedBufferHandle,
-        _signature: Self::ManagedBufferHandle,
-        _hash_type: MessageHashType,
+        key: Self::ManagedBufferHandle,
+        message: Self::ManagedBufferHandle,
+        signature: Self::ManagedBufferHandle,
+        hash_type: MessageHashType,
     ) -> bool {
-        panic!("verify_custom_secp256k1 not implemented yet!")
+        self.verify_custom_secp256k1_legacy(
+            self.mb_to_boxed_bytes(key).as_slice(),
+            self.mb_to_boxed_bytes(message).as_slice(),
+            self.mb_to_boxed_bytes(signature).as_slice(),
+            hash_type,
+        )
     }
 
-    fn encode_secp256k1_der_signature_legacy(&self, _r: &[u8], _s: &[u8]) -> BoxedBytes {
-        panic!("encode_secp256k1_signature not implemented yet!")
+    fn encode_secp256k1_der_signature_legacy(&self, r: &[u8], s: &[u8]) -> BoxedBytes {
+        crypto_secp256k1_util::encode_der_signature(r, s).into()
     }
 
     fn encode_secp256k1_der_signature_managed(
         &self,
-        _r: Self::ManagedBufferHandle,
-        _s: Self::ManagedBufferHandle,
-        _dest: Self::ManagedBufferHandle,
+        r: Self::ManagedBufferHandle,
+        s: Self::ManagedBufferHandle,
+        dest: Self::ManagedBufferHandle,
     ) {
-        panic!("encode_secp256k1_signature not implemented yet!")
+        let encoded = crypto_secp256k1_util::encode_der_signature(
+            self.mb_to_boxed_bytes(r).as_slice(),
+            self.mb_to_boxed_bytes(s).as_slice(),
+        );
+        self.mb_overwrite(dest, encoded.as_slice());
     }
 }
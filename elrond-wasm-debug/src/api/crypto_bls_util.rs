@@ -0,0 +1,184 @@
+//! BLS signature verification, compatible with the herumi/mcl BLS12-381 scheme used by the VM.
+//!
+//! Points are serialized the mcl way: the x coordinate in little endian,
+//! with the parity of y stored in the most significant bit of the last byte.
+
+use crate::num_bigint::BigUint;
+use bls12_381::{pairing, G1Affine, G1Projective, G2Affine, Scalar};
+use num_traits::Zero;
+use sha2::{Digest, Sha512};
+
+pub const BLS_PUBLIC_KEY_BYTE_LEN: usize = 96;
+pub const BLS_SIGNATURE_BYTE_LEN: usize = 48;
+
+const FP_BYTE_LEN: usize = 48;
+
+/// Base field modulus.
+const FIELD_ORDER_HEX: &[u8] =
+    b"1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
+
+/// G1 cofactor, 0x396c8c005555e1568c00aaab0000aaab, as little endian 64-bit limbs.
+const G1_COFACTOR: [u64; 4] = [0x8c00aaab0000aaab, 0x396c8c005555e156, 0, 0];
+
+/// The G2 generator used by mcl in non-Ethereum mode, i.e. mapToG2(1), zcash compressed encoding.
+const G2_GENERATOR_COMPRESSED_HEX: &[u8] = b"b71df7a5080f908a16c2658ea90164e28c924c3f0e6655f6d82adca6bfbdfb5f9efca82c1609676fa15cd30396f1a4b30f3d011af81acf00140aab3c122c61bbdf0628db81c37664bdfc828163ce074ee33a1a5ce5488556603bc5d8d9f21ecc";
+
+const ZCASH_FLAG_COMPRESSED: u8 = 0x80;
+const ZCASH_FLAG_MASK: u8 = 0xe0;
+const MCL_FLAG_Y_ODD: u8 = 0x80;
+
+pub fn verify_bls(key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let public_key = match deserialize_g2(key) {
+        Some(point) => point,
+        None => return false,
+    };
+    let signature = match deserialize_g1(signature) {
+        Some(point) => point,
+        None => return false,
+    };
+    let hashed_message = match hash_and_map_to_g1(message) {
+        Some(point) => point,
+        None => return false,
+    };
+    let generator = g2_generator();
+    pairing(&signature, &generator) == pairing(&hashed_message, &public_key)
+}
+
+fn field_order() -> BigUint {
+    BigUint::parse_bytes(FIELD_ORDER_HEX, 16).unwrap()
+}
+
+fn g2_generator() -> G2Affine {
+    let mut bytes = [0u8; 2 * FP_BYTE_LEN];
+    hex::decode_to_slice(G2_GENERATOR_COMPRESSED_HEX, &mut bytes[..]).unwrap();
+    G2Affine::from_compressed(&bytes).unwrap()
+}
+
+/// Splits off the y parity flag, which mcl stores in the most significant bit of the last byte.
+fn split_mcl_flag(bytes: &[u8]) -> (Vec<u8>, bool) {
+    let mut x_bytes = bytes.to_vec();
+    let last = x_bytes.last_mut().unwrap();
+    let y_odd = *last & MCL_FLAG_Y_ODD != 0;
+    *last &= !MCL_FLAG_Y_ODD;
+    (x_bytes, y_odd)
+}
+
+/// Converts a little endian field element to big endian.
+/// Fails if the top bits are set, since they cannot belong to a valid coordinate
+/// and would be interpreted as flags in the zcash encoding.
+fn fp_le_to_be(le_bytes: &[u8], dest: &mut [u8]) -> Option<()> {
+    for (i, byte) in le_bytes.iter().rev().enumerate() {
+        dest[i] = *byte;
+    }
+    if dest[0] & ZCASH_FLAG_MASK != 0 {
+        return None;
+    }
+    Some(())
+}
+
+fn deserialize_g1(bytes: &[u8]) -> Option<G1Affine> {
+    if bytes.len() != BLS_SIGNATURE_BYTE_LEN || bytes.iter().all(|b| *b == 0) {
+        return None;
+    }
+    let (x_bytes, y_odd) = split_mcl_flag(bytes);
+    let mut compressed = [0u8; FP_BYTE_LEN];
+    fp_le_to_be(&x_bytes[..], &mut compressed[..])?;
+    compressed[0] |= ZCASH_FLAG_COMPRESSED;
+    let point: G1Affine = Option::from(G1Affine::from_compressed(&compressed))?;
+
+    // the zcash sign flag selects the lexicographically larger y, mcl uses the parity of y instead
+    let y_parity = point.to_uncompressed()[2 * FP_BYTE_LEN - 1] & 1 == 1;
+    if y_parity == y_odd {
+        Some(point)
+    } else {
+        Some(-point)
+    }
+}
+
+fn deserialize_g2(bytes: &[u8]) -> Option<G2Affine> {
+    if bytes.len() != BLS_PUBLIC_KEY_BYTE_LEN || bytes.iter().all(|b| *b == 0) {
+        return None;
+    }
+    // mcl serializes x.c0 || x.c1, zcash expects x.c1 || x.c0
+    let (x_bytes, y_odd) = split_mcl_flag(bytes);
+    let mut compressed = [0u8; 2 * FP_BYTE_LEN];
+    fp_le_to_be(&x_bytes[FP_BYTE_LEN..], &mut compressed[..FP_BYTE_LEN])?;
+    fp_le_to_be(&x_bytes[..FP_BYTE_LEN], &mut compressed[FP_BYTE_LEN..])?;
+    compressed[0] |= ZCASH_FLAG_COMPRESSED;
+    let point: G2Affine = Option::from(G2Affine::from_compressed(&compressed))?;
+
+    // for G2, mcl only looks at the parity of y.c0
+    let y_parity = point.to_uncompressed()[4 * FP_BYTE_LEN - 1] & 1 == 1;
+    if y_parity == y_odd {
+        Some(point)
+    } else {
+        Some(-point)
+    }
+}
+
+/// Same as mcl `hashAndMapToG1`: SHA-512 of the message, truncated to 380 bits (little endian),
+/// mapped to the curve using the Fouque-Tibouchi method, then multiplied by the cofactor.
+fn hash_and_map_to_g1(message: &[u8]) -> Option<G1Affine> {
+    let hash = Sha512::digest(message);
+    let mut t_bytes = [0u8; FP_BYTE_LEN];
+    t_bytes.copy_from_slice(&hash[..FP_BYTE_LEN]);
+    t_bytes[FP_BYTE_LEN - 1] &= 0x0f;
+    let t = BigUint::from_bytes_le(&t_bytes[..]);
+    if t.is_zero() {
+        return None;
+    }
+
+    let (x, y) = map_to_curve(&t);
+    let mut uncompressed = [0u8; 2 * FP_BYTE_LEN];
+    write_be(&x, &mut uncompressed[..FP_BYTE_LEN]);
+    write_be(&y, &mut uncompressed[FP_BYTE_LEN..]);
+    let point: G1Affine = Option::from(G1Affine::from_uncompressed_unchecked(&uncompressed))?;
+    let cleared = G1Projective::from(point) * Scalar::from_raw(G1_COFACTOR);
+    Some(G1Affine::from(cleared))
+}
+
+fn write_be(value: &BigUint, dest: &mut [u8]) {
+    let bytes = value.to_bytes_be();
+    let offset = dest.len() - bytes.len();
+    dest[offset..].copy_from_slice(&bytes[..]);
+}
+
+fn mod_neg(x: &BigUint, p: &BigUint) -> BigUint {
+    (p - x % p) % p
+}
+
+fn mod_inv(x: &BigUint, p: &BigUint) -> BigUint {
+    x.modpow(&(p - 2u32), p)
+}
+
+/// Only works because p ≡ 3 (mod 4).
+fn mod_sqrt(x: &BigUint, p: &BigUint) -> Option<BigUint> {
+    let root = x.modpow(&((p + 1u32) >> 2), p);
+    if &root * &root % p == x % p {
+        Some(root)
+    } else {
+        None
+    }
+}
+
+/// Fouque-Tibouchi map onto y² = x³ + 4, mcl `MapTo::calcBN`.
+fn map_to_curve(t: &BigUint) -> (BigUint, BigUint) {
+    let p = field_order();
+    let b = BigUint::from(4u32);
+    let c1 = mod_sqrt(&mod_neg(&BigUint::from(3u32), &p), &p).unwrap();
+    let c2 = (&c1 + &p - 1u32) * mod_inv(&BigUint::from(2u32), &p) % &p;
+    let negative = t.modpow(&((&p - 1u32) >> 1), &p) == &p - 1u32;
+
+    let w = mod_inv(&((t * t + &b + 1u32) % &p), &p) * &c1 % &p * t % &p;
+    let x1 = (mod_neg(&(t * &w % &p), &p) + &c2) % &p;
+    let x2 = (mod_neg(&x1, &p) + &p - 1u32) % &p;
+    let x3 = (mod_inv(&(&w * &w % &p), &p) + 1u32) % &p;
+    for x in [x1, x2, x3] {
+        let y_squared = (&x * &x * &x + &b) % &p;
+        if let Some(y) = mod_sqrt(&y_squared, &p) {
+            let y = if negative { mod_neg(&y, &p) } else { y };
+            return (x, y);
+        }
+    }
+    unreachable!("one of the three candidates is always on the curve")
+}
@@ -0,0 +1,101 @@
+use crate::num_bigint::BigUint;
+use elrond_wasm::{api::SHA256_RESULT_LEN, types::MessageHashType};
+use libsecp256k1::{Message, PublicKey, Signature};
+use ripemd160::Ripemd160;
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+
+/// Order of the secp256k1 base point.
+const CURVE_ORDER_HEX: &[u8] = b"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+
+const DER_SEQUENCE_TAG: u8 = 0x30;
+const DER_INTEGER_TAG: u8 = 0x02;
+const DER_LONG_LENGTH_FLAG: u8 = 0x80;
+
+fn hash_message(message: &[u8], hash_type: MessageHashType) -> Vec<u8> {
+    match hash_type {
+        MessageHashType::ECDSAPlainMsg => message.to_vec(),
+        MessageHashType::ECDSASha256 => Sha256::digest(message).to_vec(),
+        MessageHashType::ECDSADoubleSha256 => Sha256::digest(&Sha256::digest(message)).to_vec(),
+        MessageHashType::ECDSAKeccak256 => Keccak256::digest(message).to_vec(),
+        MessageHashType::ECDSARipemd160 => Ripemd160::digest(message).to_vec(),
+    }
+}
+
+/// Same as the Go `hashToInt`: hashes longer than the curve order are truncated,
+/// shorter ones are interpreted as smaller numbers, i.e. left-padded with zeros.
+fn hash_to_message(hash: &[u8]) -> Message {
+    let mut padded = [0u8; SHA256_RESULT_LEN];
+    if hash.len() >= SHA256_RESULT_LEN {
+        padded.copy_from_slice(&hash[..SHA256_RESULT_LEN]);
+    } else {
+        padded[SHA256_RESULT_LEN - hash.len()..].copy_from_slice(hash);
+    }
+    Message::parse(&padded)
+}
+
+/// The key can be either compressed (33 bytes) or uncompressed (65 bytes),
+/// the signature is DER-encoded.
+pub fn verify_secp256k1(
+    key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+    hash_type: MessageHashType,
+) -> bool {
+    let public_key = match PublicKey::parse_slice(key, None) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+    let mut signature = match Signature::parse_der(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    // the Go implementation also accepts signatures with a high S value
+    signature.normalize_s();
+
+    let hash = hash_message(message, hash_type);
+    libsecp256k1::verify(&hash_to_message(&hash), &signature, &public_key)
+}
+
+/// Serializes (r, s) as a DER signature, with S normalized to the lower half of the curve order.
+pub fn encode_der_signature(r: &[u8], s: &[u8]) -> Vec<u8> {
+    let curve_order = BigUint::parse_bytes(CURVE_ORDER_HEX, 16).unwrap();
+    let mut s = BigUint::from_bytes_be(s);
+    if s > &curve_order >> 1 {
+        s = &curve_order - s % &curve_order;
+    }
+
+    let r_der = der_integer(BigUint::from_bytes_be(r));
+    let s_der = der_integer(s);
+    let mut result = vec![DER_SEQUENCE_TAG];
+    result.extend_from_slice(&der_length(r_der.len() + s_der.len()));
+    result.extend_from_slice(&r_der);
+    result.extend_from_slice(&s_der);
+    result
+}
+
+/// Lengths below 128 use the short form, a single byte.
+/// Longer ones use the long form: the number of length bytes (with the top bit set), followed by the length itself.
+fn der_length(len: usize) -> Vec<u8> {
+    if len < DER_LONG_LENGTH_FLAG as usize {
+        return vec![len as u8];
+    }
+
+    let len_bytes = len.to_be_bytes();
+    let first_non_zero = len_bytes.iter().position(|b| *b != 0).unwrap();
+    let len_bytes = &len_bytes[first_non_zero..];
+    let mut result = vec![DER_LONG_LENGTH_FLAG | len_bytes.len() as u8];
+    result.extend_from_slice(len_bytes);
+    result
+}
+
+fn der_integer(value: BigUint) -> Vec<u8> {
+    let mut bytes = value.to_bytes_be();
+    if bytes[0] & 0x80 != 0 {
+        bytes.insert(0, 0);
+    }
+    let mut result = vec![DER_INTEGER_TAG];
+    result.extend_from_slice(&der_length(bytes.len()));
+    result.extend_from_slice(&bytes);
+    result
+}
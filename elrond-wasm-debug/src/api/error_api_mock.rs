@@ -15,6 +15,11 @@ impl ErrorApiImpl for DebugApi {
         // run `clear & cargo test -- --nocapture` to see the output
         println!("{}", std::str::from_utf8(message).unwrap());
 
+        self.blockchain_ref()
+            .coverage
+            .borrow_mut()
+            .record_error(message);
+
         std::panic::panic_any(TxPanic {
             status: 4,
             message: String::from_utf8(message.to_vec()).unwrap(),
@@ -34,6 +34,13 @@ impl LogApiImpl for DebugApi {
             current_index += arg_len;
         }
 
+        if let Some(identifier) = topics.first() {
+            self.blockchain_ref()
+                .coverage
+                .borrow_mut()
+                .record_event(identifier);
+        }
+
         let mut tx_result = self.result_borrow_mut();
         tx_result.result_logs.push(TxLog {
             address: self.input_ref().to.clone(),
@@ -22,13 +22,16 @@ fn assert_positive(bi: &num_bigint::BigInt) {
 }
 
 macro_rules! binary_op_method {
-    ($method_name:ident, $rust_op_name:ident) => {
+    ($method_name:ident, $rust_op_name:ident, $gas_cost_field:ident) => {
         fn $method_name(
             &self,
             dest: Self::BigIntHandle,
             x: Self::BigIntHandle,
             y: Self::BigIntHandle,
         ) {
+            self.use_gas_from_schedule(|gas_schedule| {
+                gas_schedule.big_int_api_cost.$gas_cost_field
+            });
             let bi_x = self.bi_get(x);
             let bi_y = self.bi_get(y);
             let result = bi_x.$rust_op_name(bi_y);
@@ -38,13 +41,16 @@ macro_rules! binary_op_method {
 }
 
 macro_rules! binary_bitwise_op_method {
-    ($method_name:ident, $rust_op_name:ident) => {
+    ($method_name:ident, $rust_op_name:ident, $gas_cost_field:ident) => {
         fn $method_name(
             &self,
             dest: Self::BigIntHandle,
             x: Self::BigIntHandle,
             y: Self::BigIntHandle,
         ) {
+            self.use_gas_from_schedule(|gas_schedule| {
+                gas_schedule.big_int_api_cost.$gas_cost_field
+            });
             let bi_x = self.bi_get(x);
             assert_positive(&bi_x);
             let bi_y = self.bi_get(y);
@@ -56,8 +62,11 @@ macro_rules! binary_bitwise_op_method {
 }
 
 macro_rules! unary_op_method {
-    ($method_name:ident, $rust_op_name:ident) => {
+    ($method_name:ident, $rust_op_name:ident, $gas_cost_field:ident) => {
         fn $method_name(&self, dest: Self::BigIntHandle, x: Self::BigIntHandle) {
+            self.use_gas_from_schedule(|gas_schedule| {
+                gas_schedule.big_int_api_cost.$gas_cost_field
+            });
             let bi_x = self.bi_get(x);
             let result = bi_x.$rust_op_name();
             self.bi_overwrite(dest, result);
@@ -89,6 +98,7 @@ impl DebugApi {
 impl BigIntApi for DebugApi {
     #[allow(dead_code)]
     fn bi_new(&self, value: i64) -> Self::BigIntHandle {
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.big_int_api_cost.big_int_new);
         let mut managed_types = self.m_types_borrow_mut();
         managed_types
             .big_int_map
@@ -141,8 +151,8 @@ impl BigIntApi for DebugApi {
         big_int_to_i64(&bi)
     }
 
-    binary_op_method! {bi_add, add}
-    binary_op_method! {bi_sub, sub}
+    binary_op_method! {bi_add, add, big_int_add}
+    binary_op_method! {bi_sub, sub, big_int_sub}
 
     fn bi_sub_unsigned(
         &self,
@@ -150,6 +160,7 @@ impl BigIntApi for DebugApi {
         x: Self::BigIntHandle,
         y: Self::BigIntHandle,
     ) {
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.big_int_api_cost.big_int_sub);
         let bi_x = self.bi_get(x);
         let bi_y = self.bi_get(y);
         let result = bi_x.sub(bi_y);
@@ -159,14 +170,15 @@ impl BigIntApi for DebugApi {
         self.bi_overwrite(dest, result);
     }
 
-    binary_op_method! {bi_mul, mul}
-    binary_op_method! {bi_t_div, div}
-    binary_op_method! {bi_t_mod, rem}
+    binary_op_method! {bi_mul, mul, big_int_mul}
+    binary_op_method! {bi_t_div, div, big_int_t_div}
+    binary_op_method! {bi_t_mod, rem, big_int_t_mod}
 
-    unary_op_method! {bi_abs, abs}
-    unary_op_method! {bi_neg, neg}
+    unary_op_method! {bi_abs, abs, big_int_abs}
+    unary_op_method! {bi_neg, neg, big_int_neg}
 
     fn bi_sign(&self, x: Self::BigIntHandle) -> elrond_wasm::api::Sign {
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.big_int_api_cost.big_int_sign);
         let bi = self.bi_get(x);
         match bi.sign() {
             num_bigint::Sign::Minus => elrond_wasm::api::Sign::Minus,
@@ -176,14 +188,16 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_cmp(&self, x: Self::BigIntHandle, y: Self::BigIntHandle) -> Ordering {
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.big_int_api_cost.big_int_cmp);
         let bi_x = self.bi_get(x);
         let bi_y = self.bi_get(y);
         bi_x.cmp(&bi_y)
     }
 
-    unary_op_method! {bi_sqrt, sqrt}
+    unary_op_method! {bi_sqrt, sqrt, big_int_sqrt}
 
     fn bi_pow(&self, dest: Self::BigIntHandle, x: Self::BigIntHandle, y: Self::BigIntHandle) {
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.big_int_api_cost.big_int_pow);
         let bi_x = self.bi_get(x);
         let bi_y = self.bi_get(y);
         let exp = big_int_to_i64(&bi_y).unwrap().try_into().unwrap();
@@ -192,15 +206,17 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_log2(&self, x: Self::BigIntHandle) -> u32 {
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.big_int_api_cost.big_int_log2);
         let bi_x = self.bi_get(x);
         bi_x.bits() as u32 - 1
     }
 
-    binary_bitwise_op_method! {bi_and, bitand}
-    binary_bitwise_op_method! {bi_or, bitor}
-    binary_bitwise_op_method! {bi_xor, bitxor}
+    binary_bitwise_op_method! {bi_and, bitand, big_int_and}
+    binary_bitwise_op_method! {bi_or, bitor, big_int_or}
+    binary_bitwise_op_method! {bi_xor, bitxor, big_int_xor}
 
     fn bi_shr(&self, dest: Self::BigIntHandle, x: Self::BigIntHandle, bits: usize) {
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.big_int_api_cost.big_int_shr);
         let bi_x = self.bi_get(x);
         assert_positive(&bi_x);
         let result = bi_x.shr(bits);
@@ -208,6 +224,7 @@ impl BigIntApi for DebugApi {
     }
 
     fn bi_shl(&self, dest: Self::BigIntHandle, x: Self::BigIntHandle, bits: usize) {
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.big_int_api_cost.big_int_shl);
         let bi_x = self.bi_get(x);
         assert_positive(&bi_x);
         let result = bi_x.shl(bits);
@@ -1,210 +1,384 @@
-use elrond_wasm::{api::EllipticCurveApi, types::heap::BoxedBytes};
+use elrond_wasm::{
+    api::{EllipticCurveApi, HandleTypeInfo, ManagedBufferApi},
+    err_msg,
+    types::heap::BoxedBytes,
+};
 
-use crate::DebugApi;
+use crate::{num_bigint::BigInt, tx_mock::TxPanic, DebugApi};
+
+use super::{EllipticCurveParams, EllipticCurvePoint};
+
+fn ec_fail(message: &str) -> ! {
+    std::panic::panic_any(TxPanic {
+        status: 10,
+        message: message.to_string(),
+    })
+}
+
+impl DebugApi {
+    fn ec_new(&self, name: &[u8]) -> <Self as HandleTypeInfo>::EllipticCurveHandle {
+        let ec = EllipticCurveParams::from_name(name)
+            .unwrap_or_else(|| ec_fail(err_msg::EC_CURVE_NOT_SUPPORTED));
+        let mut managed_types = self.m_types_borrow_mut();
+        managed_types.elliptic_curve_map.insert_new_handle(ec)
+    }
+
+    fn ec_get(&self, handle: <Self as HandleTypeInfo>::EllipticCurveHandle) -> EllipticCurveParams {
+        let managed_types = handle.context.m_types_borrow();
+        managed_types
+            .elliptic_curve_map
+            .get(handle.get_raw_handle_unchecked())
+            .clone()
+    }
+
+    /// Loads a point and checks that it is on the curve, as the VM does before any operation.
+    fn ec_get_point(
+        &self,
+        ec: &EllipticCurveParams,
+        x_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        y_handle: <Self as HandleTypeInfo>::BigIntHandle,
+    ) -> EllipticCurvePoint {
+        match (
+            self.bi_get(x_handle).to_biguint(),
+            self.bi_get(y_handle).to_biguint(),
+        ) {
+            (Some(x), Some(y)) if ec.is_on_curve(&x, &y) => (x, y),
+            _ => ec_fail(err_msg::EC_POINT_NOT_ON_CURVE),
+        }
+    }
+
+    fn ec_set_point(
+        &self,
+        x_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        y_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        point: EllipticCurvePoint,
+    ) {
+        let (x, y) = point;
+        self.bi_overwrite(x_handle, BigInt::from(x));
+        self.bi_overwrite(y_handle, BigInt::from(y));
+    }
+
+    fn ec_marshal_bytes(
+        &self,
+        ec_handle: <Self as HandleTypeInfo>::EllipticCurveHandle,
+        x_pair_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        y_pair_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        compressed: bool,
+    ) -> Vec<u8> {
+        let ec = self.ec_get(ec_handle);
+        let (x, y) = self.ec_get_point(&ec, x_pair_handle, y_pair_handle);
+        if compressed {
+            ec.marshal_compressed(&x, &y)
+        } else {
+            ec.marshal(&x, &y)
+        }
+    }
+
+    fn ec_unmarshal_bytes(
+        &self,
+        x_result_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        y_result_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        ec_handle: <Self as HandleTypeInfo>::EllipticCurveHandle,
+        data: &[u8],
+        compressed: bool,
+    ) {
+        let ec = self.ec_get(ec_handle);
+        let expected_len = if compressed {
+            1 + ec.byte_len()
+        } else {
+            1 + 2 * ec.byte_len()
+        };
+        if data.len() != expected_len {
+            ec_fail(err_msg::EC_BUFFER_LENGTH_INCORRECT);
+        }
+        let point = if compressed {
+            ec.unmarshal_compressed(data)
+        } else {
+            ec.unmarshal(data)
+        };
+        let point = point.unwrap_or_else(|| ec_fail(err_msg::EC_POINT_NOT_ON_CURVE));
+        self.ec_set_point(x_result_handle, y_result_handle, point);
+    }
+
+    fn ec_generate_key_bytes(
+        &self,
+        x_pub_key_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        y_pub_key_handle: <Self as HandleTypeInfo>::BigIntHandle,
+        ec_handle: <Self as HandleTypeInfo>::EllipticCurveHandle,
+    ) -> Vec<u8> {
+        let ec = self.ec_get(ec_handle);
+        let private_key = loop {
+            let mut random_bytes = vec![0u8; ec.private_key_byte_len()];
+            self.rng_borrow_mut().fill(&mut random_bytes[..]);
+            if let Some(private_key) = ec.private_key_from_random_bytes(random_bytes) {
+                break private_key;
+            }
+        };
+        let public_key = ec.scalar_base_mult(&private_key);
+        self.ec_set_point(x_pub_key_handle, y_pub_key_handle, public_key);
+        private_key
+    }
+}
 
 impl EllipticCurveApi for DebugApi {
-    fn ec_create_from_name_bytes(&self, _name: &[u8]) -> Self::ManagedBufferHandle {
-        panic!("ec_create not implemented")
+    fn ec_create_from_name_bytes(&self, name: &[u8]) -> Self::EllipticCurveHandle {
+        self.ec_new(name)
     }
 
     fn ec_create_from_name_mb(
         &self,
-        _name_handle: Self::ManagedBufferHandle,
-    ) -> Self::ManagedBufferHandle {
-        panic!("ec_create not implemented")
+        name_handle: Self::ManagedBufferHandle,
+    ) -> Self::EllipticCurveHandle {
+        self.ec_new(self.mb_get(name_handle).as_slice())
     }
 
     fn ec_get_values(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _field_order_handle: Self::BigIntHandle,
-        _base_point_order_handle: Self::BigIntHandle,
-        _eq_constant_handle: Self::BigIntHandle,
-        _x_base_point_handle: Self::BigIntHandle,
-        _y_base_point_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        field_order_handle: Self::BigIntHandle,
+        base_point_order_handle: Self::BigIntHandle,
+        eq_constant_handle: Self::BigIntHandle,
+        x_base_point_handle: Self::BigIntHandle,
+        y_base_point_handle: Self::BigIntHandle,
     ) {
-        panic!("ec_get_values not implemented")
+        let ec = self.ec_get(ec_handle);
+        self.bi_overwrite(field_order_handle, ec.field_order.into());
+        self.bi_overwrite(base_point_order_handle, ec.base_point_order.into());
+        self.bi_overwrite(eq_constant_handle, ec.eq_constant.into());
+        self.bi_overwrite(x_base_point_handle, ec.x_base_point.into());
+        self.bi_overwrite(y_base_point_handle, ec.y_base_point.into());
     }
 
-    fn ec_curve_length(&self, _ec_handle: Self::EllipticCurveHandle) -> u32 {
-        panic!("ec_curve_length not implemented")
+    fn ec_curve_length(&self, ec_handle: Self::EllipticCurveHandle) -> u32 {
+        self.ec_get(ec_handle).size_of_field
     }
 
-    fn ec_private_key_byte_length(&self, _ec_handle: Self::EllipticCurveHandle) -> u32 {
-        panic!("ec_private_key_byte_length not implemented")
+    fn ec_private_key_byte_length(&self, ec_handle: Self::EllipticCurveHandle) -> u32 {
+        self.ec_get(ec_handle).private_key_byte_len() as u32
     }
 
     fn ec_add(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_first_point: Self::BigIntHandle,
-        _y_first_point: Self::BigIntHandle,
-        _x_second_point: Self::BigIntHandle,
-        _y_second_point: Self::BigIntHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_first_point: Self::BigIntHandle,
+        y_first_point: Self::BigIntHandle,
+        x_second_point: Self::BigIntHandle,
+        y_second_point: Self::BigIntHandle,
     ) {
-        panic!("ec_add not implemented")
+        let ec = self.ec_get(ec_handle);
+        let first = self.ec_get_point(&ec, x_first_point, y_first_point);
+        let second = self.ec_get_point(&ec, x_second_point, y_second_point);
+        self.ec_set_point(x_result_handle, y_result_handle, ec.add(&first, &second));
     }
 
     fn ec_double(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_point_handle: Self::BigIntHandle,
-        _y_point_handle: Self::BigIntHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_point_handle: Self::BigIntHandle,
+        y_point_handle: Self::BigIntHandle,
     ) {
-        panic!("ec_double not implemented")
+        let ec = self.ec_get(ec_handle);
+        let point = self.ec_get_point(&ec, x_point_handle, y_point_handle);
+        self.ec_set_point(x_result_handle, y_result_handle, ec.double(&point));
     }
 
     fn ec_is_on_curve(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_point_handle: Self::BigIntHandle,
-        _y_point_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_point_handle: Self::BigIntHandle,
+        y_point_handle: Self::BigIntHandle,
     ) -> bool {
-        panic!("ec_is_on_curve not implemented")
+        let ec = self.ec_get(ec_handle);
+        match (
+            self.bi_get(x_point_handle).to_biguint(),
+            self.bi_get(y_point_handle).to_biguint(),
+        ) {
+            (Some(x), Some(y)) => ec.is_on_curve(&x, &y),
+            _ => false,
+        }
     }
 
     fn ec_scalar_mult_legacy(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_point_handle: Self::BigIntHandle,
-        _y_point_handle: Self::BigIntHandle,
-        _data: &[u8],
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_point_handle: Self::BigIntHandle,
+        y_point_handle: Self::BigIntHandle,
+        data: &[u8],
     ) {
-        panic!("ec_scalar_mult not implemented")
+        let ec = self.ec_get(ec_handle);
+        let point = self.ec_get_point(&ec, x_point_handle, y_point_handle);
+        self.ec_set_point(
+            x_result_handle,
+            y_result_handle,
+            ec.scalar_mult(&point, data),
+        );
     }
 
     fn ec_scalar_mult(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_point_handle: Self::BigIntHandle,
-        _y_point_handle: Self::BigIntHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_point_handle: Self::BigIntHandle,
+        y_point_handle: Self::BigIntHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_scalar_mult not implemented")
+        self.ec_scalar_mult_legacy(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            x_point_handle,
+            y_point_handle,
+            self.mb_get(data_handle).as_slice(),
+        );
     }
 
     fn ec_scalar_base_mult_legacy(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data: &[u8],
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data: &[u8],
     ) {
-        panic!("ec_scalar_base_mult not implemented")
+        let ec = self.ec_get(ec_handle);
+        self.ec_set_point(x_result_handle, y_result_handle, ec.scalar_base_mult(data));
     }
 
     fn ec_scalar_base_mult(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_scalar_base_mult not implemented")
+        self.ec_scalar_base_mult_legacy(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            self.mb_get(data_handle).as_slice(),
+        );
     }
 
     fn ec_marshal_legacy(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_pair_handle: Self::BigIntHandle,
-        _y_pair_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_pair_handle: Self::BigIntHandle,
+        y_pair_handle: Self::BigIntHandle,
     ) -> BoxedBytes {
-        panic!("ec_marshal not implemented")
+        self.ec_marshal_bytes(ec_handle, x_pair_handle, y_pair_handle, false)
+            .into()
     }
 
     fn ec_marshal(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_pair_handle: Self::BigIntHandle,
-        _y_pair_handle: Self::BigIntHandle,
-        _result_handle: Self::ManagedBufferHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_pair_handle: Self::BigIntHandle,
+        y_pair_handle: Self::BigIntHandle,
+        result_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_marshal not implemented")
+        let result = self.ec_marshal_bytes(ec_handle, x_pair_handle, y_pair_handle, false);
+        self.mb_overwrite(result_handle, result.as_slice());
     }
 
     fn ec_marshal_compressed_legacy(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_pair_handle: Self::BigIntHandle,
-        _y_pair_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_pair_handle: Self::BigIntHandle,
+        y_pair_handle: Self::BigIntHandle,
     ) -> BoxedBytes {
-        panic!("ec_marshal_compressed not implemented")
+        self.ec_marshal_bytes(ec_handle, x_pair_handle, y_pair_handle, true)
+            .into()
     }
 
     fn ec_marshal_compressed(
         &self,
-        _ec_handle: Self::EllipticCurveHandle,
-        _x_pair_handle: Self::BigIntHandle,
-        _y_pair_handle: Self::BigIntHandle,
-        _result_handle: Self::ManagedBufferHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        x_pair_handle: Self::BigIntHandle,
+        y_pair_handle: Self::BigIntHandle,
+        result_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_marshal_compressed not implemented")
+        let result = self.ec_marshal_bytes(ec_handle, x_pair_handle, y_pair_handle, true);
+        self.mb_overwrite(result_handle, result.as_slice());
     }
 
     fn ec_unmarshal_legacy(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data: &[u8],
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data: &[u8],
     ) {
-        panic!("ec_unmarshal not implemented")
+        self.ec_unmarshal_bytes(x_result_handle, y_result_handle, ec_handle, data, false);
     }
 
     fn ec_unmarshal(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_unmarshal not implemented")
+        let data = self.mb_get(data_handle);
+        self.ec_unmarshal_bytes(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            data.as_slice(),
+            false,
+        );
     }
 
     fn ec_unmarshal_compressed_legacy(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data: &[u8],
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data: &[u8],
     ) {
-        panic!("ec_unmarshal_compressed not implemented")
+        self.ec_unmarshal_bytes(x_result_handle, y_result_handle, ec_handle, data, true);
     }
 
     fn ec_unmarshal_compressed(
         &self,
-        _x_result_handle: Self::BigIntHandle,
-        _y_result_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _data_handle: Self::ManagedBufferHandle,
+        x_result_handle: Self::BigIntHandle,
+        y_result_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        data_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_unmarshal_compressed not implemented")
+        let data = self.mb_get(data_handle);
+        self.ec_unmarshal_bytes(
+            x_result_handle,
+            y_result_handle,
+            ec_handle,
+            data.as_slice(),
+            true,
+        );
     }
 
     fn ec_generate_key_legacy(
         &self,
-        _x_pub_key_handle: Self::BigIntHandle,
-        _y_pub_key_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
+        x_pub_key_handle: Self::BigIntHandle,
+        y_pub_key_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
     ) -> BoxedBytes {
-        panic!("ec_generate_key not implemented")
+        self.ec_generate_key_bytes(x_pub_key_handle, y_pub_key_handle, ec_handle)
+            .into()
     }
 
     fn ec_generate_key(
         &self,
-        _x_pub_key_handle: Self::BigIntHandle,
-        _y_pub_key_handle: Self::BigIntHandle,
-        _ec_handle: Self::EllipticCurveHandle,
-        _result_handle: Self::ManagedBufferHandle,
+        x_pub_key_handle: Self::BigIntHandle,
+        y_pub_key_handle: Self::BigIntHandle,
+        ec_handle: Self::EllipticCurveHandle,
+        result_handle: Self::ManagedBufferHandle,
     ) {
-        panic!("ec_generate_key not implemented")
+        let private_key = self.ec_generate_key_bytes(x_pub_key_handle, y_pub_key_handle, ec_handle);
+        self.mb_overwrite(result_handle, private_key.as_slice());
     }
 }
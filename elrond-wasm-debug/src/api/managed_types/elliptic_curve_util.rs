@@ -0,0 +1,319 @@
+use crate::num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+
+/// An affine point. The point at infinity is represented as (0, 0), same as in the Go crypto library used by the VM.
+pub type EllipticCurvePoint = (BigUint, BigUint);
+
+/// Parameters of a curve of the form y² = x³ - 3x + b, which covers all NIST curves supported by the VM.
+#[derive(Debug, Clone)]
+pub struct EllipticCurveParams {
+    pub field_order: BigUint,
+    pub base_point_order: BigUint,
+    pub eq_constant: BigUint,
+    pub x_base_point: BigUint,
+    pub y_base_point: BigUint,
+    pub size_of_field: u32,
+}
+
+fn parse_hex(hex_str: &str) -> BigUint {
+    BigUint::parse_bytes(hex_str.as_bytes(), 16).unwrap()
+}
+
+impl EllipticCurveParams {
+    fn new(p: &str, n: &str, b: &str, gx: &str, gy: &str, size_of_field: u32) -> Self {
+        EllipticCurveParams {
+            field_order: parse_hex(p),
+            base_point_order: parse_hex(n),
+            eq_constant: parse_hex(b),
+            x_base_point: parse_hex(gx),
+            y_base_point: parse_hex(gy),
+            size_of_field,
+        }
+    }
+
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        match name {
+            b"p224" => Some(Self::new(
+                "ffffffffffffffffffffffffffffffff000000000000000000000001",
+                "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+                "b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4",
+                "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+                "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+                224,
+            )),
+            b"p256" => Some(Self::new(
+                "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+                "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+                "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+                "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+                "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+                256,
+            )),
+            b"p384" => Some(Self::new(
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff",
+                "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+                "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+                "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+                "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+                384,
+            )),
+            b"p521" => Some(Self::new(
+                "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+                "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+                "0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+                "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+                "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+                521,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Byte length of a field element, used when (un)marshalling points.
+    pub fn byte_len(&self) -> usize {
+        (self.size_of_field as usize + 7) / 8
+    }
+
+    pub fn private_key_byte_len(&self) -> usize {
+        (self.base_point_order.bits() as usize + 7) / 8
+    }
+
+    fn mod_add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + b) % &self.field_order
+    }
+
+    fn mod_sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + &self.field_order - b % &self.field_order) % &self.field_order
+    }
+
+    fn mod_mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a * b) % &self.field_order
+    }
+
+    /// Extended Euclidean algorithm. The field order is prime, so the inverse always exists for non-zero values.
+    fn mod_inv(&self, a: &BigUint) -> BigUint {
+        let modulus = BigInt::from(self.field_order.clone());
+        let (mut old_r, mut r) = (BigInt::from(a.clone()), modulus.clone());
+        let (mut old_s, mut s) = (BigInt::one(), BigInt::zero());
+        while !r.is_zero() {
+            let quotient = &old_r / &r;
+            let new_r = &old_r - &quotient * &r;
+            old_r = core::mem::replace(&mut r, new_r);
+            let new_s = &old_s - &quotient * &s;
+            old_s = core::mem::replace(&mut s, new_s);
+        }
+        ((old_s % &modulus + &modulus) % &modulus)
+            .to_biguint()
+            .unwrap()
+    }
+
+    /// Tonelli-Shanks, with the shortcut for p ≡ 3 (mod 4).
+    fn mod_sqrt(&self, a: &BigUint) -> Option<BigUint> {
+        let p = &self.field_order;
+        let one = BigUint::one();
+        let p_minus_one = p - &one;
+        if a.is_zero() {
+            return Some(BigUint::zero());
+        }
+        let legendre_exp = &p_minus_one >> 1;
+        if a.modpow(&legendre_exp, p) != one {
+            return None;
+        }
+        if p.bit(0) && p.bit(1) {
+            return Some(a.modpow(&((p + &one) >> 2), p));
+        }
+
+        let mut q = p_minus_one.clone();
+        let mut s = 0u64;
+        while !q.bit(0) {
+            q >>= 1;
+            s += 1;
+        }
+        let mut z = BigUint::from(2u32);
+        while z.modpow(&legendre_exp, p) != p_minus_one {
+            z += 1u32;
+        }
+
+        let mut m = s;
+        let mut c = z.modpow(&q, p);
+        let mut t = a.modpow(&q, p);
+        let mut r = a.modpow(&((&q + &one) >> 1), p);
+        while t != one {
+            let mut i = 0u64;
+            let mut t_pow = t.clone();
+            while t_pow != one {
+                t_pow = self.mod_mul(&t_pow, &t_pow);
+                i += 1;
+            }
+            let b = c.modpow(&(BigUint::one() << (m - i - 1)), p);
+            m = i;
+            c = self.mod_mul(&b, &b);
+            t = self.mod_mul(&t, &c);
+            r = self.mod_mul(&r, &b);
+        }
+        Some(r)
+    }
+
+    /// Computes x³ - 3x + b.
+    fn polynomial(&self, x: &BigUint) -> BigUint {
+        let x_cubed = self.mod_mul(&self.mod_mul(x, x), x);
+        let three_x = self.mod_mul(x, &BigUint::from(3u32));
+        self.mod_add(&self.mod_sub(&x_cubed, &three_x), &self.eq_constant)
+    }
+
+    pub fn is_on_curve(&self, x: &BigUint, y: &BigUint) -> bool {
+        if x >= &self.field_order || y >= &self.field_order {
+            return false;
+        }
+        self.mod_mul(y, y) == self.polynomial(x)
+    }
+
+    pub fn add(
+        &self,
+        first: &EllipticCurvePoint,
+        second: &EllipticCurvePoint,
+    ) -> EllipticCurvePoint {
+        if is_infinity(first) {
+            return second.clone();
+        }
+        if is_infinity(second) {
+            return first.clone();
+        }
+        let (x1, y1) = first;
+        let (x2, y2) = second;
+        if x1 == x2 {
+            if y1 == y2 {
+                return self.double(first);
+            }
+            return infinity();
+        }
+
+        let lambda = self.mod_mul(&self.mod_sub(y2, y1), &self.mod_inv(&self.mod_sub(x2, x1)));
+        self.point_from_lambda(&lambda, first, x2)
+    }
+
+    pub fn double(&self, point: &EllipticCurvePoint) -> EllipticCurvePoint {
+        let (x, y) = point;
+        if is_infinity(point) || y.is_zero() {
+            return infinity();
+        }
+
+        let three_x_squared = self.mod_mul(&self.mod_mul(x, x), &BigUint::from(3u32));
+        let numerator = self.mod_sub(&three_x_squared, &BigUint::from(3u32));
+        let denominator = self.mod_add(y, y);
+        let lambda = self.mod_mul(&numerator, &self.mod_inv(&denominator));
+        self.point_from_lambda(&lambda, point, x)
+    }
+
+    fn point_from_lambda(
+        &self,
+        lambda: &BigUint,
+        first: &EllipticCurvePoint,
+        x2: &BigUint,
+    ) -> EllipticCurvePoint {
+        let (x1, y1) = first;
+        let x3 = self.mod_sub(&self.mod_sub(&self.mod_mul(lambda, lambda), x1), x2);
+        let y3 = self.mod_sub(&self.mod_mul(lambda, &self.mod_sub(x1, &x3)), y1);
+        (x3, y3)
+    }
+
+    /// The scalar is interpreted as a big-endian unsigned integer.
+    pub fn scalar_mult(&self, point: &EllipticCurvePoint, scalar: &[u8]) -> EllipticCurvePoint {
+        let mut result = infinity();
+        for byte in scalar {
+            for bit_index in (0..8).rev() {
+                result = self.double(&result);
+                if (byte >> bit_index) & 1 == 1 {
+                    result = self.add(&result, point);
+                }
+            }
+        }
+        result
+    }
+
+    pub fn scalar_base_mult(&self, scalar: &[u8]) -> EllipticCurvePoint {
+        let base_point = (self.x_base_point.clone(), self.y_base_point.clone());
+        self.scalar_mult(&base_point, scalar)
+    }
+
+    /// Uncompressed form: 0x04 || x || y.
+    pub fn marshal(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let byte_len = self.byte_len();
+        let mut result = vec![4u8];
+        result.extend_from_slice(&pad_left(x, byte_len));
+        result.extend_from_slice(&pad_left(y, byte_len));
+        result
+    }
+
+    /// Compressed form: 0x02 or 0x03 (depending on the parity of y) || x.
+    pub fn marshal_compressed(&self, x: &BigUint, y: &BigUint) -> Vec<u8> {
+        let mut result = vec![2u8 + y.bit(0) as u8];
+        result.extend_from_slice(&pad_left(x, self.byte_len()));
+        result
+    }
+
+    /// Expects the length to have already been checked.
+    pub fn unmarshal(&self, data: &[u8]) -> Option<EllipticCurvePoint> {
+        let byte_len = self.byte_len();
+        if data[0] != 4 {
+            return None;
+        }
+        let x = BigUint::from_bytes_be(&data[1..1 + byte_len]);
+        let y = BigUint::from_bytes_be(&data[1 + byte_len..]);
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// Expects the length to have already been checked.
+    pub fn unmarshal_compressed(&self, data: &[u8]) -> Option<EllipticCurvePoint> {
+        if data[0] != 2 && data[0] != 3 {
+            return None;
+        }
+        let x = BigUint::from_bytes_be(&data[1..]);
+        if x >= self.field_order {
+            return None;
+        }
+        let mut y = self.mod_sqrt(&self.polynomial(&x))?;
+        if y.bit(0) != (data[0] & 1 == 1) {
+            y = self.mod_sub(&BigUint::zero(), &y);
+        }
+        if !self.is_on_curve(&x, &y) {
+            return None;
+        }
+        Some((x, y))
+    }
+
+    /// Turns random bytes into a valid private key, if possible.
+    /// Mirrors the Go implementation: the excess high bits are masked out
+    /// and the candidate is rejected if it is zero or not below the base point order.
+    pub fn private_key_from_random_bytes(&self, mut random_bytes: Vec<u8>) -> Option<Vec<u8>> {
+        const MASK: [u8; 8] = [0xff, 0x1, 0x3, 0x7, 0xf, 0x1f, 0x3f, 0x7f];
+        random_bytes[0] &= MASK[self.base_point_order.bits() as usize % 8];
+        let candidate = BigUint::from_bytes_be(&random_bytes);
+        if candidate.is_zero() || candidate >= self.base_point_order {
+            return None;
+        }
+        Some(random_bytes)
+    }
+}
+
+pub fn infinity() -> EllipticCurvePoint {
+    (BigUint::zero(), BigUint::zero())
+}
+
+fn is_infinity(point: &EllipticCurvePoint) -> bool {
+    point.0.is_zero() && point.1.is_zero()
+}
+
+fn pad_left(value: &BigUint, byte_len: usize) -> Vec<u8> {
+    let bytes = if value.is_zero() {
+        Vec::new()
+    } else {
+        value.to_bytes_be()
+    };
+    let mut result = vec![0u8; byte_len - bytes.len()];
+    result.extend_from_slice(&bytes);
+    result
+}
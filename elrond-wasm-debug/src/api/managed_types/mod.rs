@@ -1,7 +1,10 @@
 mod big_float_api_mock;
 mod big_int_api_mock;
 mod elliptic_curve_api_mock;
+mod elliptic_curve_util;
 mod managed_buffer_api_mock;
 mod managed_type_api_mock;
 mod managed_type_util;
 mod static_var_api_mock;
+
+pub use elliptic_curve_util::{EllipticCurveParams, EllipticCurvePoint};
@@ -1,6 +1,8 @@
 mod blockchain_api_mock;
 mod call_value_api_mock;
 mod crypto_api_mock;
+mod crypto_bls_util;
+mod crypto_secp256k1_util;
 mod debug_handle_mock;
 mod endpoint_arg_api_mock;
 mod endpoint_finish_api_mock;
@@ -13,3 +15,4 @@ mod storage_api_mock;
 mod vm_api_mock;
 
 pub use debug_handle_mock::DebugHandle;
+pub use managed_types::{EllipticCurveParams, EllipticCurvePoint};
@@ -1,7 +1,10 @@
 use crate::{
     num_bigint,
-    tx_execution::{deploy_contract, execute_builtin_function_or_default},
-    tx_mock::{AsyncCallTxData, BlockchainUpdate, Promise, TxCache, TxInput, TxPanic, TxResult},
+    tx_execution::{deploy_contract, execute_builtin_function_or_default, execute_on_same_context},
+    tx_mock::{
+        AsyncCallTxData, BlockchainUpdate, Promise, TxCache, TxInput, TxPanic, TxResult,
+        TxTraceCallType, TxTraceEntry,
+    },
     DebugApi,
 };
 use elrond_wasm::{
@@ -35,6 +38,31 @@ impl DebugApi {
         }
     }
 
+    fn record_call_trace(&self, call_type: TxTraceCallType, tx_input: &TxInput) {
+        self.tx_cache.record_trace(|| TxTraceEntry::Call {
+            call_type,
+            from: tx_input.from.clone(),
+            to: tx_input.to.clone(),
+            endpoint: tx_input.func_name.clone(),
+            args: tx_input.args.clone(),
+        });
+    }
+
+    fn record_async_call_trace(&self, call_type: TxTraceCallType, call: &AsyncCallTxData) {
+        self.tx_cache.record_trace(|| TxTraceEntry::Call {
+            call_type,
+            from: call.from.clone(),
+            to: call.to.clone(),
+            endpoint: call.endpoint_name.clone(),
+            args: call.arguments.clone(),
+        });
+    }
+
+    /// The gas given to a call cannot exceed the gas left in the current context.
+    fn call_gas_limit(&self, gas: u64) -> u64 {
+        gas.min(self.gas_left())
+    }
+
     fn sync_call_post_processing(
         &self,
         tx_result: TxResult,
@@ -49,6 +77,7 @@ impl DebugApi {
 
     fn prepare_execute_on_dest_context_input(
         &self,
+        gas: u64,
         to: Address,
         egld_value: num_bigint::BigUint,
         func_name: Vec<u8>,
@@ -63,25 +92,36 @@ impl DebugApi {
             esdt_values: Vec::new(),
             func_name,
             args,
-            gas_limit: 1000,
+            gas_limit: self.call_gas_limit(gas),
             gas_price: 0,
             tx_hash,
             promise_callback_closure_data: Vec::new(),
         }
     }
 
-    fn perform_execute_on_dest_context(
+    /// Transfers and any other operations that would change the state of other accounts
+    /// are forbidden during read-only calls.
+    fn check_not_readonly(&self) {
+        if self.blockchain_cache().is_readonly() {
+            std::panic::panic_any(TxPanic {
+                status: 10,
+                message: err_msg::OPERATION_NOT_PERMITTED_READONLY.to_string(),
+            });
+        }
+    }
+
+    fn check_not_readonly_transfer(&self, egld_value: &num_bigint::BigUint) {
+        if !egld_value.is_zero() {
+            self.check_not_readonly();
+        }
+    }
+
+    fn sync_call_result_or_panic(
         &self,
-        to: Address,
-        egld_value: num_bigint::BigUint,
-        func_name: Vec<u8>,
-        args: Vec<Vec<u8>>,
+        tx_result: TxResult,
+        blockchain_updates: BlockchainUpdate,
     ) -> Vec<Vec<u8>> {
-        let tx_input = self.prepare_execute_on_dest_context_input(to, egld_value, func_name, args);
-        let tx_cache = TxCache::new(self.blockchain_cache_rc());
-        let (tx_result, blockchain_updates) =
-            execute_builtin_function_or_default(tx_input, tx_cache);
-
+        self.use_gas(tx_result.gas_used);
         if tx_result.result_status == 0 {
             self.sync_call_post_processing(tx_result, blockchain_updates)
         } else {
@@ -93,18 +133,96 @@ impl DebugApi {
         }
     }
 
+    fn perform_execute_on_dest_context(
+        &self,
+        gas: u64,
+        to: Address,
+        egld_value: num_bigint::BigUint,
+        func_name: Vec<u8>,
+        args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        self.check_not_readonly_transfer(&egld_value);
+        self.use_gas_from_schedule(|gas_schedule| {
+            gas_schedule.base_ops_api_cost.execute_on_dest_context
+        });
+        let tx_input =
+            self.prepare_execute_on_dest_context_input(gas, to, egld_value, func_name, args);
+        self.record_call_trace(TxTraceCallType::ExecuteOnDestContext, &tx_input);
+        let tx_cache = TxCache::new(self.blockchain_cache_rc());
+        let (tx_result, blockchain_updates) =
+            execute_builtin_function_or_default(tx_input, tx_cache);
+
+        self.sync_call_result_or_panic(tx_result, blockchain_updates)
+    }
+
+    fn perform_execute_on_dest_context_readonly(
+        &self,
+        gas: u64,
+        to: Address,
+        func_name: Vec<u8>,
+        args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.base_ops_api_cost.execute_read_only);
+        let tx_input = self.prepare_execute_on_dest_context_input(
+            gas,
+            to,
+            num_bigint::BigUint::zero(),
+            func_name,
+            args,
+        );
+        self.record_call_trace(TxTraceCallType::ExecuteReadOnly, &tx_input);
+        let tx_cache = TxCache::new_readonly(self.blockchain_cache_rc());
+        let (tx_result, blockchain_updates) =
+            execute_builtin_function_or_default(tx_input, tx_cache);
+
+        self.sync_call_result_or_panic(tx_result, blockchain_updates)
+    }
+
+    fn perform_execute_on_same_context(
+        &self,
+        gas: u64,
+        to: Address,
+        egld_value: num_bigint::BigUint,
+        func_name: Vec<u8>,
+        args: Vec<Vec<u8>>,
+    ) -> Vec<Vec<u8>> {
+        self.check_not_readonly_transfer(&egld_value);
+        self.use_gas_from_schedule(|gas_schedule| {
+            gas_schedule.base_ops_api_cost.execute_on_same_context
+        });
+        let contract_code = self.get_contract_code(&to);
+
+        // the callee runs on behalf of the current contract, on the current contract's account
+        let mut tx_input =
+            self.prepare_execute_on_dest_context_input(gas, to, egld_value, func_name, args);
+        self.record_call_trace(TxTraceCallType::ExecuteOnSameContext, &tx_input);
+        tx_input.to = tx_input.from.clone();
+
+        let tx_cache = TxCache::new(self.blockchain_cache_rc());
+        let (tx_result, blockchain_updates) =
+            execute_on_same_context(tx_input, contract_code, tx_cache);
+
+        self.sync_call_result_or_panic(tx_result, blockchain_updates)
+    }
+
     fn perform_transfer_execute(
         &self,
+        gas: u64,
         to: Address,
         egld_value: num_bigint::BigUint,
         func_name: Vec<u8>,
         args: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
-        let tx_input = self.prepare_execute_on_dest_context_input(to, egld_value, func_name, args);
+        self.check_not_readonly();
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.base_ops_api_cost.transfer_value);
+        let tx_input =
+            self.prepare_execute_on_dest_context_input(gas, to, egld_value, func_name, args);
+        self.record_call_trace(TxTraceCallType::TransferExecute, &tx_input);
         let tx_cache = TxCache::new(self.blockchain_cache_rc());
         let (tx_result, blockchain_updates) =
             execute_builtin_function_or_default(tx_input, tx_cache);
 
+        self.use_gas(tx_result.gas_used);
         if tx_result.result_status == 0 {
             self.sync_call_post_processing(tx_result, blockchain_updates)
         } else {
@@ -118,10 +236,13 @@ impl DebugApi {
 
     fn perform_deploy(
         &self,
+        gas: u64,
         contract_code: Vec<u8>,
         egld_value: num_bigint::BigUint,
         args: Vec<Vec<u8>>,
     ) -> (Address, Vec<Vec<u8>>) {
+        self.check_not_readonly();
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.base_ops_api_cost.create_contract);
         let contract_address = &self.input_ref().to;
         let tx_hash = self.get_tx_hash_legacy();
         let tx_input = TxInput {
@@ -131,7 +252,7 @@ impl DebugApi {
             esdt_values: Vec::new(),
             func_name: Vec::new(),
             args,
-            gas_limit: 1000,
+            gas_limit: self.call_gas_limit(gas),
             gas_price: 0,
             tx_hash,
             promise_callback_closure_data: Vec::new(),
@@ -142,6 +263,7 @@ impl DebugApi {
         let (tx_result, new_address, blockchain_updates) =
             deploy_contract(tx_input, contract_code, tx_cache);
 
+        self.use_gas(tx_result.gas_used);
         if tx_result.result_status == 0 {
             (
                 new_address,
@@ -156,7 +278,11 @@ impl DebugApi {
         }
     }
 
-    fn perform_async_call(&self, call: AsyncCallTxData) -> ! {
+    fn perform_async_call(&self, mut call: AsyncCallTxData) -> ! {
+        self.check_not_readonly();
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.base_ops_api_cost.create_async_call);
+        call.gas_limit = self.call_gas_limit(call.gas_limit);
+        self.record_async_call_trace(TxTraceCallType::AsyncCall, &call);
         // the cell is no longer needed, since we end in a panic
         let mut tx_result = self.extract_result();
         tx_result.result_calls.async_call = Some(call);
@@ -165,6 +291,7 @@ impl DebugApi {
 
     fn perform_upgrade_contract<M: ManagedTypeApi>(
         &self,
+        gas: u64,
         sc_address: &ManagedAddress<M>,
         amount: &BigUint<M>,
         contract_code: Vec<u8>,
@@ -190,6 +317,7 @@ impl DebugApi {
             endpoint_name: UPGRADE_CONTRACT_FUNC_NAME.to_vec(),
             arguments,
             tx_hash,
+            gas_limit: gas,
         };
         self.perform_async_call(call)
     }
@@ -214,7 +342,7 @@ impl SendApiImpl for DebugApi {
         &self,
         to: &ManagedAddress<M>,
         amount: &BigUint<M>,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name: &ManagedBuffer<M>,
         arg_buffer: &ManagedArgBuffer<M>,
     ) -> Result<(), &'static [u8]> {
@@ -223,6 +351,7 @@ impl SendApiImpl for DebugApi {
         let recipient = to.to_address();
 
         let _ = self.perform_transfer_execute(
+            gas_limit,
             recipient,
             egld_value,
             endpoint_name.to_boxed_bytes().into_vec(),
@@ -237,7 +366,7 @@ impl SendApiImpl for DebugApi {
         to: &ManagedAddress<M>,
         token: &TokenIdentifier<M>,
         amount: &BigUint<M>,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name: &ManagedBuffer<M>,
         arg_buffer: &ManagedArgBuffer<M>,
     ) -> Result<(), &'static [u8]> {
@@ -249,6 +378,7 @@ impl SendApiImpl for DebugApi {
         Self::append_endpoint_name_and_args(&mut args, endpoint_name, arg_buffer);
 
         let _ = self.perform_transfer_execute(
+            gas_limit,
             recipient,
             num_bigint::BigUint::zero(),
             ESDT_TRANSFER_FUNC_NAME.to_vec(),
@@ -264,7 +394,7 @@ impl SendApiImpl for DebugApi {
         token: &TokenIdentifier<M>,
         nonce: u64,
         amount: &BigUint<M>,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name: &ManagedBuffer<M>,
         arg_buffer: &ManagedArgBuffer<M>,
     ) -> Result<(), &'static [u8]> {
@@ -285,6 +415,7 @@ impl SendApiImpl for DebugApi {
         Self::append_endpoint_name_and_args(&mut args, endpoint_name, arg_buffer);
 
         let _ = self.perform_transfer_execute(
+            gas_limit,
             contract_address,
             num_bigint::BigUint::zero(),
             ESDT_NFT_TRANSFER_FUNC_NAME.to_vec(),
@@ -298,7 +429,7 @@ impl SendApiImpl for DebugApi {
         &self,
         to: &ManagedAddress<M>,
         payments: &ManagedVec<M, EsdtTokenPayment<M>>,
-        _gas_limit: u64,
+        gas_limit: u64,
         endpoint_name: &ManagedBuffer<M>,
         arg_buffer: &ManagedArgBuffer<M>,
     ) -> Result<(), &'static [u8]> {
@@ -329,6 +460,7 @@ impl SendApiImpl for DebugApi {
         }
 
         let _ = self.perform_transfer_execute(
+            gas_limit,
             contract_address,
             num_bigint::BigUint::zero(),
             ESDT_MULTI_TRANSFER_FUNC_NAME.to_vec(),
@@ -357,6 +489,7 @@ impl SendApiImpl for DebugApi {
             endpoint_name: endpoint_name.to_boxed_bytes().into_vec(),
             arguments: arg_buffer.to_raw_args_vec(),
             tx_hash,
+            gas_limit: self.gas_left(),
         };
         self.perform_async_call(call)
     }
@@ -369,10 +502,13 @@ impl SendApiImpl for DebugApi {
         arg_buffer_handle: Self::ManagedBufferHandle,
         success_callback: &'static [u8],
         error_callback: &'static [u8],
-        _gas: u64,
-        _extra_gas_for_callback: u64,
+        gas: u64,
+        extra_gas_for_callback: u64,
         callback_closure_handle: Self::ManagedBufferHandle,
     ) {
+        self.check_not_readonly();
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.base_ops_api_cost.create_async_call);
+
         let amount_value = self.big_uint_handle_to_value(amount);
         let contract_address = self.input_ref().to.clone();
         let recipient = self.address_handle_to_value(to);
@@ -390,13 +526,17 @@ impl SendApiImpl for DebugApi {
             )
             .to_raw_args_vec(),
             tx_hash,
+            gas_limit: self.call_gas_limit(gas),
         };
 
+        self.record_async_call_trace(TxTraceCallType::Promise, &call);
+
         let promise = Promise {
             endpoint: call,
             success_callback,
             error_callback,
             callback_closure_data,
+            extra_gas_for_callback,
         };
 
         let mut tx_result = self.result_borrow_mut();
@@ -405,7 +545,7 @@ impl SendApiImpl for DebugApi {
 
     fn deploy_contract<M: ManagedTypeApi>(
         &self,
-        _gas: u64,
+        gas: u64,
         amount: &BigUint<M>,
         code: &ManagedBuffer<M>,
         _code_metadata: CodeMetadata,
@@ -415,14 +555,14 @@ impl SendApiImpl for DebugApi {
             self.big_uint_handle_to_value(amount.get_handle().cast_or_signal_error::<M, _>());
         let contract_code = code.to_boxed_bytes().into_vec();
         let (new_address, result) =
-            self.perform_deploy(contract_code, egld_value, arg_buffer.to_raw_args_vec());
+            self.perform_deploy(gas, contract_code, egld_value, arg_buffer.to_raw_args_vec());
 
         (ManagedAddress::from(new_address), ManagedVec::from(result))
     }
 
     fn deploy_from_source_contract<M: ManagedTypeApi>(
         &self,
-        _gas: u64,
+        gas: u64,
         amount: &BigUint<M>,
         source_contract_address: &ManagedAddress<M>,
         _code_metadata: CodeMetadata,
@@ -432,6 +572,7 @@ impl SendApiImpl for DebugApi {
             self.big_uint_handle_to_value(amount.get_handle().cast_or_signal_error::<M, _>());
         let source_contract_code = self.get_contract_code(&source_contract_address.to_address());
         let (new_address, result) = self.perform_deploy(
+            gas,
             source_contract_code,
             egld_value,
             arg_buffer.to_raw_args_vec(),
@@ -443,32 +584,46 @@ impl SendApiImpl for DebugApi {
     fn upgrade_contract<M: ManagedTypeApi>(
         &self,
         sc_address: &ManagedAddress<M>,
-        _gas: u64,
+        gas: u64,
         amount: &BigUint<M>,
         code: &ManagedBuffer<M>,
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<M>,
     ) {
         let contract_code = code.to_boxed_bytes().into_vec();
-        self.perform_upgrade_contract(sc_address, amount, contract_code, code_metadata, arg_buffer)
+        self.perform_upgrade_contract(
+            gas,
+            sc_address,
+            amount,
+            contract_code,
+            code_metadata,
+            arg_buffer,
+        )
     }
 
     fn upgrade_from_source_contract<M: ManagedTypeApi>(
         &self,
         sc_address: &ManagedAddress<M>,
-        _gas: u64,
+        gas: u64,
         amount: &BigUint<M>,
         source_contract_address: &ManagedAddress<M>,
         code_metadata: CodeMetadata,
         arg_buffer: &ManagedArgBuffer<M>,
     ) {
         let contract_code = self.get_contract_code(&source_contract_address.to_address());
-        self.perform_upgrade_contract(sc_address, amount, contract_code, code_metadata, arg_buffer)
+        self.perform_upgrade_contract(
+            gas,
+            sc_address,
+            amount,
+            contract_code,
+            code_metadata,
+            arg_buffer,
+        )
     }
 
     fn execute_on_dest_context_raw<M: ManagedTypeApi>(
         &self,
-        _gas: u64,
+        gas: u64,
         to: &ManagedAddress<M>,
         value: &BigUint<M>,
         endpoint_name: &ManagedBuffer<M>,
@@ -479,6 +634,7 @@ impl SendApiImpl for DebugApi {
         let recipient = to.to_address();
 
         let result = self.perform_execute_on_dest_context(
+            gas,
             recipient,
             egld_value,
             endpoint_name.to_boxed_bytes().into_vec(),
@@ -490,23 +646,44 @@ impl SendApiImpl for DebugApi {
 
     fn execute_on_same_context_raw<M: ManagedTypeApi>(
         &self,
-        _gas: u64,
-        _to: &ManagedAddress<M>,
-        _value: &BigUint<M>,
-        _endpoint_name: &ManagedBuffer<M>,
-        _arg_buffer: &ManagedArgBuffer<M>,
+        gas: u64,
+        to: &ManagedAddress<M>,
+        value: &BigUint<M>,
+        endpoint_name: &ManagedBuffer<M>,
+        arg_buffer: &ManagedArgBuffer<M>,
     ) -> ManagedVec<M, ManagedBuffer<M>> {
-        panic!("execute_on_same_context_raw not implemented yet!");
+        let egld_value =
+            self.big_uint_handle_to_value(value.get_handle().cast_or_signal_error::<M, _>());
+        let recipient = to.to_address();
+
+        let result = self.perform_execute_on_same_context(
+            gas,
+            recipient,
+            egld_value,
+            endpoint_name.to_boxed_bytes().into_vec(),
+            arg_buffer.to_raw_args_vec(),
+        );
+
+        ManagedVec::from(result)
     }
 
     fn execute_on_dest_context_readonly_raw<M: ManagedTypeApi>(
         &self,
-        _gas: u64,
-        _to: &ManagedAddress<M>,
-        _endpoint_name: &ManagedBuffer<M>,
-        _arg_buffer: &ManagedArgBuffer<M>,
+        gas: u64,
+        to: &ManagedAddress<M>,
+        endpoint_name: &ManagedBuffer<M>,
+        arg_buffer: &ManagedArgBuffer<M>,
     ) -> ManagedVec<M, ManagedBuffer<M>> {
-        panic!("execute_on_dest_context_readonly_raw not implemented yet!");
+        let recipient = to.to_address();
+
+        let result = self.perform_execute_on_dest_context_readonly(
+            gas,
+            recipient,
+            endpoint_name.to_boxed_bytes().into_vec(),
+            arg_buffer.to_raw_args_vec(),
+        );
+
+        ManagedVec::from(result)
     }
 
     fn clean_return_data(&self) {
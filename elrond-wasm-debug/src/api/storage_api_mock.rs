@@ -1,12 +1,15 @@
 use crate::{
     num_bigint::{BigInt, Sign},
-    tx_mock::TxPanic,
+    tx_mock::{TxPanic, TxTraceEntry},
     DebugApi,
 };
 use alloc::vec::Vec;
-use elrond_wasm::api::{
-    BigIntApi, ManagedBufferApi, StorageReadApi, StorageReadApiImpl, StorageWriteApi,
-    StorageWriteApiImpl,
+use elrond_wasm::{
+    api::{
+        BigIntApi, ManagedBufferApi, StorageReadApi, StorageReadApiImpl, StorageWriteApi,
+        StorageWriteApiImpl,
+    },
+    err_msg,
 };
 
 impl StorageReadApi for DebugApi {
@@ -19,10 +22,18 @@ impl StorageReadApi for DebugApi {
 
 impl DebugApi {
     fn storage_load_vec_u8(&self, key: &[u8]) -> Vec<u8> {
-        self.with_contract_account(|account| match account.storage.get(&key.to_vec()) {
-            None => Vec::with_capacity(0),
-            Some(value) => value.clone(),
-        })
+        let value =
+            self.with_contract_account(|account| match account.storage.get(&key.to_vec()) {
+                None => Vec::with_capacity(0),
+                Some(value) => value.clone(),
+            });
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.storage_load_cost(value.len()));
+        self.tx_cache.record_trace(|| TxTraceEntry::StorageRead {
+            address: self.tx_input_box.to.clone(),
+            key: key.to_vec(),
+            value: value.clone(),
+        });
+        value
     }
 }
 
@@ -61,12 +72,20 @@ impl StorageReadApiImpl for DebugApi {
             self.mb_to_boxed_bytes(address_handle).as_slice(),
         );
         let key_bytes = self.mb_to_boxed_bytes(key_handle);
-        self.with_account(&address, |account| {
-            match account.storage.get(key_bytes.as_slice()) {
-                None => self.mb_overwrite(dest, &[]),
-                Some(value) => self.mb_overwrite(dest, value.as_slice()),
-            }
-        })
+        let value = self.with_account(&address, |account| {
+            account
+                .storage
+                .get(key_bytes.as_slice())
+                .cloned()
+                .unwrap_or_default()
+        });
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.storage_load_cost(value.len()));
+        self.tx_cache.record_trace(|| TxTraceEntry::StorageRead {
+            address,
+            key: key_bytes.into_vec(),
+            value: value.clone(),
+        });
+        self.mb_overwrite(dest, value.as_slice());
     }
 }
 
@@ -88,8 +107,22 @@ impl StorageWriteApiImpl for DebugApi {
             });
         }
 
-        self.with_contract_account_mut(|account| {
-            account.storage.insert(key.to_vec(), value.to_vec());
+        if self.blockchain_cache().is_readonly() {
+            std::panic::panic_any(TxPanic {
+                status: 10,
+                message: err_msg::STORAGE_WRITE_READONLY.to_string(),
+            });
+        }
+
+        self.use_gas_from_schedule(|gas_schedule| gas_schedule.storage_store_cost(value.len()));
+        let old_value = self.with_contract_account_mut(|account| {
+            account.storage.insert(key.to_vec(), value.to_vec())
+        });
+        self.tx_cache.record_trace(|| TxTraceEntry::StorageWrite {
+            address: self.tx_input_box.to.clone(),
+            key: key.to_vec(),
+            old_value: old_value.unwrap_or_default(),
+            new_value: value.to_vec(),
         });
     }
 
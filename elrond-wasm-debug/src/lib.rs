@@ -7,7 +7,9 @@ pub mod bech32;
 mod display_util;
 mod managed_test_util;
 pub mod mandos_system;
+pub mod merkle_tree;
 pub mod meta;
+pub mod storage_decode;
 pub mod testing_framework;
 pub mod tx_execution;
 pub mod tx_mock;
@@ -16,6 +18,7 @@ pub mod world_mock;
 pub use display_util::*;
 pub use managed_test_util::*;
 pub use mandos_system::{executor::*, mandos_go, mandos_rs};
+pub use merkle_tree::MerkleTree;
 
 pub use tx_mock::DebugApi;
 pub use world_mock::BlockchainMock;
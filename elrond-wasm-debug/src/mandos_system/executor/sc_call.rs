@@ -7,7 +7,7 @@ use crate::{
     world_mock::BlockchainMock,
 };
 
-use super::check_tx_output;
+use super::{check_tx_gas, check_tx_output};
 
 impl BlockchainMock {
     /// Adds a mandos SC call step, as specified in the `sc_call_step` argument, then executes it.
@@ -87,6 +87,10 @@ fn execute_and_check(
     let (tx_result, state) = execute(state, sc_call_step);
     if let Some(tx_expect) = &sc_call_step.expect {
         check_tx_output(&sc_call_step.id, tx_expect, &tx_result);
+        if state.gas_schedule.is_some() {
+            let gas_limit = sc_call_step.tx.gas_limit.value;
+            check_tx_gas(&sc_call_step.id, tx_expect, gas_limit, &tx_result);
+        }
     }
     (tx_result, state)
 }
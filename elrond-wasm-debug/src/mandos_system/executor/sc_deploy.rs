@@ -10,7 +10,7 @@ use crate::{
     world_mock::BlockchainMock,
 };
 
-use super::check_tx_output;
+use super::{check_tx_gas, check_tx_output};
 
 impl BlockchainMock {
     /// Adds a mandos SC deploy step, as specified in the `sc_deploy_step` argument, then executes it.
@@ -97,6 +97,10 @@ fn execute_and_check(
     let (tx_result, address, state) = execute(state, sc_deploy_step);
     if let Some(tx_expect) = &sc_deploy_step.expect {
         check_tx_output(&sc_deploy_step.id, tx_expect, &tx_result);
+        if state.gas_schedule.is_some() {
+            let gas_limit = sc_deploy_step.tx.gas_limit.value;
+            check_tx_gas(&sc_deploy_step.id, tx_expect, gas_limit, &tx_result);
+        }
     }
     (tx_result, address, state)
 }
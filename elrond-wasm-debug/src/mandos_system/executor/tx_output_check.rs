@@ -1,4 +1,5 @@
 use crate::mandos_system::model::{CheckLogs, Checkable, TxExpect};
+use elrond_wasm::types::parse_contract_error_code;
 
 use crate::{address_hex, bytes_to_string, tx_mock::TxResult, verbose_hex, verbose_hex_list};
 
@@ -29,6 +30,18 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
         have_str,
     );
 
+    if !tx_expect.error_code.is_star() {
+        let have_error_code = parse_contract_error_code(tx_result.result_message.as_bytes());
+        assert!(
+            have_error_code.map_or(false, |code| tx_expect.error_code.check(code as u64)),
+            "error code mismatch. Tx id: '{}'. Want: {}. Have: {:?}. Message: {}",
+            tx_id,
+            tx_expect.error_code,
+            have_error_code,
+            have_str,
+        );
+    }
+
     match &tx_expect.logs {
         CheckLogs::Star => {},
         CheckLogs::List(expected_logs) => {
@@ -72,3 +85,16 @@ pub fn check_tx_output(tx_id: &str, tx_expect: &TxExpect, tx_result: &TxResult)
         },
     }
 }
+
+/// Only relevant when gas metering is enabled. As in mandos-go, the expected `gas` is the gas remaining.
+pub fn check_tx_gas(tx_id: &str, tx_expect: &TxExpect, gas_limit: u64, tx_result: &TxResult) {
+    let gas_remaining = gas_limit.saturating_sub(tx_result.gas_used);
+    assert!(
+        tx_expect.gas.check(gas_remaining),
+        "gas remaining mismatch. Tx id: '{}'. Want: {}. Have: {}. Gas used: {}",
+        tx_id,
+        tx_expect.gas,
+        gas_remaining,
+        tx_result.gas_used,
+    );
+}
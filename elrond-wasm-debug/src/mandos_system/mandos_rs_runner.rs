@@ -8,10 +8,12 @@ use std::path::Path;
 /// Runs mandos test using the Rust infrastructure and the debug mode.
 /// Uses a contract map to replace the references to the wasm bytecode
 /// with the contracts running in debug mode.
+/// If sharding is enabled, cross-shard calls are delivered at the end of the step that produced them.
 pub fn mandos_rs<P: AsRef<Path>>(relative_path: P, mut world: BlockchainMock) {
     let mut absolute_path = world.current_dir.clone();
     absolute_path.push(relative_path);
     parse_execute_mandos_steps(absolute_path.as_ref(), &mut world);
+    world.write_coverage_reports();
 }
 
 fn parse_execute_mandos_steps(steps_path: &Path, state: &mut BlockchainMock) {
@@ -49,5 +51,8 @@ fn parse_execute_mandos_steps(steps_path: &Path, state: &mut BlockchainMock) {
                 state.mandos_dump_state();
             },
         }
+
+        // scenarios cannot deliver cross-shard calls explicitly, so they complete within the step that made them
+        state.execute_all_cross_shard_calls();
     }
 }
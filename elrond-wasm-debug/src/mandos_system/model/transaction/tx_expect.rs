@@ -9,6 +9,10 @@ pub struct TxExpect {
     pub out: CheckValueList,
     pub status: CheckValue<U64Value>,
     pub message: CheckValue<BytesValue>,
+    /// The code of an error declared via `#[derive(ContractError)]`, parsed from the message.
+    ///
+    /// Only checked by the Rust runner, so it is not part of the scenario JSON.
+    pub error_code: CheckValue<U64Value>,
     pub logs: CheckLogs,
     pub gas: CheckValue<U64Value>,
     pub refund: CheckValue<U64Value>,
@@ -20,6 +24,7 @@ impl TxExpect {
             out: CheckValue::Star,
             status: CheckValue::Equal(U64Value::zero()),
             message: CheckValue::Star,
+            error_code: CheckValue::Star,
             logs: CheckLogs::Star,
             gas: CheckValue::Star,
             refund: CheckValue::Star,
@@ -38,6 +43,24 @@ impl TxExpect {
             out: CheckValue::Star,
             status: CheckValue::Equal(status_code),
             message: CheckValue::Equal(err_msg),
+            error_code: CheckValue::Star,
+            logs: CheckLogs::Star,
+            gas: CheckValue::Star,
+            refund: CheckValue::Star,
+        }
+    }
+
+    /// Expects an error declared via `#[derive(ContractError)]`, checked by code only,
+    /// so that the message arguments do not need to be spelled out.
+    pub fn err_code<C>(error_code_expr: C) -> Self
+    where
+        U64Value: From<C>,
+    {
+        TxExpect {
+            out: CheckValue::Star,
+            status: CheckValue::Equal(<U64Value as From<u64>>::from(4)),
+            message: CheckValue::Star,
+            error_code: CheckValue::Equal(U64Value::from(error_code_expr)),
             logs: CheckLogs::Star,
             gas: CheckValue::Star,
             refund: CheckValue::Star,
@@ -49,6 +72,15 @@ impl TxExpect {
         self
     }
 
+    /// The gas expected to remain after the transaction. Only checked when gas metering is enabled.
+    pub fn gas<V>(mut self, gas_remaining_expr: V) -> Self
+    where
+        U64Value: From<V>,
+    {
+        self.gas = CheckValue::Equal(U64Value::from(gas_remaining_expr));
+        self
+    }
+
     pub fn result(mut self, value: &str) -> Self {
         let mut check_results = match self.out {
             CheckValue::Star => Vec::new(),
@@ -70,6 +102,7 @@ impl InterpretableFrom<TxExpectRaw> for TxExpect {
             status: CheckValue::<U64Value>::interpret_from(from.status, context),
             logs: CheckLogs::interpret_from(from.logs, context),
             message: CheckValue::<BytesValue>::interpret_from(from.message, context),
+            error_code: CheckValue::Star,
             gas: CheckValue::<U64Value>::interpret_from(from.gas, context),
             refund: CheckValue::<U64Value>::interpret_from(from.refund, context),
         }
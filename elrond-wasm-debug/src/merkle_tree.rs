@@ -0,0 +1,114 @@
+use elrond_wasm::types::{
+    MerkleHashAlgorithm, MERKLE_HASH_LEN, MERKLE_LEAF_PREFIX, MERKLE_NODE_PREFIX,
+};
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+
+pub type MerkleHashBytes = [u8; MERKLE_HASH_LEN];
+
+/// Off-chain Merkle tree, that produces the same root and proofs as the contract-side `MerkleTreeHasher`.
+///
+/// Useful for setting up Merkle airdrops and whitelists in tests.
+pub struct MerkleTree {
+    algorithm: MerkleHashAlgorithm,
+    /// All the levels of the tree, starting with the leaf hashes and ending with the root.
+    levels: Vec<Vec<MerkleHashBytes>>,
+}
+
+impl MerkleTree {
+    /// Hashes the leaves and builds the whole tree.
+    ///
+    /// Panics if there are no leaves.
+    pub fn new<I, D>(algorithm: MerkleHashAlgorithm, leaves: I) -> Self
+    where
+        I: IntoIterator<Item = D>,
+        D: AsRef<[u8]>,
+    {
+        let leaf_hashes: Vec<MerkleHashBytes> = leaves
+            .into_iter()
+            .map(|leaf| hash_leaf(algorithm, leaf.as_ref()))
+            .collect();
+        assert!(!leaf_hashes.is_empty(), "Merkle tree has no leaves");
+
+        let mut levels = vec![leaf_hashes];
+        while levels.last().unwrap().len() > 1 {
+            let next_level = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [first, second] => hash_pair(algorithm, first, second),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next_level);
+        }
+
+        MerkleTree { algorithm, levels }
+    }
+
+    pub fn algorithm(&self) -> MerkleHashAlgorithm {
+        self.algorithm
+    }
+
+    pub fn num_leaves(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    pub fn root(&self) -> MerkleHashBytes {
+        self.levels.last().unwrap()[0]
+    }
+
+    pub fn leaf_hash(&self, leaf_index: usize) -> MerkleHashBytes {
+        self.levels[0][leaf_index]
+    }
+
+    /// The sibling hashes on the path from the leaf to the root.
+    pub fn proof(&self, leaf_index: usize) -> Vec<MerkleHashBytes> {
+        assert!(
+            leaf_index < self.num_leaves(),
+            "Merkle leaf index out of range"
+        );
+
+        let mut proof = Vec::new();
+        let mut index = leaf_index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling_index = index ^ 1;
+            if sibling_index < level.len() {
+                proof.push(level[sibling_index]);
+            }
+            index /= 2;
+        }
+        proof
+    }
+}
+
+fn hash(algorithm: MerkleHashAlgorithm, data: &[u8]) -> MerkleHashBytes {
+    match algorithm {
+        MerkleHashAlgorithm::Keccak256 => Keccak256::digest(data).into(),
+        MerkleHashAlgorithm::Sha256 => Sha256::digest(data).into(),
+    }
+}
+
+pub fn hash_leaf(algorithm: MerkleHashAlgorithm, data: &[u8]) -> MerkleHashBytes {
+    let mut prefixed = vec![MERKLE_LEAF_PREFIX];
+    prefixed.extend_from_slice(data);
+    hash(algorithm, &prefixed)
+}
+
+pub fn hash_pair(
+    algorithm: MerkleHashAlgorithm,
+    first: &MerkleHashBytes,
+    second: &MerkleHashBytes,
+) -> MerkleHashBytes {
+    let (lesser, greater) = if first <= second {
+        (first, second)
+    } else {
+        (second, first)
+    };
+    let mut concatenated = vec![MERKLE_NODE_PREFIX];
+    concatenated.extend_from_slice(lesser);
+    concatenated.extend_from_slice(greater);
+    hash(algorithm, &concatenated)
+}
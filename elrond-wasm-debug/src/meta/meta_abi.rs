@@ -1,16 +1,26 @@
 use std::{
-    fs::{create_dir_all, File},
+    fs::{self, create_dir_all, File},
     io::Write,
     process::{Command, Output},
 };
 
-use crate::abi_json::{serialize_abi_to_json, ContractAbiJson};
+use crate::abi_json::{serialize_abi_to_json, ContractAbiJson, ContractBuildAbiJson};
 
-use super::{meta_config::MetaConfig, output_contract::OutputContract};
+use super::{
+    meta_config::MetaConfig,
+    meta_verify::{cargo_lock_hash, code_hash},
+    output_contract::OutputContract,
+};
 
-fn write_contract_abi(output_contract: &OutputContract, git_version: &str, output_path: &str) {
+fn write_contract_abi(
+    output_contract: &OutputContract,
+    git_version: &str,
+    contract_build: Option<ContractBuildAbiJson>,
+    output_path: &str,
+) {
     let mut abi_json = ContractAbiJson::from(&output_contract.abi);
     abi_json.build_info.contract_crate.git_version = git_version.to_string();
+    abi_json.build_info.contract_build = contract_build;
     let abi_string = serialize_abi_to_json(&abi_json);
 
     let abi_file_path = format!("{output_path}/{}", output_contract.abi_output_name(),);
@@ -26,11 +36,33 @@ impl MetaConfig {
             write_contract_abi(
                 output_contract,
                 git_version.as_str(),
+                None,
                 self.output_dir.as_str(),
             );
         }
     }
 
+    /// Rewrites the ABI of a freshly built contract, to also contain the code hash and the build settings.
+    pub fn write_contract_build_abi(&self, output_contract: &OutputContract) {
+        let output_wasm_path = format!(
+            "{}/{}",
+            self.output_dir,
+            output_contract.wasm_output_name(&self.build_args)
+        );
+        let wasm_bytes = fs::read(output_wasm_path).expect("failed to read compiled contract");
+        let contract_build = ContractBuildAbiJson {
+            code_hash: hex::encode(code_hash(wasm_bytes.as_slice())),
+            cargo_lock_hash: cargo_lock_hash(output_contract.cargo_lock_path().as_str()),
+            build_flags: self.build_args.to_cli_args(),
+        };
+        write_contract_abi(
+            output_contract,
+            self.git_describe().as_str(),
+            Some(contract_build),
+            self.output_dir.as_str(),
+        );
+    }
+
     fn git_describe(&self) -> String {
         if !self.build_args.abi_git_version {
             return String::new();
@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct BuildArgs {
     pub debug_symbols: bool,
     pub wasm_name_override: Option<String>,
@@ -8,6 +8,13 @@ pub struct BuildArgs {
     pub extract_imports: bool,
     pub target_dir: Option<String>,
     pub abi_git_version: bool,
+    /// Passes `--locked` to cargo, so that the build fails if `Cargo.lock` would change.
+    pub locked: bool,
+    pub size_report: bool,
+    /// Directory containing the size reports of a previous build.
+    pub size_compare_dir: Option<String>,
+    /// Maximum allowed size increase, in percent, compared to the previous build.
+    pub size_threshold: Option<f64>,
 }
 
 impl Default for BuildArgs {
@@ -21,6 +28,10 @@ impl Default for BuildArgs {
             extract_imports: true,
             target_dir: None,
             abi_git_version: true,
+            locked: false,
+            size_report: false,
+            size_compare_dir: None,
+            size_threshold: None,
         }
     }
 }
@@ -65,10 +76,68 @@ impl BuildArgs {
                 "--no-abi-git-version" => {
                     result.abi_git_version = false;
                 },
+                "--locked" => {
+                    result.locked = true;
+                },
+                "--size-report" => {
+                    result.size_report = true;
+                },
+                "--size-compare" => {
+                    let dir = iter
+                        .next()
+                        .expect("argument `--size-compare` must be followed by the previous output directory");
+                    result.size_report = true;
+                    result.size_compare_dir = Some(dir.clone());
+                },
+                "--size-threshold" => {
+                    let threshold = iter
+                        .next()
+                        .expect("argument `--size-threshold` must be followed by a percentage");
+                    result.size_threshold = Some(
+                        threshold
+                            .parse()
+                            .expect("argument `--size-threshold` must be a number"),
+                    );
+                },
                 _ => {},
             }
         }
 
+        assert!(
+            result.size_threshold.is_none() || result.size_compare_dir.is_some(),
+            "argument `--size-threshold` requires `--size-compare`, there is nothing to compare the size against otherwise"
+        );
+
+        result
+    }
+    /// The arguments that reproduce the flags relevant for the contract bytecode.
+    ///
+    /// The target directory and the report flags are left out, since they do not influence the output.
+    pub fn to_cli_args(&self) -> Vec<String> {
+        let mut result = Vec::new();
+        if self.debug_symbols {
+            result.push("--wasm-symbols".to_string());
+        }
+        if let Some(wasm_name_override) = &self.wasm_name_override {
+            result.push("--wasm-name".to_string());
+            result.push(wasm_name_override.clone());
+        }
+        if let Some(wasm_name_suffix) = &self.wasm_name_suffix {
+            result.push("--wasm-suffix".to_string());
+            result.push(wasm_name_suffix.clone());
+        }
+        if !self.wasm_opt {
+            result.push("--no-wasm-opt".to_string());
+        }
+        if self.wat {
+            result.push("--wat".to_string());
+        }
+        if !self.extract_imports {
+            result.push("--no-imports".to_string());
+        }
+        if self.locked {
+            result.push("--locked".to_string());
+        }
         result
     }
 }
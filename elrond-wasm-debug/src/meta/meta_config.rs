@@ -4,7 +4,7 @@ use elrond_wasm::abi::ContractAbi;
 
 use super::{
     meta_build_args::BuildArgs,
-    output_contract::{CargoTomlContents, OutputContractConfig},
+    output_contract::{CargoTomlContents, OutputContract, OutputContractConfig},
 };
 
 const OUTPUT_RELATIVE_PATH: &str = "../output";
@@ -77,10 +77,15 @@ impl MetaConfig {
         self.check_tools_installed();
 
         for output_contract in &self.output_contracts.contracts {
-            output_contract.build_contract(&self.build_args, self.output_dir.as_str());
+            self.build_output_contract(output_contract);
         }
     }
 
+    pub(crate) fn build_output_contract(&self, output_contract: &OutputContract) {
+        output_contract.build_contract(&self.build_args, self.output_dir.as_str());
+        self.write_contract_build_abi(output_contract);
+    }
+
     /// Convenience functionality, to get all flags right for the debug build.
     pub fn build_dbg(&mut self) {
         self.build_args.wasm_name_suffix = Some("dbg".to_string());
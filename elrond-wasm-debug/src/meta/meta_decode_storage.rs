@@ -0,0 +1,27 @@
+use crate::storage_decode::{load_storage_dump, StorageDecoder};
+
+use super::meta_config::MetaConfig;
+
+impl MetaConfig {
+    /// Prints the storage from a mandos scenario or from a JSON dump,
+    /// with keys and values decoded based on the storage layout in the ABI.
+    pub fn decode_storage(&self, args: &[String]) {
+        let path = args
+            .first()
+            .expect("usage: decode-storage <mandos scenario or JSON storage dump>");
+        let decoder = StorageDecoder::new(&self.original_contract_abi);
+
+        for account_dump in load_storage_dump(path) {
+            let indent = match &account_dump.account {
+                Some(account) => {
+                    println!("{account}:");
+                    "    "
+                },
+                None => "",
+            };
+            for entry in decoder.decode_storage(&account_dump.storage) {
+                println!("{indent}{} = {}", entry.key, entry.value);
+            }
+        }
+    }
+}
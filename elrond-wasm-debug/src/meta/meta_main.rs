@@ -1,6 +1,8 @@
-use elrond_wasm::contract_base::ContractAbiProvider;
+use elrond_wasm::contract_base::{CallableContractBuilder, ContractAbiProvider};
 use std::env;
 
+use crate::world_mock::BlockchainMock;
+
 use super::{
     meta_build_args::BuildArgs, meta_config::MetaConfig, output_contract::OutputContractConfig,
 };
@@ -8,6 +10,38 @@ use super::{
 static SNIPPETS_OVERWRITE_FLAG_NAME: &str = "--overwrite";
 
 pub fn perform<AbiObj: ContractAbiProvider>() {
+    perform_with_world_builder::<AbiObj>(None)
+}
+
+/// Same as `perform`, but also allows the `test` command to run the scenarios with the Rust runner.
+pub fn perform_with_contract<AbiObj, B>(contract_builder: B)
+where
+    AbiObj: ContractAbiProvider,
+    B: CallableContractBuilder,
+{
+    perform_with_contract_and_world_setup::<AbiObj, B, _>(contract_builder, |_| {})
+}
+
+/// Same as `perform_with_contract`, but the world of the `test` command can be further set up,
+/// typically to register the other contracts that the scenarios deploy or call.
+///
+/// The paths of the other contracts are relative to the contract crate, same as in the scenarios.
+pub fn perform_with_contract_and_world_setup<AbiObj, B, F>(contract_builder: B, world_setup: F)
+where
+    AbiObj: ContractAbiProvider,
+    B: CallableContractBuilder,
+    F: Fn(&mut BlockchainMock),
+{
+    perform_with_world_builder::<AbiObj>(Some(&|meta_config: &MetaConfig| {
+        let mut world = meta_config.mandos_world(&contract_builder);
+        world_setup(&mut world);
+        world
+    }))
+}
+
+fn perform_with_world_builder<AbiObj: ContractAbiProvider>(
+    world_builder: Option<&dyn Fn(&MetaConfig) -> BlockchainMock>,
+) {
     let original_contract_abi = <AbiObj as ContractAbiProvider>::abi();
     super::meta_validate_abi::validate_abi(&original_contract_abi).unwrap();
 
@@ -24,6 +58,8 @@ pub fn perform<AbiObj: ContractAbiProvider>() {
             "build" => meta_config.build(),
             "build-dbg" => meta_config.build_dbg(),
             "clean" => meta_config.clean(),
+            "verify" => meta_config.verify(&args[2..]),
+            "decode-storage" => meta_config.decode_storage(&args[2..]),
             "snippets" => {
                 let overwrite = match args.get(2) {
                     Some(arg) => arg.as_str() == SNIPPETS_OVERWRITE_FLAG_NAME,
@@ -32,6 +68,18 @@ pub fn perform<AbiObj: ContractAbiProvider>() {
 
                 meta_config.generate_rust_snippets(overwrite);
             },
+            "test" => {
+                let world_factory = world_builder.map(|world_builder| {
+                    let meta_config = &meta_config;
+                    move || world_builder(meta_config)
+                });
+                meta_config.test(
+                    &args[2..],
+                    world_factory
+                        .as_ref()
+                        .map(|world_factory| world_factory as &dyn Fn() -> BlockchainMock),
+                );
+            },
             _ => (),
         }
     }
@@ -0,0 +1,289 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use elrond_wasm::contract_base::CallableContractBuilder;
+
+use crate::{
+    world_mock::{BlockchainMock, ContractContainer},
+    DebugApi,
+};
+
+use super::{
+    meta_build_args::BuildArgs, meta_config::MetaConfig, output_contract::OutputContractConfig,
+};
+
+const MANDOS_DIR_NAME: &str = "mandos";
+const SCENARIO_FILE_SUFFIX: &str = ".scen.json";
+const MANDOS_GO_EXECUTABLE: &str = "mandos-test";
+const NO_GO_FLAG_NAME: &str = "--no-go";
+
+/// Creates a fresh world, with all the contracts needed by the scenarios registered,
+/// and the current directory set to the contract crate.
+pub type MandosWorldFactory<'a> = &'a dyn Fn() -> BlockchainMock;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ScenarioOutcome {
+    Passed,
+    Failed(String),
+    Skipped,
+}
+
+#[derive(Clone, Debug)]
+pub struct ScenarioTestResult {
+    /// Relative to the contract crate.
+    pub path: PathBuf,
+    pub rs: ScenarioOutcome,
+    pub go: ScenarioOutcome,
+}
+
+impl ScenarioOutcome {
+    pub fn is_failed(&self) -> bool {
+        matches!(self, ScenarioOutcome::Failed(_))
+    }
+
+    fn table_cell(&self) -> &'static str {
+        match self {
+            ScenarioOutcome::Passed => "pass",
+            ScenarioOutcome::Failed(_) => "FAIL",
+            ScenarioOutcome::Skipped => "-",
+        }
+    }
+}
+
+impl ScenarioTestResult {
+    pub fn is_failed(&self) -> bool {
+        self.rs.is_failed() || self.go.is_failed()
+    }
+
+    /// False if the scenario was skipped by both runners.
+    pub fn has_run(&self) -> bool {
+        self.rs != ScenarioOutcome::Skipped || self.go != ScenarioOutcome::Skipped
+    }
+}
+
+/// Recursively lists all `*.scen.json` files in a directory, sorted.
+pub fn find_scenario_files(dir: &Path) -> Vec<PathBuf> {
+    let mut result = Vec::new();
+    let read_dir = if let Ok(read_dir) = fs::read_dir(dir) {
+        read_dir
+    } else {
+        return result;
+    };
+    for entry_result in read_dir {
+        let path = entry_result
+            .expect("error reading mandos directory entry")
+            .path();
+        if path.is_dir() {
+            result.append(&mut find_scenario_files(path.as_path()));
+        } else if path
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().ends_with(SCENARIO_FILE_SUFFIX))
+            .unwrap_or(false)
+        {
+            result.push(path);
+        }
+    }
+    result.sort();
+    result
+}
+
+/// Checks whether the Go runner can be found in the `PATH`.
+pub fn is_mandos_go_installed() -> bool {
+    env::var_os("PATH")
+        .map(|paths| env::split_paths(&paths).any(|dir| dir.join(MANDOS_GO_EXECUTABLE).is_file()))
+        .unwrap_or(false)
+}
+
+/// Runs each scenario with the Rust runner, if a world is provided, and with the Go runner, if requested.
+///
+/// The scenario paths are relative to the contract directory.
+pub fn run_scenarios(
+    contract_dir: &Path,
+    scenario_paths: &[PathBuf],
+    world_factory: Option<MandosWorldFactory>,
+    run_go: bool,
+) -> Vec<ScenarioTestResult> {
+    scenario_paths
+        .iter()
+        .map(|path| ScenarioTestResult {
+            path: path.clone(),
+            rs: world_factory
+                .map(|world_factory| run_scenario_rs(path, world_factory))
+                .unwrap_or(ScenarioOutcome::Skipped),
+            go: if run_go {
+                run_scenario_go(contract_dir.join(path).as_path())
+            } else {
+                ScenarioOutcome::Skipped
+            },
+        })
+        .collect()
+}
+
+fn run_scenario_rs(path: &Path, world_factory: MandosWorldFactory) -> ScenarioOutcome {
+    // the panic messages go to stderr, the report is printed to stdout
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        crate::mandos_rs(path, world_factory());
+    }));
+
+    match result {
+        Ok(()) => ScenarioOutcome::Passed,
+        Err(panic_any) => {
+            let message = if let Some(s) = panic_any.downcast_ref::<String>() {
+                s.clone()
+            } else if let Some(s) = panic_any.downcast_ref::<&str>() {
+                s.to_string()
+            } else {
+                "unknown panic".to_string()
+            };
+            ScenarioOutcome::Failed(message)
+        },
+    }
+}
+
+fn run_scenario_go(absolute_path: &Path) -> ScenarioOutcome {
+    let output = Command::new(MANDOS_GO_EXECUTABLE)
+        .arg(absolute_path)
+        .output()
+        .expect("failed to execute mandos-test");
+
+    if output.status.success() {
+        ScenarioOutcome::Passed
+    } else {
+        ScenarioOutcome::Failed(format!(
+            "{}{}",
+            String::from_utf8_lossy(output.stdout.as_slice()),
+            String::from_utf8_lossy(output.stderr.as_slice())
+        ))
+    }
+}
+
+pub fn print_scenario_results(results: &[ScenarioTestResult]) {
+    let path_width = results
+        .iter()
+        .map(|result| result.path.display().to_string().len())
+        .max()
+        .unwrap_or_default()
+        .max("scenario".len());
+
+    println!("{:<path_width$}  {:<4}  go", "scenario", "rs");
+    for result in results {
+        println!(
+            "{:<path_width$}  {:<4}  {}",
+            result.path.display().to_string(),
+            result.rs.table_cell(),
+            result.go.table_cell()
+        );
+    }
+
+    for result in results.iter().filter(|result| result.is_failed()) {
+        for (runner_name, outcome) in [("rs", &result.rs), ("go", &result.go)] {
+            if let ScenarioOutcome::Failed(message) = outcome {
+                println!(
+                    "\n{} failed ({runner_name}):\n{}",
+                    result.path.display(),
+                    message.trim_end()
+                );
+            }
+        }
+    }
+
+    let num_failed = results.iter().filter(|result| result.is_failed()).count();
+    println!(
+        "\n{} scenarios, {} passed, {num_failed} failed",
+        results.len(),
+        results.len() - num_failed
+    );
+}
+
+/// A world with the current directory set to the contract crate,
+/// and all the output contracts registered, at their respective paths in the output directory.
+///
+/// Multi-contract outputs only expose their own endpoints, same as `register_partial_contract`.
+pub fn output_contracts_world<B: CallableContractBuilder>(
+    contract_dir: PathBuf,
+    output_contracts: &OutputContractConfig,
+    build_args: &BuildArgs,
+    contract_builder: &B,
+) -> BlockchainMock {
+    let mut world = BlockchainMock::new();
+    world.current_dir = contract_dir;
+
+    for output_contract in &output_contracts.contracts {
+        let contract_obj = if output_contract.external_view {
+            contract_builder.new_contract_obj::<elrond_wasm::api::ExternalViewApi<DebugApi>>()
+        } else {
+            contract_builder.new_contract_obj::<DebugApi>()
+        };
+        let function_whitelist = if output_contract.main {
+            None
+        } else {
+            Some(output_contract.all_exported_function_names())
+        };
+
+        world.register_contract_container(
+            format!(
+                "file:output/{}",
+                output_contract.wasm_output_name(build_args)
+            )
+            .as_str(),
+            ContractContainer::new(contract_obj, function_whitelist),
+        );
+    }
+
+    world
+}
+
+impl MetaConfig {
+    /// A world with all the output contracts registered, at their respective paths in the output directory.
+    pub fn mandos_world<B: CallableContractBuilder>(&self, contract_builder: &B) -> BlockchainMock {
+        output_contracts_world(
+            contract_dir(),
+            &self.output_contracts,
+            &self.build_args,
+            contract_builder,
+        )
+    }
+
+    /// Runs all the scenarios in the `mandos` directory of the contract and prints the results.
+    ///
+    /// Exits with an error code if any of the scenarios fails, or if no scenario could be run at all.
+    pub fn test(&self, args: &[String], world_factory: Option<MandosWorldFactory>) {
+        let contract_dir = contract_dir();
+        let scenario_paths: Vec<PathBuf> =
+            find_scenario_files(contract_dir.join(MANDOS_DIR_NAME).as_path())
+                .into_iter()
+                .map(|path| path.strip_prefix(&contract_dir).unwrap().to_path_buf())
+                .collect();
+
+        if world_factory.is_none() {
+            println!("Skipping the Rust runner, the contract builder was not provided to the meta crate.");
+        }
+        let run_go = !args.iter().any(|arg| arg == NO_GO_FLAG_NAME) && is_mandos_go_installed();
+        if !run_go {
+            println!("Skipping the Go runner.");
+        }
+
+        let results = run_scenarios(
+            contract_dir.as_path(),
+            scenario_paths.as_slice(),
+            world_factory,
+            run_go,
+        );
+        print_scenario_results(results.as_slice());
+
+        if !results.iter().any(ScenarioTestResult::has_run) {
+            eprintln!("Error: no scenario was run.");
+            std::process::exit(1);
+        }
+        if results.iter().any(ScenarioTestResult::is_failed) {
+            std::process::exit(1);
+        }
+    }
+}
+
+fn contract_dir() -> PathBuf {
+    fs::canonicalize("..").expect("could not resolve contract directory")
+}
@@ -0,0 +1,427 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, fs, path::Path};
+
+const WASM_MAGIC: &[u8] = b"\0asm";
+const WASM_HEADER_LEN: usize = 8;
+
+const CUSTOM_SECTION_ID: u8 = 0;
+const IMPORT_SECTION_ID: u8 = 2;
+const EXPORT_SECTION_ID: u8 = 7;
+const CODE_SECTION_ID: u8 = 10;
+const DATA_SECTION_ID: u8 = 11;
+
+const NAME_SECTION_NAME: &str = "name";
+const FUNCTION_NAMES_SUBSECTION_ID: u8 = 1;
+
+const IMPORT_KIND_FUNCTION: u8 = 0;
+const IMPORT_KIND_TABLE: u8 = 1;
+const IMPORT_KIND_MEMORY: u8 = 2;
+const IMPORT_KIND_GLOBAL: u8 = 3;
+const EXPORT_KIND_FUNCTION: u8 = 0;
+
+/// Size breakdown of a compiled contract.
+///
+/// Function names are only available if the name section was kept, i.e. for debug builds.
+/// Otherwise exported functions are named after their export, the rest are just `func[index]`.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmSizeReport {
+    pub total_size: usize,
+    pub code_section_size: usize,
+    pub data_section_size: usize,
+    pub sections: Vec<WasmSectionSize>,
+    /// Sorted by size, largest first.
+    pub functions: Vec<WasmFunctionSize>,
+    pub imports: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WasmSectionSize {
+    pub name: String,
+    pub size: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WasmFunctionSize {
+    pub index: u32,
+    pub name: String,
+    pub size: usize,
+}
+
+/// Size changes between two builds of the same contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WasmSizeComparison {
+    pub size_before: usize,
+    pub size_after: usize,
+    /// Only the functions that changed, sorted by the absolute size change, largest first.
+    pub function_changes: Vec<WasmFunctionSizeChange>,
+}
+
+/// A size of 0 means that the function is missing from that build.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WasmFunctionSizeChange {
+    pub name: String,
+    pub size_before: usize,
+    pub size_after: usize,
+}
+
+struct WasmReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> WasmReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        WasmReader { bytes, position: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position >= self.bytes.len()
+    }
+
+    fn read_u8(&mut self) -> Result<u8, String> {
+        let byte = *self
+            .bytes
+            .get(self.position)
+            .ok_or_else(|| "unexpected end of wasm".to_string())?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, String> {
+        let mut result = 0u32;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_u8()?;
+            if shift >= 32 {
+                return Err("invalid LEB128 integer in wasm".to_string());
+            }
+            result |= ((byte & 0x7f) as u32) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift += 7;
+        }
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self.position + len;
+        if end > self.bytes.len() {
+            return Err("unexpected end of wasm".to_string());
+        }
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn read_name(&mut self) -> Result<String, String> {
+        let len = self.read_u32()? as usize;
+        let bytes = self.read_bytes(len)?;
+        Ok(String::from_utf8_lossy(bytes).to_string())
+    }
+
+    fn skip_limits(&mut self) -> Result<(), String> {
+        let flags = self.read_u8()?;
+        self.read_u32()?;
+        if flags & 1 != 0 {
+            self.read_u32()?;
+        }
+        Ok(())
+    }
+}
+
+fn section_name(id: u8) -> &'static str {
+    match id {
+        1 => "type",
+        2 => "import",
+        3 => "function",
+        4 => "table",
+        5 => "memory",
+        6 => "global",
+        7 => "export",
+        8 => "start",
+        9 => "element",
+        10 => "code",
+        11 => "data",
+        12 => "datacount",
+        _ => "unknown",
+    }
+}
+
+impl WasmSizeReport {
+    pub fn from_wasm_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() < WASM_HEADER_LEN || &bytes[..WASM_MAGIC.len()] != WASM_MAGIC {
+            return Err("not a wasm binary".to_string());
+        }
+
+        let mut report = WasmSizeReport {
+            total_size: bytes.len(),
+            ..Default::default()
+        };
+        let mut num_imported_functions = 0u32;
+        let mut export_names = Vec::new();
+        let mut debug_names = Vec::new();
+        let mut function_body_sizes = Vec::new();
+
+        let mut reader = WasmReader::new(&bytes[WASM_HEADER_LEN..]);
+        while !reader.is_empty() {
+            let id = reader.read_u8()?;
+            let size = reader.read_u32()? as usize;
+            let mut section_reader = WasmReader::new(reader.read_bytes(size)?);
+            let name = match id {
+                CUSTOM_SECTION_ID => {
+                    let custom_name = section_reader.read_name()?;
+                    if custom_name == NAME_SECTION_NAME {
+                        debug_names = parse_function_names(&mut section_reader)?;
+                    }
+                    format!("custom:{custom_name}")
+                },
+                IMPORT_SECTION_ID => {
+                    num_imported_functions = parse_imports(&mut section_reader, &mut report)?;
+                    section_name(id).to_string()
+                },
+                EXPORT_SECTION_ID => {
+                    export_names = parse_function_exports(&mut section_reader)?;
+                    section_name(id).to_string()
+                },
+                CODE_SECTION_ID => {
+                    report.code_section_size = size;
+                    let num_bodies = section_reader.read_u32()?;
+                    for _ in 0..num_bodies {
+                        let body_size = section_reader.read_u32()? as usize;
+                        section_reader.read_bytes(body_size)?;
+                        function_body_sizes.push(body_size);
+                    }
+                    section_name(id).to_string()
+                },
+                DATA_SECTION_ID => {
+                    report.data_section_size = size;
+                    section_name(id).to_string()
+                },
+                _ => section_name(id).to_string(),
+            };
+            report.sections.push(WasmSectionSize { name, size });
+        }
+
+        report.functions = function_body_sizes
+            .into_iter()
+            .enumerate()
+            .map(|(i, size)| {
+                let index = num_imported_functions + i as u32;
+                let name = find_name(&debug_names, index)
+                    .map(|name| format!("{:#}", rustc_demangle::demangle(name)))
+                    .or_else(|| find_name(&export_names, index).map(str::to_string))
+                    .unwrap_or_else(|| format!("func[{index}]"));
+                WasmFunctionSize { index, name, size }
+            })
+            .collect();
+        report
+            .functions
+            .sort_by(|a, b| b.size.cmp(&a.size).then(a.index.cmp(&b.index)));
+
+        Ok(report)
+    }
+
+    pub fn load_json_file<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let json = fs::read_to_string(path).ok()?;
+        serde_json::from_str(json.as_str()).ok()
+    }
+
+    pub fn save_json_file<P: AsRef<Path>>(&self, path: P) {
+        let json = serde_json::to_string_pretty(self).unwrap();
+        fs::write(path, json).expect("failed to write size report file");
+    }
+
+    /// Prints how the contract size changed since the report saved by a previous build.
+    ///
+    /// Panics if the contract grew beyond the threshold, if one is given.
+    /// A missing previous report is only tolerated without a threshold, so that a size gate never passes without a baseline.
+    pub fn compare_with_previous_file<P: AsRef<Path>>(
+        &self,
+        contract_name: &str,
+        previous_report_path: P,
+        threshold: Option<f64>,
+    ) {
+        let previous_report_path = previous_report_path.as_ref();
+        let previous_report = match Self::load_json_file(previous_report_path) {
+            Some(previous_report) => previous_report,
+            None => {
+                assert!(
+                    threshold.is_none(),
+                    "no previous size report found at {}, cannot check the size threshold of contract {contract_name}",
+                    previous_report_path.display()
+                );
+                println!(
+                    "No previous size report found at {}, skipping comparison.",
+                    previous_report_path.display()
+                );
+                return;
+            },
+        };
+
+        let comparison = WasmSizeComparison::compare(&previous_report, self);
+        print!("{contract_name}: {comparison}");
+        if let Some(threshold) = threshold {
+            assert!(
+                !comparison.exceeds_threshold(threshold),
+                "contract {contract_name} grew by {:.2}%, which exceeds the {threshold}% threshold",
+                comparison.size_change_percent()
+            );
+        }
+    }
+}
+
+fn find_name(names: &[(u32, String)], index: u32) -> Option<&str> {
+    names
+        .iter()
+        .find(|(name_index, _)| *name_index == index)
+        .map(|(_, name)| name.as_str())
+}
+
+/// Also returns the number of imported functions, since they come first in the function index space.
+fn parse_imports(reader: &mut WasmReader, report: &mut WasmSizeReport) -> Result<u32, String> {
+    let mut num_imported_functions = 0;
+    let count = reader.read_u32()?;
+    for _ in 0..count {
+        let _module = reader.read_name()?;
+        let field = reader.read_name()?;
+        match reader.read_u8()? {
+            IMPORT_KIND_FUNCTION => {
+                reader.read_u32()?;
+                num_imported_functions += 1;
+                report.imports.push(field);
+            },
+            IMPORT_KIND_TABLE => {
+                reader.read_u8()?;
+                reader.skip_limits()?;
+            },
+            IMPORT_KIND_MEMORY => {
+                reader.skip_limits()?;
+            },
+            IMPORT_KIND_GLOBAL => {
+                reader.read_bytes(2)?;
+            },
+            kind => return Err(format!("unknown wasm import kind {kind}")),
+        }
+    }
+    Ok(num_imported_functions)
+}
+
+fn parse_function_exports(reader: &mut WasmReader) -> Result<Vec<(u32, String)>, String> {
+    let mut result = Vec::new();
+    let count = reader.read_u32()?;
+    for _ in 0..count {
+        let name = reader.read_name()?;
+        let kind = reader.read_u8()?;
+        let index = reader.read_u32()?;
+        if kind == EXPORT_KIND_FUNCTION {
+            result.push((index, name));
+        }
+    }
+    Ok(result)
+}
+
+fn parse_function_names(reader: &mut WasmReader) -> Result<Vec<(u32, String)>, String> {
+    let mut result = Vec::new();
+    while !reader.is_empty() {
+        let subsection_id = reader.read_u8()?;
+        let size = reader.read_u32()? as usize;
+        let mut subsection_reader = WasmReader::new(reader.read_bytes(size)?);
+        if subsection_id == FUNCTION_NAMES_SUBSECTION_ID {
+            let count = subsection_reader.read_u32()?;
+            for _ in 0..count {
+                let index = subsection_reader.read_u32()?;
+                let name = subsection_reader.read_name()?;
+                result.push((index, name));
+            }
+        }
+    }
+    Ok(result)
+}
+
+impl WasmSizeComparison {
+    pub fn compare(previous: &WasmSizeReport, current: &WasmSizeReport) -> Self {
+        let mut function_changes: Vec<WasmFunctionSizeChange> = Vec::new();
+        for function in &current.functions {
+            let size_before = previous
+                .functions
+                .iter()
+                .find(|previous_function| previous_function.name == function.name)
+                .map(|previous_function| previous_function.size)
+                .unwrap_or_default();
+            if size_before != function.size {
+                function_changes.push(WasmFunctionSizeChange {
+                    name: function.name.clone(),
+                    size_before,
+                    size_after: function.size,
+                });
+            }
+        }
+        for previous_function in &previous.functions {
+            if !current
+                .functions
+                .iter()
+                .any(|function| function.name == previous_function.name)
+            {
+                function_changes.push(WasmFunctionSizeChange {
+                    name: previous_function.name.clone(),
+                    size_before: previous_function.size,
+                    size_after: 0,
+                });
+            }
+        }
+        function_changes.sort_by_key(|change| std::cmp::Reverse(change.size_change().abs()));
+
+        WasmSizeComparison {
+            size_before: previous.total_size,
+            size_after: current.total_size,
+            function_changes,
+        }
+    }
+
+    pub fn size_change(&self) -> i64 {
+        self.size_after as i64 - self.size_before as i64
+    }
+
+    pub fn size_change_percent(&self) -> f64 {
+        if self.size_before == 0 {
+            return 0.0;
+        }
+        self.size_change() as f64 * 100.0 / self.size_before as f64
+    }
+
+    /// Size decreases never exceed the threshold.
+    pub fn exceeds_threshold(&self, threshold_percent: f64) -> bool {
+        self.size_change_percent() > threshold_percent
+    }
+}
+
+impl WasmFunctionSizeChange {
+    pub fn size_change(&self) -> i64 {
+        self.size_after as i64 - self.size_before as i64
+    }
+}
+
+impl fmt::Display for WasmSizeComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "size: {} -> {} ({:+} bytes, {:+.2}%)",
+            self.size_before,
+            self.size_after,
+            self.size_change(),
+            self.size_change_percent()
+        )?;
+        for change in &self.function_changes {
+            writeln!(
+                f,
+                "  {:+8}  {} ({} -> {})",
+                change.size_change(),
+                change.name,
+                change.size_before,
+                change.size_after
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use blake2::{
+    digest::{Update, VariableOutput},
+    VarBlake2b,
+};
+use sha2::{Digest, Sha256};
+
+use crate::abi_json::ContractAbiJson;
+
+use super::{meta_build_args::BuildArgs, meta_config::MetaConfig};
+
+const CODE_HASH_LEN: usize = 32;
+const CONTRACT_FLAG_NAME: &str = "--contract";
+
+/// The code hash, as computed by the protocol for deployed contracts (Blake2b, 256 bits).
+pub fn code_hash(wasm_bytes: &[u8]) -> [u8; CODE_HASH_LEN] {
+    let mut result = [0u8; CODE_HASH_LEN];
+    let mut hasher = VarBlake2b::new(CODE_HASH_LEN).unwrap();
+    hasher.update(wasm_bytes);
+    hasher.finalize_variable(|hash| result.copy_from_slice(hash));
+    result
+}
+
+/// Accepts either the path to a wasm file, or a hex-encoded code hash, with or without the `0x` prefix.
+pub fn parse_expected_code_hash(arg: &str) -> Result<Vec<u8>, String> {
+    if Path::new(arg).is_file() {
+        let wasm_bytes = fs::read(arg).map_err(|err| format!("could not read {arg}: {err}"))?;
+        return Ok(code_hash(wasm_bytes.as_slice()).to_vec());
+    }
+
+    let hex_str = arg.strip_prefix("0x").unwrap_or(arg);
+    let hash = hex::decode(hex_str)
+        .map_err(|_| format!("{arg} is neither a wasm file, nor a hex-encoded code hash"))?;
+    if hash.len() != CODE_HASH_LEN {
+        return Err(format!(
+            "code hash must be {CODE_HASH_LEN} bytes long, {} bytes provided",
+            hash.len()
+        ));
+    }
+    Ok(hash)
+}
+
+/// Hex-encoded SHA256 of the lock file, empty if there is none.
+pub(crate) fn cargo_lock_hash(cargo_lock_path: &str) -> String {
+    fs::read(cargo_lock_path)
+        .map(|contents| hex::encode(Sha256::digest(contents.as_slice())))
+        .unwrap_or_default()
+}
+
+/// The build settings recorded in the ABI of a previous build, as found in `buildInfo.contractBuild.buildFlags`.
+///
+/// The dependency versions are always pinned by `Cargo.lock`, regardless of the recorded flags.
+pub fn recorded_build_args<P: AsRef<Path>>(abi_path: P) -> Result<BuildArgs, String> {
+    let abi_path = abi_path.as_ref();
+    let abi_json = fs::read_to_string(abi_path)
+        .map_err(|err| format!("could not read {}: {err}", abi_path.display()))?;
+    let abi: ContractAbiJson = serde_json::from_str(abi_json.as_str())
+        .map_err(|err| format!("could not parse {}: {err}", abi_path.display()))?;
+    let contract_build = abi.build_info.contract_build.ok_or_else(|| {
+        format!(
+            "{} contains no build settings, the contract needs to be built first",
+            abi_path.display()
+        )
+    })?;
+
+    let mut build_args = BuildArgs::process(contract_build.build_flags.as_slice());
+    build_args.locked = true;
+    Ok(build_args)
+}
+
+impl MetaConfig {
+    /// Rebuilds a contract with the settings recorded in its ABI and the dependency versions pinned by `Cargo.lock`,
+    /// then compares the resulting code hash with the expected one.
+    ///
+    /// The rebuild goes to a temporary directory, so the existing build output is left untouched.
+    ///
+    /// Exits with an error code if the hashes differ.
+    pub fn verify(&mut self, args: &[String]) {
+        let expected_arg = args
+            .first()
+            .expect("usage: verify <wasm file or code hash> [--contract <contract name>]");
+        let expected_hash =
+            parse_expected_code_hash(expected_arg).unwrap_or_else(|err| panic!("{err}"));
+
+        let contract_name = match args.iter().position(|arg| arg == CONTRACT_FLAG_NAME) {
+            Some(index) => args
+                .get(index + 1)
+                .expect("argument `--contract` must be followed by the contract name")
+                .clone(),
+            None => self.output_contracts.main_contract().contract_name.clone(),
+        };
+
+        let abi_path = format!(
+            "{}/{}",
+            self.output_dir,
+            self.output_contracts
+                .find_contract(contract_name.as_str())
+                .abi_output_name()
+        );
+        let recorded_args = recorded_build_args(abi_path).unwrap_or_else(|err| panic!("{err}"));
+        self.build_args = BuildArgs {
+            target_dir: self.build_args.target_dir.clone(),
+            abi_git_version: self.build_args.abi_git_version,
+            ..recorded_args.clone()
+        };
+        self.check_tools_installed();
+        if recorded_args.wasm_opt && !self.build_args.wasm_opt {
+            println!("Warning: the contract was built using wasm-opt, the verification will most likely fail without it");
+        }
+
+        let output_contract = self.output_contracts.find_contract(contract_name.as_str());
+        let verify_dir = verify_output_dir(output_contract.contract_name.as_str());
+        fs::create_dir_all(&verify_dir).expect("failed to create verification output directory");
+        let verify_dir_str = verify_dir
+            .to_str()
+            .expect("verification output directory path is not valid UTF-8");
+        output_contract.build_contract(&self.build_args, verify_dir_str);
+
+        let output_wasm_path = verify_dir.join(output_contract.wasm_output_name(&self.build_args));
+        let wasm_bytes = fs::read(output_wasm_path).expect("failed to read compiled contract");
+        let actual_hash = code_hash(wasm_bytes.as_slice());
+        let _ = fs::remove_dir_all(&verify_dir);
+
+        println!("expected code hash: {}", hex::encode(&expected_hash));
+        println!("built code hash:    {}", hex::encode(actual_hash));
+        if actual_hash[..] == expected_hash[..] {
+            println!("Contract {} verified.", output_contract.contract_name);
+        } else {
+            println!(
+                "Contract {} does not match the expected code.",
+                output_contract.contract_name
+            );
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Separate from the regular output directory, so that the verification does not overwrite the artifacts it checks.
+fn verify_output_dir(contract_name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("{contract_name}-verify-{}", std::process::id()))
+}
@@ -1,10 +1,20 @@
 mod meta_abi;
 mod meta_build_args;
 mod meta_config;
+mod meta_decode_storage;
 mod meta_generate_snippets;
 mod meta_main;
+mod meta_mandos_test;
+mod meta_size_report;
 mod meta_validate_abi;
+mod meta_verify;
 mod meta_wasm_tools;
 pub mod output_contract;
 
-pub use meta_main::{multi_contract_config, perform};
+pub use meta_build_args::BuildArgs;
+pub use meta_main::{
+    multi_contract_config, perform, perform_with_contract, perform_with_contract_and_world_setup,
+};
+pub use meta_mandos_test::*;
+pub use meta_size_report::*;
+pub use meta_verify::{code_hash, parse_expected_code_hash, recorded_build_args};
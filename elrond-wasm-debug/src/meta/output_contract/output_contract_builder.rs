@@ -172,6 +172,8 @@ fn build_contract_abi(builder: OutputContractBuilder, original_abi: &ContractAbi
         endpoints: builder.endpoints,
         promise_callbacks: original_abi.promise_callbacks.clone(),
         events: original_abi.events.clone(),
+        storage: original_abi.storage.clone(),
+        errors: original_abi.errors.clone(),
         has_callback: !builder.external_view && original_abi.has_callback,
         type_descriptions: original_abi.type_descriptions.clone(),
     }
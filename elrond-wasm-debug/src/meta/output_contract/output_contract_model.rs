@@ -103,6 +103,10 @@ impl OutputContract {
         format!("{}/Cargo.toml", &self.wasm_crate_path())
     }
 
+    pub fn cargo_lock_path(&self) -> String {
+        format!("{}/Cargo.lock", &self.wasm_crate_path())
+    }
+
     /// The name of the wasm crate, as defined in its corresponding `Cargo.toml`.
     ///
     /// Note this does not necessarily have to match the name of the crate directory.
@@ -152,6 +156,10 @@ impl OutputContract {
         format!("{}.imports.json", self.output_name_base(build_args))
     }
 
+    pub fn size_report_output_name(&self, build_args: &BuildArgs) -> String {
+        format!("{}.size.json", self.output_name_base(build_args))
+    }
+
     pub fn endpoint_names(&self) -> Vec<String> {
         self.abi
             .endpoints
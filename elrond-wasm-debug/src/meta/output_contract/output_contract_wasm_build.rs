@@ -1,6 +1,6 @@
 use std::{fs, process::Command};
 
-use crate::meta::{meta_build_args::BuildArgs, meta_wasm_tools};
+use crate::meta::{meta_build_args::BuildArgs, meta_wasm_tools, WasmSizeReport};
 
 use super::OutputContract;
 
@@ -13,6 +13,9 @@ impl OutputContract {
         if let Some(target_dir) = &build_args.target_dir {
             command.args(["--target-dir", target_dir]);
         }
+        if build_args.locked {
+            command.arg("--locked");
+        }
         if !build_args.debug_symbols {
             command.env("RUSTFLAGS", "-C link-arg=-s");
         }
@@ -32,6 +35,7 @@ impl OutputContract {
         self.run_wasm_opt(build_args, output_path);
         self.run_wasm2wat(build_args, output_path);
         self.extract_imports(build_args, output_path);
+        self.write_size_report(build_args, output_path);
     }
 
     fn copy_contracts_to_output(&self, build_args: &BuildArgs, output_path: &str) {
@@ -75,6 +79,30 @@ impl OutputContract {
         let import_names = meta_wasm_tools::parse_imports(result.as_str());
         write_imports_output(output_imports_json_path.as_str(), import_names.as_slice());
     }
+
+    fn write_size_report(&self, build_args: &BuildArgs, output_path: &str) {
+        if !build_args.size_report {
+            return;
+        }
+
+        let output_wasm_path = format!("{output_path}/{}", self.wasm_output_name(build_args));
+        let wasm_bytes = fs::read(output_wasm_path).expect("failed to read compiled contract");
+        let report = WasmSizeReport::from_wasm_bytes(wasm_bytes.as_slice())
+            .expect("failed to parse compiled contract");
+        let size_report_name = self.size_report_output_name(build_args);
+        report.save_json_file(format!("{output_path}/{size_report_name}"));
+
+        let compare_dir = if let Some(compare_dir) = &build_args.size_compare_dir {
+            compare_dir
+        } else {
+            return;
+        };
+        report.compare_with_previous_file(
+            self.contract_name.as_str(),
+            format!("{compare_dir}/{size_report_name}"),
+            build_args.size_threshold,
+        );
+    }
 }
 
 fn write_imports_output(dest_path: &str, import_names: &[String]) {
@@ -0,0 +1,369 @@
+use crate::{
+    num_bigint::{BigInt, BigUint},
+    verbose_hex,
+};
+use elrond_wasm::{
+    abi::{
+        EnumVariantDescription, StructFieldDescription, TypeContents, TypeDescription,
+        TypeDescriptionContainerImpl,
+    },
+    elrond_codec::DecodeError,
+};
+
+const ADDRESS_LEN: usize = 32;
+const CODE_METADATA_LEN: usize = 2;
+
+pub const UNKNOWN_TYPE_ERR_MSG: &str = "unknown type";
+
+/// Decodes raw values, based on the type names and type descriptions from the ABI.
+///
+/// The result is a human-readable representation, not meant to be parsed back.
+pub struct AbiValueDecoder {
+    type_descriptions: TypeDescriptionContainerImpl,
+}
+
+impl AbiValueDecoder {
+    pub fn new(type_descriptions: TypeDescriptionContainerImpl) -> Self {
+        AbiValueDecoder { type_descriptions }
+    }
+
+    /// Adds a struct description, unless there is already a description with the same name.
+    pub fn add_struct_description(
+        &mut self,
+        type_name: String,
+        fields: Vec<StructFieldDescription>,
+    ) {
+        self.type_descriptions
+            .0
+            .entry(type_name.clone())
+            .or_insert(TypeDescription {
+                docs: &[],
+                name: type_name,
+                contents: TypeContents::Struct(fields),
+                version: None,
+            });
+    }
+
+    fn specified_description(&self, type_name: &str) -> Option<&TypeDescription> {
+        self.type_descriptions
+            .0
+            .get(type_name)
+            .filter(|description| description.contents.is_specified())
+    }
+
+    /// Decodes a value as stored on its own, e.g. a storage value.
+    pub fn decode_top(&self, type_name: &str, bytes: &[u8]) -> Result<String, DecodeError> {
+        if let Some(description) = self.specified_description(type_name) {
+            if let TypeContents::Transparent(underlying_type) = &description.contents {
+                return self.decode_top(underlying_type.as_str(), bytes);
+            }
+            if bytes.is_empty() {
+                if let TypeContents::Enum(variants) = &description.contents {
+                    if let Some(variant) = variants
+                        .iter()
+                        .find(|variant| variant.discriminant == 0 && variant.fields.is_empty())
+                    {
+                        return Ok(format_enum_variant(description, variant, Vec::new()));
+                    }
+                }
+            }
+            return self.decode_all_nested(type_name, bytes);
+        }
+
+        let (base_name, type_args) = split_type_name(type_name);
+        match (base_name, type_args.as_slice()) {
+            ("u8", []) => decode_top_unsigned(bytes, 1),
+            ("u16", []) => decode_top_unsigned(bytes, 2),
+            ("u32" | "usize", []) => decode_top_unsigned(bytes, 4),
+            ("u64", []) => decode_top_unsigned(bytes, 8),
+            ("i8", []) => decode_top_signed(bytes, 1),
+            ("i16", []) => decode_top_signed(bytes, 2),
+            ("i32" | "isize", []) => decode_top_signed(bytes, 4),
+            ("i64", []) => decode_top_signed(bytes, 8),
+            ("bool", []) => match bytes {
+                [] => Ok("false".to_string()),
+                [1] => Ok("true".to_string()),
+                _ => Err(DecodeError::INVALID_VALUE),
+            },
+            ("BigUint", []) => Ok(BigUint::from_bytes_be(bytes).to_string()),
+            ("BigInt", []) => Ok(BigInt::from_signed_bytes_be(bytes).to_string()),
+            ("bytes" | "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier", []) => {
+                Ok(format_bytes(bytes))
+            },
+            ("List", [item_type]) => {
+                let mut input = bytes;
+                let mut items = Vec::new();
+                while !input.is_empty() {
+                    items.push(self.decode_nested(item_type, &mut input)?);
+                }
+                Ok(format!("[{}]", items.join(", ")))
+            },
+            ("Option", [item_type]) => match bytes.split_first() {
+                None => Ok("None".to_string()),
+                Some((1, item_bytes)) => Ok(format!(
+                    "Some({})",
+                    self.decode_all_nested(item_type, item_bytes)?
+                )),
+                Some(_) => Err(DecodeError::INVALID_VALUE),
+            },
+            _ => self.decode_all_nested(type_name, bytes),
+        }
+    }
+
+    fn decode_all_nested(&self, type_name: &str, bytes: &[u8]) -> Result<String, DecodeError> {
+        let mut input = bytes;
+        let result = self.decode_nested(type_name, &mut input)?;
+        if !input.is_empty() {
+            return Err(DecodeError::INPUT_TOO_LONG);
+        }
+        Ok(result)
+    }
+
+    /// Decodes a value from the beginning of the input, as found in lists, struct fields or storage keys.
+    /// The input is advanced past the decoded value.
+    pub fn decode_nested(&self, type_name: &str, input: &mut &[u8]) -> Result<String, DecodeError> {
+        if let Some(description) = self.specified_description(type_name) {
+            return self.decode_described_nested(description, input);
+        }
+
+        let (base_name, type_args) = split_type_name(type_name);
+        match (base_name, type_args.as_slice()) {
+            ("u8", []) => Ok(read_unsigned(input, 1)?.to_string()),
+            ("u16", []) => Ok(read_unsigned(input, 2)?.to_string()),
+            ("u32" | "usize", []) => Ok(read_unsigned(input, 4)?.to_string()),
+            ("u64", []) => Ok(read_unsigned(input, 8)?.to_string()),
+            ("i8", []) => Ok(read_signed(input, 1)?.to_string()),
+            ("i16", []) => Ok(read_signed(input, 2)?.to_string()),
+            ("i32" | "isize", []) => Ok(read_signed(input, 4)?.to_string()),
+            ("i64", []) => Ok(read_signed(input, 8)?.to_string()),
+            ("bool", []) => match read_unsigned(input, 1)? {
+                0 => Ok("false".to_string()),
+                1 => Ok("true".to_string()),
+                _ => Err(DecodeError::INVALID_VALUE),
+            },
+            ("BigUint", []) => Ok(BigUint::from_bytes_be(read_length_prefixed(input)?).to_string()),
+            ("BigInt", []) => {
+                Ok(BigInt::from_signed_bytes_be(read_length_prefixed(input)?).to_string())
+            },
+            ("bytes" | "utf-8 string" | "TokenIdentifier" | "EgldOrEsdtTokenIdentifier", []) => {
+                Ok(format_bytes(read_length_prefixed(input)?))
+            },
+            ("Address" | "H256", []) => Ok(verbose_hex(read_bytes(input, ADDRESS_LEN)?)),
+            ("CodeMetadata", []) => Ok(verbose_hex(read_bytes(input, CODE_METADATA_LEN)?)),
+            ("List", [item_type]) => {
+                let len = read_unsigned(input, 4)?;
+                let items = (0..len)
+                    .map(|_| self.decode_nested(item_type, input))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(format!("[{}]", items.join(", ")))
+            },
+            ("Option", [item_type]) => match read_unsigned(input, 1)? {
+                0 => Ok("None".to_string()),
+                1 => Ok(format!("Some({})", self.decode_nested(item_type, input)?)),
+                _ => Err(DecodeError::INVALID_VALUE),
+            },
+            ("tuple", item_types) if !item_types.is_empty() => {
+                let items = item_types
+                    .iter()
+                    .map(|item_type| self.decode_nested(item_type, input))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(format!("({})", items.join(", ")))
+            },
+            (array_name, [item_type]) if array_name.starts_with("array") => {
+                let len = array_name["array".len()..]
+                    .parse::<usize>()
+                    .map_err(|_| DecodeError::from(UNKNOWN_TYPE_ERR_MSG))?;
+                if *item_type == "u8" {
+                    return Ok(verbose_hex(read_bytes(input, len)?));
+                }
+                let items = (0..len)
+                    .map(|_| self.decode_nested(item_type, input))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(format!("[{}]", items.join(", ")))
+            },
+            _ => Err(DecodeError::from(UNKNOWN_TYPE_ERR_MSG)),
+        }
+    }
+
+    fn decode_described_nested(
+        &self,
+        description: &TypeDescription,
+        input: &mut &[u8],
+    ) -> Result<String, DecodeError> {
+        match &description.contents {
+            TypeContents::Struct(fields) => {
+                let fields = match description.version {
+                    Some(version) => versioned_struct_fields(fields, version, input)?,
+                    None => fields.clone(),
+                };
+                let field_values = self.decode_fields_nested(&fields, input)?;
+                Ok(format_fields(
+                    description.name.as_str(),
+                    &fields,
+                    field_values,
+                ))
+            },
+            TypeContents::Enum(variants) => {
+                let discriminant = read_unsigned(input, 1)? as usize;
+                let variant = variants
+                    .iter()
+                    .find(|variant| variant.discriminant == discriminant)
+                    .ok_or(DecodeError::INVALID_VALUE)?;
+                let field_values = self.decode_fields_nested(&variant.fields, input)?;
+                Ok(format_enum_variant(description, variant, field_values))
+            },
+            TypeContents::Transparent(underlying_type) => {
+                self.decode_nested(underlying_type.as_str(), input)
+            },
+            TypeContents::NotSpecified => Err(DecodeError::from(UNKNOWN_TYPE_ERR_MSG)),
+        }
+    }
+
+    fn decode_fields_nested(
+        &self,
+        fields: &[StructFieldDescription],
+        input: &mut &[u8],
+    ) -> Result<Vec<String>, DecodeError> {
+        fields
+            .iter()
+            .map(|field| self.decode_nested(field.field_type.as_str(), input))
+            .collect()
+    }
+}
+
+/// Reads the version byte of a versioned struct and keeps the fields serialized in that version.
+fn versioned_struct_fields(
+    fields: &[StructFieldDescription],
+    version: u8,
+    input: &mut &[u8],
+) -> Result<Vec<StructFieldDescription>, DecodeError> {
+    let encoded_version = read_unsigned(input, 1)? as u8;
+    if encoded_version > version {
+        return Err(DecodeError::UNSUPPORTED_VERSION);
+    }
+    Ok(fields
+        .iter()
+        .filter(|field| field.since.map_or(true, |since| since <= encoded_version))
+        .cloned()
+        .collect())
+}
+
+/// Splits `List<tuple<u32,bytes>>` into `List` and `[tuple<u32,bytes>]`.
+fn split_type_name(type_name: &str) -> (&str, Vec<&str>) {
+    let open = match type_name.find('<') {
+        Some(open) if type_name.ends_with('>') => open,
+        _ => return (type_name, Vec::new()),
+    };
+
+    let args_str = &type_name[open + 1..type_name.len() - 1];
+    let mut type_args = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in args_str.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                type_args.push(args_str[start..i].trim());
+                start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    type_args.push(args_str[start..].trim());
+    (&type_name[..open], type_args)
+}
+
+fn read_bytes<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], DecodeError> {
+    if input.len() < len {
+        return Err(DecodeError::INPUT_TOO_SHORT);
+    }
+    let (bytes, rest) = input.split_at(len);
+    *input = rest;
+    Ok(bytes)
+}
+
+fn read_length_prefixed<'a>(input: &mut &'a [u8]) -> Result<&'a [u8], DecodeError> {
+    let len = read_unsigned(input, 4)? as usize;
+    read_bytes(input, len)
+}
+
+fn read_unsigned(input: &mut &[u8], size: usize) -> Result<u64, DecodeError> {
+    Ok(bytes_to_u64(read_bytes(input, size)?))
+}
+
+fn read_signed(input: &mut &[u8], size: usize) -> Result<i64, DecodeError> {
+    Ok(bytes_to_i64(read_bytes(input, size)?))
+}
+
+fn decode_top_unsigned(bytes: &[u8], size: usize) -> Result<String, DecodeError> {
+    if bytes.len() > size {
+        return Err(DecodeError::INPUT_TOO_LONG);
+    }
+    Ok(bytes_to_u64(bytes).to_string())
+}
+
+fn decode_top_signed(bytes: &[u8], size: usize) -> Result<String, DecodeError> {
+    if bytes.len() > size {
+        return Err(DecodeError::INPUT_TOO_LONG);
+    }
+    Ok(bytes_to_i64(bytes).to_string())
+}
+
+fn bytes_to_u64(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(0u64, |result, byte| (result << 8) | *byte as u64)
+}
+
+/// Big endian, sign-extended from the first byte.
+fn bytes_to_i64(bytes: &[u8]) -> i64 {
+    let initial = match bytes.first() {
+        Some(first) if *first >= 0x80 => -1i64,
+        _ => 0i64,
+    };
+    bytes
+        .iter()
+        .fold(initial, |result, byte| (result << 8) | *byte as i64)
+}
+
+/// Printable ASCII is shown as a mandos `str:` expression, everything else as hex.
+pub(super) fn format_bytes(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        return "\"\"".to_string();
+    }
+    if bytes.iter().all(|byte| (0x20..0x7f).contains(byte)) {
+        return format!("str:{}", String::from_utf8_lossy(bytes));
+    }
+    verbose_hex(bytes)
+}
+
+fn format_fields(name: &str, fields: &[StructFieldDescription], values: Vec<String>) -> String {
+    if fields.is_empty() {
+        return name.to_string();
+    }
+
+    // tuple structs and tuple variants have numeric field names
+    if fields[0].name.parse::<usize>().is_ok() {
+        return format!("{name}({})", values.join(", "));
+    }
+
+    let named_values: Vec<String> = fields
+        .iter()
+        .zip(values)
+        .map(|(field, value)| format!("{}: {value}", field.name))
+        .collect();
+    format!("{name} {{ {} }}", named_values.join(", "))
+}
+
+fn format_enum_variant(
+    description: &TypeDescription,
+    variant: &EnumVariantDescription,
+    values: Vec<String>,
+) -> String {
+    format_fields(
+        format!("{}::{}", description.name, variant.name).as_str(),
+        &variant.fields,
+        values,
+    )
+}
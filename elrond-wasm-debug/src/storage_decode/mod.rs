@@ -0,0 +1,8 @@
+mod abi_value_decoder;
+mod storage_decoder;
+mod storage_dump;
+mod storage_mapper_layout;
+
+pub use abi_value_decoder::{AbiValueDecoder, UNKNOWN_TYPE_ERR_MSG};
+pub use storage_decoder::{DecodedStorageEntry, StorageDecoder};
+pub use storage_dump::{load_storage_dump, AccountStorageDump};
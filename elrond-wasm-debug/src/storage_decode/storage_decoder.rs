@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+
+use elrond_wasm::abi::{ContractAbi, StorageAbi};
+
+use crate::verbose_hex;
+
+use super::{
+    abi_value_decoder::format_bytes,
+    storage_mapper_layout::{add_mapper_type_descriptions, mapper_sub_keys},
+    AbiValueDecoder,
+};
+
+/// A storage entry, with the key expressed in terms of the storage mapper that owns it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedStorageEntry {
+    /// E.g. `balances(address: 0x...).mapped(str:TOKEN-123456)`.
+    pub key: String,
+    pub value: String,
+}
+
+/// Maps raw storage keys back to the storage entries declared in the contract ABI,
+/// then decodes the values based on their type.
+///
+/// Keys that cannot be attributed to any storage entry, as well as values that fail to decode, are shown in hex.
+pub struct StorageDecoder {
+    storage_abis: Vec<StorageAbi>,
+    value_decoder: AbiValueDecoder,
+}
+
+impl StorageDecoder {
+    pub fn new(abi: &ContractAbi) -> Self {
+        let mut value_decoder = AbiValueDecoder::new(abi.type_descriptions.clone());
+        for storage_abi in &abi.storage {
+            add_mapper_type_descriptions(
+                &mut value_decoder,
+                storage_abi.mapper,
+                &storage_abi.value_types,
+            );
+        }
+
+        // longer keys first, so that a key is never attributed to an entry whose key is only a prefix
+        let mut storage_abis = abi.storage.clone();
+        storage_abis.sort_by(|a, b| b.key.len().cmp(&a.key.len()));
+
+        StorageDecoder {
+            storage_abis,
+            value_decoder,
+        }
+    }
+
+    pub fn decode_storage(&self, storage: &BTreeMap<Vec<u8>, Vec<u8>>) -> Vec<DecodedStorageEntry> {
+        storage
+            .iter()
+            .map(|(key, value)| self.decode_entry(key.as_slice(), value.as_slice()))
+            .collect()
+    }
+
+    pub fn decode_entry(&self, key: &[u8], value: &[u8]) -> DecodedStorageEntry {
+        self.storage_abis
+            .iter()
+            .find_map(|storage_abi| self.try_decode_entry(storage_abi, key, value))
+            .unwrap_or_else(|| DecodedStorageEntry {
+                key: format_bytes(key),
+                value: verbose_hex(value),
+            })
+    }
+
+    fn try_decode_entry(
+        &self,
+        storage_abi: &StorageAbi,
+        key: &[u8],
+        value: &[u8],
+    ) -> Option<DecodedStorageEntry> {
+        let mut rest = key.strip_prefix(storage_abi.key.as_bytes())?;
+        let mut key_name = storage_abi.key.to_string();
+        if !storage_abi.key_args.is_empty() {
+            let mut key_arg_values = Vec::with_capacity(storage_abi.key_args.len());
+            for key_arg in &storage_abi.key_args {
+                let arg_value = self
+                    .value_decoder
+                    .decode_nested(key_arg.type_name.as_str(), &mut rest)
+                    .ok()?;
+                key_arg_values.push(format!("{}: {arg_value}", key_arg.arg_name));
+            }
+            key_name.push_str(format!("({})", key_arg_values.join(", ")).as_str());
+        }
+
+        let sub_keys = match mapper_sub_keys(storage_abi.mapper, &storage_abi.value_types) {
+            Some(sub_keys) => sub_keys,
+            None => {
+                // unknown layout, the entry is only attributed to the mapper
+                if !rest.is_empty() {
+                    key_name.push_str(format!(" + {}", verbose_hex(rest)).as_str());
+                }
+                return Some(DecodedStorageEntry {
+                    key: key_name,
+                    value: verbose_hex(value),
+                });
+            },
+        };
+
+        for sub_key in sub_keys {
+            let mut sub_key_rest = match rest.strip_prefix(sub_key.suffix.as_bytes()) {
+                Some(sub_key_rest) => sub_key_rest,
+                None => continue,
+            };
+            let suffix_arg = match &sub_key.suffix_arg_type {
+                Some(suffix_arg_type) => match self
+                    .value_decoder
+                    .decode_nested(suffix_arg_type.as_str(), &mut sub_key_rest)
+                {
+                    Ok(suffix_arg) => Some(suffix_arg),
+                    Err(_) => continue,
+                },
+                None => None,
+            };
+            if !sub_key_rest.is_empty() {
+                continue;
+            }
+
+            key_name.push_str(sub_key.suffix);
+            if let Some(suffix_arg) = suffix_arg {
+                key_name.push_str(format!("({suffix_arg})").as_str());
+            }
+            return Some(DecodedStorageEntry {
+                key: key_name,
+                value: self.decode_value(sub_key.value_type.as_str(), value),
+            });
+        }
+
+        None
+    }
+
+    fn decode_value(&self, type_name: &str, value: &[u8]) -> String {
+        match self.value_decoder.decode_top(type_name, value) {
+            Ok(decoded) => decoded,
+            Err(err) => format!(
+                "{} (could not decode as {type_name}: {})",
+                verbose_hex(value),
+                err.message_str()
+            ),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use crate::mandos_system::{
+    model::{CheckStorage, CheckValue, Step},
+    parse_scenario,
+};
+
+const STEPS_FIELD_NAME: &str = "steps";
+const DATA_FIELD_NAME: &str = "data";
+const PAIRS_FIELD_NAME: &str = "pairs";
+
+/// The raw storage of an account.
+pub struct AccountStorageDump {
+    /// The address, as written in the mandos scenario. Not known for plain key-value dumps.
+    pub account: Option<String>,
+    pub storage: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+/// Loads account storage from either:
+/// - a mandos scenario, in which case the storage of each account is the one from its last `setState` or `checkState` step;
+/// - a JSON object of hex-encoded key-value pairs, optionally nested under `pairs` or `data.pairs`,
+///   as returned by the proxy when querying all the keys of an account.
+pub fn load_storage_dump<P: AsRef<Path>>(path: P) -> Vec<AccountStorageDump> {
+    let contents = fs::read_to_string(path.as_ref())
+        .unwrap_or_else(|e| panic!("not found: {} {:?}", e, path.as_ref()));
+    let json: serde_json::Value = serde_json::from_str(contents.as_str())
+        .unwrap_or_else(|e| panic!("invalid JSON in {:?}: {e}", path.as_ref()));
+
+    if json.get(STEPS_FIELD_NAME).is_some() {
+        load_mandos_storage(path)
+    } else {
+        vec![AccountStorageDump {
+            account: None,
+            storage: parse_key_value_pairs(&json),
+        }]
+    }
+}
+
+fn load_mandos_storage<P: AsRef<Path>>(path: P) -> Vec<AccountStorageDump> {
+    let scenario = parse_scenario(path);
+    let mut accounts = BTreeMap::<String, BTreeMap<Vec<u8>, Vec<u8>>>::new();
+    for step in scenario.steps {
+        match step {
+            Step::SetState(set_state_step) => {
+                for (address, account) in set_state_step.accounts {
+                    let storage = accounts.entry(address.original).or_default();
+                    for (key, value) in account.storage {
+                        if value.value.is_empty() {
+                            storage.remove(&key.value);
+                        } else {
+                            storage.insert(key.value, value.value);
+                        }
+                    }
+                }
+            },
+            Step::CheckState(check_state_step) => {
+                for (address, check_account) in check_state_step.accounts.accounts {
+                    if let CheckStorage::Equal(check_storage) = check_account.storage {
+                        let storage = accounts.entry(address.original).or_default();
+                        if !check_storage.other_storages_allowed {
+                            storage.clear();
+                        }
+                        for (key, check_value) in check_storage.storages {
+                            if let CheckValue::Equal(value) = check_value {
+                                storage.insert(key.value, value.value);
+                            }
+                        }
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    accounts
+        .into_iter()
+        .filter(|(_, storage)| !storage.is_empty())
+        .map(|(account, storage)| AccountStorageDump {
+            account: Some(account),
+            storage,
+        })
+        .collect()
+}
+
+fn parse_key_value_pairs(json: &serde_json::Value) -> BTreeMap<Vec<u8>, Vec<u8>> {
+    let pairs = json
+        .get(DATA_FIELD_NAME)
+        .and_then(|data| data.get(PAIRS_FIELD_NAME))
+        .or_else(|| json.get(PAIRS_FIELD_NAME))
+        .unwrap_or(json)
+        .as_object()
+        .expect("storage dump must be a JSON object of hex-encoded key-value pairs");
+
+    pairs
+        .iter()
+        .map(|(key, value)| {
+            let value_str = value
+                .as_str()
+                .unwrap_or_else(|| panic!("storage value for key {key} is not a string"));
+            (decode_hex(key), decode_hex(value_str))
+        })
+        .collect()
+}
+
+fn decode_hex(hex_str: &str) -> Vec<u8> {
+    let hex_str = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+    hex::decode(hex_str).unwrap_or_else(|_| panic!("{hex_str} is not hex-encoded"))
+}
@@ -0,0 +1,199 @@
+use elrond_wasm::abi::{StructFieldDescription, TypeName};
+
+use super::AbiValueDecoder;
+
+pub(super) const LINKED_LIST_INFO_TYPE_NAME: &str = "LinkedListInfo";
+pub(super) const LINKED_LIST_NODE_TYPE_NAME: &str = "LinkedListNode";
+pub(super) const QUEUE_INFO_TYPE_NAME: &str = "QueueMapperInfo";
+pub(super) const QUEUE_NODE_TYPE_NAME: &str = "QueueMapperNode";
+pub(super) const ORDERED_MAP_INFO_TYPE_NAME: &str = "OrderedMapMapperInfo";
+pub(super) const ORDERED_MAP_NODE_TYPE_NAME: &str = "TreeNode";
+
+/// Bitmap words are 256 bits, stored as 4 little endian 64-bit limbs.
+const BITMAP_WORD_TYPE_NAME: &str = "array32<u8>";
+
+/// One of the storage entries of a mapper, whose key is the mapper key followed by a suffix,
+/// and optionally by a nested-encoded argument, e.g. the index in a `VecMapper`.
+pub(super) struct MapperSubKey {
+    pub suffix: &'static str,
+    pub suffix_arg_type: Option<TypeName>,
+    pub value_type: TypeName,
+}
+
+impl MapperSubKey {
+    fn new(suffix: &'static str, suffix_arg_type: Option<&str>, value_type: &str) -> Self {
+        MapperSubKey {
+            suffix,
+            suffix_arg_type: suffix_arg_type.map(TypeName::from),
+            value_type: TypeName::from(value_type),
+        }
+    }
+}
+
+fn vec_mapper_sub_keys(item_type: &str) -> Vec<MapperSubKey> {
+    vec![
+        MapperSubKey::new(".len", None, "u32"),
+        MapperSubKey::new(".item", Some("u32"), item_type),
+    ]
+}
+
+fn queue_mapper_sub_keys(item_type: &str) -> Vec<MapperSubKey> {
+    vec![
+        MapperSubKey::new(".info", None, QUEUE_INFO_TYPE_NAME),
+        MapperSubKey::new(".node_links", Some("u32"), QUEUE_NODE_TYPE_NAME),
+        MapperSubKey::new(".value", Some("u32"), item_type),
+    ]
+}
+
+fn set_mapper_sub_keys(item_type: &str) -> Vec<MapperSubKey> {
+    let mut sub_keys = queue_mapper_sub_keys(item_type);
+    sub_keys.push(MapperSubKey::new(".node_id", Some(item_type), "u32"));
+    sub_keys
+}
+
+pub(super) fn linked_list_node_type_name(item_type: &str) -> TypeName {
+    format!("{LINKED_LIST_NODE_TYPE_NAME}<{item_type}>")
+}
+
+/// The storage layout of the mappers that can be decoded, based on their type arguments.
+/// Returns `None` for mappers whose layout is not known.
+///
+/// Longer suffixes come first, so they get matched before their prefixes.
+pub(super) fn mapper_sub_keys(mapper: &str, value_types: &[TypeName]) -> Option<Vec<MapperSubKey>> {
+    let first_type = value_types.first().map(TypeName::as_str);
+    let second_type = value_types.get(1).map(TypeName::as_str);
+    let mut sub_keys = match (mapper, first_type, second_type) {
+        ("SingleValueMapper", Some(value_type), _) => vec![MapperSubKey::new("", None, value_type)],
+        ("VecMapper", Some(item_type), _) => vec_mapper_sub_keys(item_type),
+        ("UnorderedSetMapper", Some(item_type), _) => {
+            let mut sub_keys = vec_mapper_sub_keys(item_type);
+            sub_keys.push(MapperSubKey::new(".index", Some(item_type), "u32"));
+            sub_keys
+        },
+        ("QueueMapper", Some(item_type), _) => queue_mapper_sub_keys(item_type),
+        ("SetMapper", Some(item_type), _) => set_mapper_sub_keys(item_type),
+        ("MapMapper", Some(key_type), Some(value_type)) => {
+            let mut sub_keys = set_mapper_sub_keys(key_type);
+            sub_keys.push(MapperSubKey::new(".mapped", Some(key_type), value_type));
+            sub_keys
+        },
+        ("LinkedListMapper", Some(item_type), _) => vec![
+            MapperSubKey::new(".info", None, LINKED_LIST_INFO_TYPE_NAME),
+            MapperSubKey::new(
+                ".node",
+                Some("u32"),
+                linked_list_node_type_name(item_type).as_str(),
+            ),
+        ],
+        ("WhitelistMapper", Some(item_type), _) => {
+            vec![MapperSubKey::new("", Some(item_type), "bool")]
+        },
+        ("OrderedMapMapper", Some(key_type), Some(value_type)) => vec![
+            MapperSubKey::new(".info", None, ORDERED_MAP_INFO_TYPE_NAME),
+            MapperSubKey::new(".node_links", Some("u32"), ORDERED_MAP_NODE_TYPE_NAME),
+            MapperSubKey::new(".node_key", Some("u32"), key_type),
+            MapperSubKey::new(".node_value", Some("u32"), value_type),
+            MapperSubKey::new(".node_id", Some(key_type), "u32"),
+        ],
+        ("PriorityQueueMapper", Some(priority_type), Some(value_type)) => vec![
+            MapperSubKey::new(".len", None, "u32"),
+            MapperSubKey::new(".priority", Some("u32"), priority_type),
+            MapperSubKey::new(".value", Some("u32"), value_type),
+        ],
+        ("BitmapMapper", _, _) => vec![
+            MapperSubKey::new(".num_words", None, "u64"),
+            MapperSubKey::new(".word", Some("u64"), BITMAP_WORD_TYPE_NAME),
+        ],
+        ("CheckpointMapper", Some(value_type), _) => vec![
+            MapperSubKey::new(".len", None, "u32"),
+            MapperSubKey::new(".block_nonce", Some("u32"), "u64"),
+            MapperSubKey::new(".value", Some("u32"), value_type),
+        ],
+        ("UniqueIdMapper", _, _) => vec_mapper_sub_keys("u32"),
+        ("UserMapper", _, _) => vec![
+            MapperSubKey::new("_address_to_id", Some("Address"), "u32"),
+            MapperSubKey::new("_id_to_address", Some("u32"), "Address"),
+            MapperSubKey::new("_count", None, "u32"),
+        ],
+        _ => return None,
+    };
+    sub_keys.sort_by(|a, b| b.suffix.len().cmp(&a.suffix.len()));
+    Some(sub_keys)
+}
+
+fn u32_field(name: &'static str) -> StructFieldDescription {
+    StructFieldDescription {
+        docs: &[],
+        name,
+        field_type: TypeName::from("u32"),
+        since: None,
+    }
+}
+
+/// The mappers store some bookkeeping structures of their own, these are not part of the ABI.
+pub(super) fn add_mapper_type_descriptions(
+    value_decoder: &mut AbiValueDecoder,
+    mapper: &str,
+    value_types: &[TypeName],
+) {
+    match mapper {
+        "QueueMapper" | "SetMapper" | "MapMapper" => {
+            value_decoder.add_struct_description(
+                TypeName::from(QUEUE_INFO_TYPE_NAME),
+                vec![
+                    u32_field("len"),
+                    u32_field("front"),
+                    u32_field("back"),
+                    u32_field("new"),
+                ],
+            );
+            value_decoder.add_struct_description(
+                TypeName::from(QUEUE_NODE_TYPE_NAME),
+                vec![u32_field("previous"), u32_field("next")],
+            );
+        },
+        "OrderedMapMapper" => {
+            value_decoder.add_struct_description(
+                TypeName::from(ORDERED_MAP_INFO_TYPE_NAME),
+                vec![u32_field("len"), u32_field("root"), u32_field("new")],
+            );
+            value_decoder.add_struct_description(
+                TypeName::from(ORDERED_MAP_NODE_TYPE_NAME),
+                vec![
+                    u32_field("parent"),
+                    u32_field("left"),
+                    u32_field("right"),
+                    u32_field("height"),
+                ],
+            );
+        },
+        "LinkedListMapper" => {
+            value_decoder.add_struct_description(
+                TypeName::from(LINKED_LIST_INFO_TYPE_NAME),
+                vec![
+                    u32_field("len"),
+                    u32_field("front"),
+                    u32_field("back"),
+                    u32_field("new"),
+                ],
+            );
+            if let Some(item_type) = value_types.first() {
+                value_decoder.add_struct_description(
+                    linked_list_node_type_name(item_type),
+                    vec![
+                        StructFieldDescription {
+                            docs: &[],
+                            name: "value",
+                            field_type: item_type.clone(),
+                            since: None,
+                        },
+                        u32_field("node_id"),
+                        u32_field("next_id"),
+                        u32_field("prev_id"),
+                    ],
+                );
+            }
+        },
+        _ => {},
+    }
+}
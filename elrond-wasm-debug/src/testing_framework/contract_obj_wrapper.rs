@@ -59,6 +59,7 @@ pub struct BlockchainStateWrapper {
     address_to_code_path: HashMap<Address, Vec<u8>>,
     mandos_generator: MandosGenerator,
     workspace_path: PathBuf,
+    tx_trace_enabled: bool,
 }
 
 impl BlockchainStateWrapper {
@@ -73,9 +74,16 @@ impl BlockchainStateWrapper {
             address_to_code_path: HashMap::new(),
             mandos_generator: MandosGenerator::new(),
             workspace_path: current_dir,
+            tx_trace_enabled: false,
         }
     }
 
+    /// From now on, the results of all transactions will contain a trace
+    /// of the storage accesses, transfers and calls, as well as the changes to the accounts.
+    pub fn enable_tx_trace(&mut self) {
+        self.tx_trace_enabled = true;
+    }
+
     pub fn get_mut_state(&mut self) -> &mut Rc<BlockchainMock> {
         &mut self.rc_b_mock
     }
@@ -709,28 +717,27 @@ impl BlockchainStateWrapper {
         ContractObjBuilder: 'static + Copy + Fn() -> CB,
     {
         let sc_address = sc_wrapper.address_ref();
-        let tx_cache = TxCache::new(self.rc_b_mock.clone());
+        let mut tx_cache = TxCache::new(self.rc_b_mock.clone());
         let rust_zero = num_bigint::BigUint::zero();
 
+        let trace_before = if self.tx_trace_enabled {
+            Some((tx_cache.enable_trace(), self.rc_b_mock.accounts.clone()))
+        } else {
+            None
+        };
+
         if egld_payment > &rust_zero {
-            tx_cache.subtract_egld_balance(caller, egld_payment);
-            tx_cache.increase_egld_balance(sc_address, egld_payment);
+            tx_cache.transfer_egld_balance(caller, sc_address, egld_payment);
         }
 
         for esdt in &esdt_payments {
             if esdt.value > rust_zero {
-                let metadata = tx_cache.subtract_esdt_balance(
+                tx_cache.transfer_esdt_balance(
                     caller,
-                    &esdt.token_identifier,
-                    esdt.nonce,
-                    &esdt.value,
-                );
-                tx_cache.increase_esdt_balance(
                     sc_address,
                     &esdt.token_identifier,
                     esdt.nonce,
                     &esdt.value,
-                    metadata,
                 );
             }
         }
@@ -744,7 +751,7 @@ impl BlockchainStateWrapper {
 
         let api_after_exec = Rc::try_unwrap(TxContextStack::static_pop()).unwrap();
         let updates = api_after_exec.into_blockchain_updates();
-        let tx_result = match exec_result {
+        let mut tx_result = match exec_result {
             Ok(()) => TxResult::empty(),
             Err(panic_any) => interpret_panic_as_tx_result(panic_any),
         };
@@ -767,6 +774,12 @@ impl BlockchainStateWrapper {
             }
         }
 
+        if let Some((trace, accounts_before)) = trace_before {
+            let mut trace = trace.take();
+            trace.compute_state_diff(&accounts_before, &self.rc_b_mock.accounts);
+            tx_result.trace = Some(trace);
+        }
+
         tx_result
     }
 
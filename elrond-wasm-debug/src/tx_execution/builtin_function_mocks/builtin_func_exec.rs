@@ -17,12 +17,16 @@ use super::{
     upgrade_contract::execute_upgrade_contract,
 };
 
-use elrond_wasm::api::{
-    CHANGE_OWNER_BUILTIN_FUNC_NAME, CLAIM_DEVELOPER_REWARDS_FUNC_NAME, ESDT_LOCAL_BURN_FUNC_NAME,
-    ESDT_LOCAL_MINT_FUNC_NAME, ESDT_MULTI_TRANSFER_FUNC_NAME, ESDT_NFT_ADD_QUANTITY_FUNC_NAME,
-    ESDT_NFT_ADD_URI_FUNC_NAME, ESDT_NFT_BURN_FUNC_NAME, ESDT_NFT_CREATE_FUNC_NAME,
-    ESDT_NFT_TRANSFER_FUNC_NAME, ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME, ESDT_TRANSFER_FUNC_NAME,
-    SET_USERNAME_FUNC_NAME, UPGRADE_CONTRACT_FUNC_NAME,
+use elrond_wasm::{
+    api::{
+        CHANGE_OWNER_BUILTIN_FUNC_NAME, CLAIM_DEVELOPER_REWARDS_FUNC_NAME,
+        ESDT_LOCAL_BURN_FUNC_NAME, ESDT_LOCAL_MINT_FUNC_NAME, ESDT_MULTI_TRANSFER_FUNC_NAME,
+        ESDT_NFT_ADD_QUANTITY_FUNC_NAME, ESDT_NFT_ADD_URI_FUNC_NAME, ESDT_NFT_BURN_FUNC_NAME,
+        ESDT_NFT_CREATE_FUNC_NAME, ESDT_NFT_TRANSFER_FUNC_NAME,
+        ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME, ESDT_TRANSFER_FUNC_NAME, SET_USERNAME_FUNC_NAME,
+        UPGRADE_CONTRACT_FUNC_NAME,
+    },
+    err_msg,
 };
 
 const ESDT_ROLE_LOCAL_MINT: &[u8] = b"ESDTRoleLocalMint";
@@ -37,6 +41,12 @@ pub fn execute_builtin_function_or_default(
     tx_input: TxInput,
     tx_cache: TxCache,
 ) -> (TxResult, BlockchainUpdate) {
+    if tx_cache.is_readonly() && is_builtin_function(tx_input.func_name.as_slice()) {
+        let err_result =
+            TxResult::from_vm_error(err_msg::OPERATION_NOT_PERMITTED_READONLY.to_string());
+        return (err_result, BlockchainUpdate::empty());
+    }
+
     match tx_input.func_name.as_slice() {
         ESDT_LOCAL_MINT_FUNC_NAME => check_and_execute_builtin_function(
             ESDT_ROLE_LOCAL_MINT,
@@ -92,6 +102,28 @@ pub fn execute_builtin_function_or_default(
     }
 }
 
+/// All builtin functions change the state of accounts (transfers, minting, ownership, etc.),
+/// so none of them are allowed during read-only calls.
+fn is_builtin_function(func_name: &[u8]) -> bool {
+    matches!(
+        func_name,
+        ESDT_LOCAL_MINT_FUNC_NAME
+            | ESDT_LOCAL_BURN_FUNC_NAME
+            | ESDT_MULTI_TRANSFER_FUNC_NAME
+            | ESDT_NFT_TRANSFER_FUNC_NAME
+            | ESDT_NFT_CREATE_FUNC_NAME
+            | ESDT_NFT_ADD_QUANTITY_FUNC_NAME
+            | ESDT_NFT_BURN_FUNC_NAME
+            | ESDT_NFT_ADD_URI_FUNC_NAME
+            | ESDT_NFT_UPDATE_ATTRIBUTES_FUNC_NAME
+            | ESDT_TRANSFER_FUNC_NAME
+            | CHANGE_OWNER_BUILTIN_FUNC_NAME
+            | CLAIM_DEVELOPER_REWARDS_FUNC_NAME
+            | SET_USERNAME_FUNC_NAME
+            | UPGRADE_CONTRACT_FUNC_NAME
+    )
+}
+
 fn check_and_execute_builtin_function(
     role_name: &[u8],
     tx_input: TxInput,
@@ -35,6 +35,8 @@ pub fn execute_nft_burn(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, Bloc
         result_values: Vec::new(),
         result_logs: vec![esdt_nft_create_log],
         result_calls: TxResultCalls::empty(),
+        gas_used: 0,
+        trace: None,
     };
 
     (tx_result, tx_cache.into_blockchain_updates())
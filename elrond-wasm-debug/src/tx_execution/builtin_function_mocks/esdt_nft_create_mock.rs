@@ -70,6 +70,8 @@ pub fn execute_esdt_nft_create(
         result_values: vec![top_encode_to_vec_u8(&new_nonce).unwrap()],
         result_logs: vec![esdt_nft_create_log],
         result_calls: TxResultCalls::empty(),
+        gas_used: 0,
+        trace: None,
     };
 
     (tx_result, tx_cache.into_blockchain_updates())
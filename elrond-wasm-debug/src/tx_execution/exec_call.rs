@@ -6,8 +6,9 @@ use num_traits::Zero;
 
 use crate::{
     tx_mock::{
-        async_call_tx_input, async_callback_tx_input, async_promise_tx_input, merge_results,
-        AsyncCallTxData, Promise, TxCache, TxContext, TxInput, TxResult, TxResultCalls,
+        async_call_tx_input, async_callback_tx_input, async_promise_tx_input,
+        esdt_transfers_of_call, merge_results, AsyncCallTxData, CrossShardCall, CrossShardFunds,
+        Promise, TxCache, TxContext, TxInput, TxResult, TxResultCalls,
     },
     world_mock::{AccountData, AccountEsdt, BlockchainMock},
 };
@@ -39,43 +40,75 @@ pub fn execute_sc_call(tx_input: TxInput, mut state: BlockchainMock) -> (TxResul
 
 pub fn execute_async_call_and_callback(
     async_data: AsyncCallTxData,
-    state: BlockchainMock,
+    mut state: BlockchainMock,
 ) -> (TxResult, TxResult, BlockchainMock) {
+    if state.is_cross_shard(&async_data.from, &async_data.to) {
+        let funds = take_cross_shard_funds(&async_data.from, &async_data, &mut state);
+        state
+            .pending_cross_shard_calls
+            .push_back(CrossShardCall::AsyncCall { async_data, funds });
+        return (TxResult::empty(), TxResult::empty(), state);
+    }
+
     if state.accounts.contains_key(&async_data.to) {
         let async_input = async_call_tx_input(&async_data);
 
         let (async_result, state) = sc_call_with_async_and_callback(async_input, state);
 
-        let callback_input = async_callback_tx_input(&async_data, &async_result);
-        let (callback_result, state) = execute_sc_call(callback_input, state);
-        assert!(
-            callback_result.result_calls.async_call.is_none(),
-            "successive asyncs currently not supported"
-        );
+        let (callback_result, state) = execute_async_callback(&async_data, &async_result, state);
         (async_result, callback_result, state)
     } else {
-        let state_rc = Rc::new(state);
-        let tx_cache = TxCache::new(state_rc.clone());
-        tx_cache.subtract_egld_balance(&async_data.from, &async_data.call_value);
-        tx_cache.insert_account(AccountData {
-            address: async_data.to.clone(),
-            nonce: 0,
-            egld_balance: async_data.call_value,
-            esdt: AccountEsdt::default(),
-            username: Vec::new(),
-            storage: HashMap::new(),
-            contract_path: None,
-            contract_owner: None,
-            developer_rewards: BigUint::zero(),
-        });
-        let blockchain_updates = tx_cache.into_blockchain_updates();
-        let mut state = Rc::try_unwrap(state_rc).unwrap();
-        state.commit_updates(blockchain_updates);
-
+        let state = transfer_to_new_account(
+            &async_data.from,
+            &async_data.to,
+            &async_data.call_value,
+            state,
+        );
         (TxResult::empty(), TxResult::empty(), state)
     }
 }
 
+fn execute_async_callback(
+    async_data: &AsyncCallTxData,
+    async_result: &TxResult,
+    state: BlockchainMock,
+) -> (TxResult, BlockchainMock) {
+    let callback_input = async_callback_tx_input(async_data, async_result);
+    let (callback_result, state) = execute_sc_call(callback_input, state);
+    assert!(
+        callback_result.result_calls.async_call.is_none(),
+        "successive asyncs currently not supported"
+    );
+    (callback_result, state)
+}
+
+/// Async calls to accounts that do not exist (yet) simply create them.
+fn transfer_to_new_account(
+    from: &Address,
+    to: &Address,
+    call_value: &BigUint,
+    state: BlockchainMock,
+) -> BlockchainMock {
+    let state_rc = Rc::new(state);
+    let tx_cache = TxCache::new(state_rc.clone());
+    tx_cache.subtract_egld_balance(from, call_value);
+    tx_cache.insert_account(AccountData {
+        address: to.clone(),
+        nonce: 0,
+        egld_balance: call_value.clone(),
+        esdt: AccountEsdt::default(),
+        username: Vec::new(),
+        storage: HashMap::new(),
+        contract_path: None,
+        contract_owner: None,
+        developer_rewards: BigUint::zero(),
+    });
+    let blockchain_updates = tx_cache.into_blockchain_updates();
+    let mut state = Rc::try_unwrap(state_rc).unwrap();
+    state.commit_updates(blockchain_updates);
+    state
+}
+
 // TODO: refactor
 pub fn sc_call_with_async_and_callback(
     tx_input: TxInput,
@@ -112,38 +145,158 @@ pub fn sc_call_with_async_and_callback(
 pub fn execute_promise_call_and_callback(
     address: &Address,
     promise: &Promise,
-    state: BlockchainMock,
+    mut state: BlockchainMock,
 ) -> (TxResult, TxResult, BlockchainMock) {
+    if state.is_cross_shard(address, &promise.endpoint.to) {
+        let funds = take_cross_shard_funds(address, &promise.endpoint, &mut state);
+        state
+            .pending_cross_shard_calls
+            .push_back(CrossShardCall::PromiseCall {
+                caller: address.clone(),
+                promise: promise.clone(),
+                funds,
+            });
+        return (TxResult::empty(), TxResult::empty(), state);
+    }
+
     if state.accounts.contains_key(&promise.endpoint.to) {
         let async_input = async_call_tx_input(&promise.endpoint);
         let (async_result, state) = sc_call_with_async_and_callback(async_input, state);
 
-        let callback_input = async_promise_tx_input(address, promise, &async_result);
-        let (callback_result, state) = execute_sc_call(callback_input, state);
-        assert!(
-            callback_result.result_calls.promises.is_empty(),
-            "successive promises currently not supported"
-        );
+        let (callback_result, state) =
+            execute_promise_callback(address, promise, &async_result, state);
         (async_result, callback_result, state)
     } else {
-        let state_rc = Rc::new(state);
-        let tx_cache = TxCache::new(state_rc.clone());
-        tx_cache.subtract_egld_balance(address, &promise.endpoint.call_value);
-        tx_cache.insert_account(AccountData {
-            address: promise.endpoint.to.clone(),
-            nonce: 0,
-            egld_balance: promise.endpoint.call_value.clone(),
-            esdt: AccountEsdt::default(),
-            username: Vec::new(),
-            storage: HashMap::new(),
-            contract_path: None,
-            contract_owner: None,
-            developer_rewards: BigUint::zero(),
-        });
-        let blockchain_updates = tx_cache.into_blockchain_updates();
-        let mut state = Rc::try_unwrap(state_rc).unwrap();
-        state.commit_updates(blockchain_updates);
-
+        let state = transfer_to_new_account(
+            address,
+            &promise.endpoint.to,
+            &promise.endpoint.call_value,
+            state,
+        );
         (TxResult::empty(), TxResult::empty(), state)
     }
 }
+
+fn execute_promise_callback(
+    address: &Address,
+    promise: &Promise,
+    async_result: &TxResult,
+    state: BlockchainMock,
+) -> (TxResult, BlockchainMock) {
+    let callback_input = async_promise_tx_input(address, promise, async_result);
+    let (callback_result, state) = execute_sc_call(callback_input, state);
+    assert!(
+        callback_result.result_calls.promises.is_empty(),
+        "successive promises currently not supported"
+    );
+    (callback_result, state)
+}
+
+/// The EGLD and ESDT sent with a cross-shard call leave the caller as soon as the call is queued.
+fn take_cross_shard_funds(
+    caller: &Address,
+    call: &AsyncCallTxData,
+    state: &mut BlockchainMock,
+) -> CrossShardFunds {
+    let esdt_values = esdt_transfers_of_call(&call.endpoint_name, &call.arguments);
+    state.take_cross_shard_funds(caller, &call.call_value, esdt_values)
+}
+
+/// Executes a cross-shard call on the destination shard.
+///
+/// The funds in transit are handed back to the caller, for the call to transfer them as usual.
+/// If the call fails, they are taken from the caller once more, since they only return with the callback.
+///
+/// Returns the funds to be refunded by the callback, or `None` if there is no callback to schedule.
+fn deliver_cross_shard_call(
+    caller: &Address,
+    call: &AsyncCallTxData,
+    funds: CrossShardFunds,
+    mut state: BlockchainMock,
+) -> (TxResult, Option<CrossShardFunds>, BlockchainMock) {
+    state.put_cross_shard_funds(caller, &funds);
+
+    if !state.accounts.contains_key(&call.to) {
+        let state = transfer_to_new_account(caller, &call.to, &call.call_value, state);
+        return (TxResult::empty(), None, state);
+    }
+
+    let async_input = async_call_tx_input(call);
+    let (async_result, mut state) = sc_call_with_async_and_callback(async_input, state);
+    let refund = if async_result.result_status == 0 {
+        CrossShardFunds::default()
+    } else {
+        take_cross_shard_funds(caller, call, &mut state)
+    };
+    (async_result, Some(refund), state)
+}
+
+/// Delivers the oldest cross-shard call waiting in the blockchain mock.
+///
+/// The call is executed on the destination shard, then its callback is queued,
+/// since it also needs to cross back to the original shard.
+pub fn execute_next_cross_shard_call(
+    mut state: BlockchainMock,
+) -> (Option<TxResult>, BlockchainMock) {
+    let cross_shard_call =
+        if let Some(cross_shard_call) = state.pending_cross_shard_calls.pop_front() {
+            cross_shard_call
+        } else {
+            return (None, state);
+        };
+
+    let (tx_result, state) = match cross_shard_call {
+        CrossShardCall::AsyncCall { async_data, funds } => {
+            let (async_result, refund, mut state) =
+                deliver_cross_shard_call(&async_data.from, &async_data, funds, state);
+            if let Some(refund) = refund {
+                state
+                    .pending_cross_shard_calls
+                    .push_back(CrossShardCall::AsyncCallback {
+                        async_data,
+                        async_result: async_result.clone(),
+                        refund,
+                    });
+            }
+            (async_result, state)
+        },
+        CrossShardCall::AsyncCallback {
+            async_data,
+            async_result,
+            refund,
+        } => {
+            state.put_cross_shard_funds(&async_data.from, &refund);
+            execute_async_callback(&async_data, &async_result, state)
+        },
+        CrossShardCall::PromiseCall {
+            caller,
+            promise,
+            funds,
+        } => {
+            let (async_result, refund, mut state) =
+                deliver_cross_shard_call(&caller, &promise.endpoint, funds, state);
+            if let Some(refund) = refund {
+                state
+                    .pending_cross_shard_calls
+                    .push_back(CrossShardCall::PromiseCallback {
+                        caller,
+                        promise,
+                        async_result: async_result.clone(),
+                        refund,
+                    });
+            }
+            (async_result, state)
+        },
+        CrossShardCall::PromiseCallback {
+            caller,
+            promise,
+            async_result,
+            refund,
+        } => {
+            state.put_cross_shard_funds(&caller, &refund);
+            execute_promise_callback(&caller, &promise, &async_result, state)
+        },
+    };
+
+    (Some(tx_result), state)
+}
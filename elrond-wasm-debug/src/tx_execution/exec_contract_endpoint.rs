@@ -13,8 +13,20 @@ use crate::{
 /// The endpoint name is taken from the tx context.
 /// Catches and wraps any panics thrown in the contract.
 pub fn execute_tx_context(tx_context: TxContext) -> (TxContext, TxResult) {
+    let contract_identifier = get_contract_identifier(&tx_context);
+    execute_tx_context_with_code(tx_context, contract_identifier)
+}
+
+/// Runs the given contract code in the tx context,
+/// regardless of the code deployed at the recipient address.
+///
+/// Used for executing on the same context, where the callee code runs on the caller's account.
+pub fn execute_tx_context_with_code(
+    tx_context: TxContext,
+    contract_identifier: Vec<u8>,
+) -> (TxContext, TxResult) {
     let tx_context_rc = Rc::new(tx_context);
-    let (tx_context_rc, tx_result) = execute_tx_context_rc(tx_context_rc);
+    let (tx_context_rc, tx_result) = execute_tx_context_rc(tx_context_rc, contract_identifier);
     let tx_context = Rc::try_unwrap(tx_context_rc).unwrap();
     (tx_context, tx_result)
 }
@@ -22,11 +34,13 @@ pub fn execute_tx_context(tx_context: TxContext) -> (TxContext, TxResult) {
 /// The actual core of the execution.
 /// The argument is returned and can be unwrapped,
 /// since the lifetimes of all other references created from it cannot outlive this function.
-fn execute_tx_context_rc(tx_context_rc: Rc<TxContext>) -> (Rc<TxContext>, TxResult) {
+fn execute_tx_context_rc(
+    tx_context_rc: Rc<TxContext>,
+    contract_identifier: Vec<u8>,
+) -> (Rc<TxContext>, TxResult) {
     let tx_context_ref = DebugApi::new(tx_context_rc.clone());
 
     let func_name = tx_context_ref.tx_input_box.func_name.as_slice();
-    let contract_identifier = get_contract_identifier(&tx_context_ref);
     let contract_map = &tx_context_rc.blockchain_ref().contract_map;
 
     // Not redundant at all, func_name is borrowed from it...
@@ -34,10 +48,17 @@ fn execute_tx_context_rc(tx_context_rc: Rc<TxContext>) -> (Rc<TxContext>, TxResu
     let contract_container =
         contract_map.get_contract(contract_identifier.as_slice(), tx_context_ref.clone());
 
+    let coverage = &tx_context_rc.blockchain_ref().coverage;
+    coverage
+        .borrow_mut()
+        .enter_contract(contract_identifier.as_slice(), func_name);
+
     TxContextStack::static_push(tx_context_rc.clone());
-    let tx_result = execute_contract_instance_endpoint(contract_container, func_name);
+    let mut tx_result = execute_contract_instance_endpoint(contract_container, func_name);
 
+    coverage.borrow_mut().exit_contract();
     let tx_context_rc = TxContextStack::static_pop();
+    tx_result.gas_used = tx_context_rc.gas_used();
     (tx_context_rc, tx_result)
 }
 
@@ -2,20 +2,20 @@ use elrond_wasm::types::heap::Address;
 use num_traits::Zero;
 
 use crate::{
-    tx_mock::{BlockchainUpdate, TxCache, TxContext, TxInput, TxLog, TxResult},
+    tx_mock::{
+        BlockchainUpdate, TxCache, TxContext, TxInput, TxLog, TxResult, TxTraceCallType,
+        TxTraceEntry,
+    },
     world_mock::is_smart_contract_address,
 };
 
-use super::execute_tx_context;
+use super::{execute_tx_context, execute_tx_context_with_code};
 
 pub fn default_execution(tx_input: TxInput, tx_cache: TxCache) -> (TxResult, BlockchainUpdate) {
     let mut tx_context = TxContext::new(tx_input, tx_cache);
 
-    tx_context.tx_cache.subtract_egld_balance(
+    tx_context.tx_cache.transfer_egld_balance(
         &tx_context.tx_input_box.from,
-        &tx_context.tx_input_box.egld_value,
-    );
-    tx_context.tx_cache.increase_egld_balance(
         &tx_context.tx_input_box.to,
         &tx_context.tx_input_box.egld_value,
     );
@@ -78,19 +78,48 @@ pub fn deploy_contract(
     let new_address = tx_cache.get_new_address(&tx_input.from);
     tx_input.to = new_address.clone();
     tx_input.func_name = b"init".to_vec();
+    tx_cache.record_trace(|| TxTraceEntry::Call {
+        call_type: TxTraceCallType::Deploy,
+        from: tx_input.from.clone(),
+        to: new_address.clone(),
+        endpoint: tx_input.func_name.clone(),
+        args: tx_input.args.clone(),
+    });
     let tx_context = TxContext::new(tx_input, tx_cache);
     let tx_input_ref = &*tx_context.tx_input_box;
 
-    tx_context
-        .tx_cache
-        .subtract_egld_balance(&tx_input_ref.from, &tx_input_ref.egld_value);
     tx_context.create_new_contract(&new_address, contract_path, tx_input_ref.from.clone());
-    tx_context
-        .tx_cache
-        .increase_egld_balance(&new_address, &tx_input_ref.egld_value);
+    tx_context.tx_cache.transfer_egld_balance(
+        &tx_input_ref.from,
+        &new_address,
+        &tx_input_ref.egld_value,
+    );
 
     let (tx_context, tx_result) = execute_tx_context(tx_context);
     let blockchain_updates = tx_context.into_blockchain_updates();
 
     (tx_result, new_address, blockchain_updates)
 }
+
+/// Runs the code of another contract on the current account,
+/// so the callee reads and writes the caller's storage and balance.
+pub fn execute_on_same_context(
+    tx_input: TxInput,
+    contract_path: Vec<u8>,
+    tx_cache: TxCache,
+) -> (TxResult, BlockchainUpdate) {
+    let tx_context = TxContext::new(tx_input, tx_cache);
+    let tx_input_ref = &*tx_context.tx_input_box;
+
+    // the funds stay on the same account, but the balance is still checked
+    tx_context.tx_cache.transfer_egld_balance(
+        &tx_input_ref.from,
+        &tx_input_ref.to,
+        &tx_input_ref.egld_value,
+    );
+
+    let (tx_context, tx_result) = execute_tx_context_with_code(tx_context, contract_path);
+    let blockchain_updates = tx_context.into_blockchain_updates();
+
+    (tx_result, blockchain_updates)
+}
@@ -9,6 +9,7 @@ mod tx_callback_selector;
 mod tx_context;
 mod tx_context_ref;
 mod tx_context_stack;
+mod tx_cross_shard_call;
 mod tx_input;
 mod tx_input_util;
 mod tx_log;
@@ -16,6 +17,7 @@ mod tx_managed_types;
 mod tx_panic;
 mod tx_result;
 mod tx_result_calls;
+mod tx_trace;
 
 pub use blockchain_rng::*;
 pub use tx_async_call_data::*;
@@ -25,6 +27,7 @@ pub use tx_callback_selector::*;
 pub use tx_context::*;
 pub use tx_context_ref::*;
 pub use tx_context_stack::*;
+pub use tx_cross_shard_call::*;
 pub use tx_input::*;
 pub use tx_input_util::*;
 pub use tx_log::*;
@@ -32,3 +35,4 @@ pub use tx_managed_types::*;
 pub use tx_panic::*;
 pub use tx_result::*;
 pub use tx_result_calls::*;
+pub use tx_trace::*;
@@ -18,6 +18,7 @@ pub struct AsyncCallTxData {
     pub endpoint_name: Vec<u8>,
     pub arguments: Vec<Vec<u8>>,
     pub tx_hash: H256,
+    pub gas_limit: u64,
 }
 
 pub fn async_call_tx_input(async_data: &AsyncCallTxData) -> TxInput {
@@ -28,7 +29,7 @@ pub fn async_call_tx_input(async_data: &AsyncCallTxData) -> TxInput {
         esdt_values: Vec::new(),
         func_name: async_data.endpoint_name.clone(),
         args: async_data.arguments.clone(),
-        gas_limit: 1000,
+        gas_limit: async_data.gas_limit,
         gas_price: 0,
         tx_hash: async_data.tx_hash.clone(),
         promise_callback_closure_data: Vec::new(),
@@ -57,7 +58,7 @@ pub fn async_callback_tx_input(async_data: &AsyncCallTxData, async_result: &TxRe
         esdt_values: Vec::new(),
         func_name: b"callBack".to_vec(),
         args,
-        gas_limit: 1000,
+        gas_limit: async_data.gas_limit.saturating_sub(async_result.gas_used),
         gas_price: 0,
         tx_hash: async_data.tx_hash.clone(),
         promise_callback_closure_data: Vec::new(),
@@ -87,7 +88,11 @@ pub fn async_promise_tx_input(
         esdt_values: Vec::new(),
         func_name: callback,
         args,
-        gas_limit: 1000,
+        gas_limit: promise
+            .endpoint
+            .gas_limit
+            .saturating_sub(async_result.gas_used)
+            + promise.extra_gas_for_callback,
         gas_price: 0,
         tx_hash: promise.endpoint.tx_hash.clone(),
         promise_callback_closure_data: promise.callback_closure_data.clone(),
@@ -99,8 +104,10 @@ pub fn merge_results(mut original: TxResult, mut new: TxResult) -> TxResult {
         original.result_values.append(&mut new.result_values);
         original.result_logs.append(&mut new.result_logs);
         original.result_message = new.result_message;
+        original.gas_used += new.gas_used;
         original
     } else {
+        new.gas_used += original.gas_used;
         new
     }
 }
@@ -12,16 +12,19 @@ use crate::{
     world_mock::{AccountData, BlockchainMock},
 };
 
-use super::TxCacheSource;
+use super::{TxCacheSource, TxTraceEntry, TxTraceRef};
 
 pub struct TxCache {
     source_ref: Rc<dyn TxCacheSource>,
+    readonly: bool,
+    trace: Option<TxTraceRef>,
     pub(super) accounts: RefCell<HashMap<Address, AccountData>>,
 }
 
 impl fmt::Debug for TxCache {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("TxCache")
+            .field("readonly", &self.readonly)
             .field("accounts", &self.accounts)
             .finish()
     }
@@ -29,12 +32,54 @@ impl fmt::Debug for TxCache {
 
 impl TxCache {
     pub fn new(source_ref: Rc<dyn TxCacheSource>) -> Self {
+        let readonly = source_ref.is_readonly();
+        let trace = source_ref.trace_ref();
         TxCache {
             source_ref,
+            readonly,
+            trace,
             accounts: RefCell::new(HashMap::new()),
         }
     }
 
+    /// Used for read-only calls. The contract code is not allowed to write to storage or transfer funds,
+    /// and any nested calls are also read-only.
+    pub fn new_readonly(source_ref: Rc<dyn TxCacheSource>) -> Self {
+        let trace = source_ref.trace_ref();
+        TxCache {
+            source_ref,
+            readonly: true,
+            trace,
+            accounts: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn is_readonly(&self) -> bool {
+        self.readonly
+    }
+
+    /// Opt-in: records storage accesses, transfers and nested calls,
+    /// including those performed by caches created on top of this one.
+    pub fn enable_trace(&mut self) -> TxTraceRef {
+        let trace = TxTraceRef::default();
+        self.trace = Some(trace.clone());
+        trace
+    }
+
+    pub fn trace_ref(&self) -> Option<TxTraceRef> {
+        self.trace.clone()
+    }
+
+    /// The entry is only built if tracing is enabled.
+    pub fn record_trace<F>(&self, entry: F)
+    where
+        F: FnOnce() -> TxTraceEntry,
+    {
+        if let Some(trace) = &self.trace {
+            trace.borrow_mut().entries.push(entry());
+        }
+    }
+
     pub fn blockchain_ref(&self) -> &BlockchainMock {
         self.source_ref.blockchain_ref()
     }
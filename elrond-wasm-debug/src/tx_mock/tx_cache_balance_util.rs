@@ -1,9 +1,10 @@
 use crate::num_bigint::BigUint;
 use elrond_wasm::types::heap::Address;
+use num_traits::Zero;
 
 use crate::{tx_mock::TxPanic, world_mock::EsdtInstanceMetadata};
 
-use super::TxCache;
+use super::{TxCache, TxTraceEntry};
 
 impl TxCache {
     pub fn subtract_egld_balance(&self, address: &Address, call_value: &BigUint) {
@@ -35,6 +36,22 @@ impl TxCache {
         });
     }
 
+    /// Moves EGLD between accounts, the transfer shows up in the trace, if enabled.
+    pub fn transfer_egld_balance(&self, from: &Address, to: &Address, value: &BigUint) {
+        self.subtract_egld_balance(from, value);
+        self.increase_egld_balance(to, value);
+
+        if !value.is_zero() {
+            self.record_trace(|| TxTraceEntry::Transfer {
+                from: from.clone(),
+                to: to.clone(),
+                token_identifier: Vec::new(),
+                nonce: 0,
+                amount: value.clone(),
+            });
+        }
+    }
+
     #[allow(clippy::redundant_closure)] // clippy is wrong here, `.unwrap_or_else(panic_insufficient_funds)` won't compile
     pub fn subtract_esdt_balance(
         &self,
@@ -93,6 +110,14 @@ impl TxCache {
         let metadata = self.subtract_esdt_balance(from, esdt_token_identifier, nonce, value);
 
         self.increase_esdt_balance(to, esdt_token_identifier, nonce, value, metadata);
+
+        self.record_trace(|| TxTraceEntry::Transfer {
+            from: from.clone(),
+            to: to.clone(),
+            token_identifier: esdt_token_identifier.to_vec(),
+            nonce,
+            amount: value.clone(),
+        });
     }
 }
 
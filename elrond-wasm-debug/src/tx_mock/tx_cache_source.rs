@@ -2,12 +2,18 @@ use elrond_wasm::types::heap::Address;
 
 use crate::world_mock::{AccountData, BlockchainMock};
 
-use super::TxCache;
+use super::{TxCache, TxTraceRef};
 
 pub trait TxCacheSource {
     fn load_account(&self, address: &Address) -> Option<AccountData>;
 
     fn blockchain_ref(&self) -> &BlockchainMock;
+
+    /// Caches created on top of a read-only source are also read-only.
+    fn is_readonly(&self) -> bool;
+
+    /// Caches created on top of a traced source also record into the same trace.
+    fn trace_ref(&self) -> Option<TxTraceRef>;
 }
 
 impl TxCacheSource for TxCache {
@@ -18,6 +24,14 @@ impl TxCacheSource for TxCache {
     fn blockchain_ref(&self) -> &BlockchainMock {
         self.blockchain_ref()
     }
+
+    fn is_readonly(&self) -> bool {
+        self.is_readonly()
+    }
+
+    fn trace_ref(&self) -> Option<TxTraceRef> {
+        self.trace_ref()
+    }
 }
 
 impl TxCacheSource for BlockchainMock {
@@ -28,4 +42,12 @@ impl TxCacheSource for BlockchainMock {
     fn blockchain_ref(&self) -> &BlockchainMock {
         self
     }
+
+    fn is_readonly(&self) -> bool {
+        false
+    }
+
+    fn trace_ref(&self) -> Option<TxTraceRef> {
+        None
+    }
 }
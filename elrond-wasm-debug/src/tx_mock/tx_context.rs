@@ -1,10 +1,13 @@
 use crate::{
     num_bigint::BigUint,
-    world_mock::{AccountData, AccountEsdt, BlockchainMock},
+    world_mock::{AccountData, AccountEsdt, BlockchainMock, GasSchedule},
 };
 use alloc::vec::Vec;
-use core::cell::RefCell;
-use elrond_wasm::types::{heap::Address, LockableStaticBuffer};
+use core::cell::{Cell, RefCell};
+use elrond_wasm::{
+    err_msg,
+    types::{heap::Address, LockableStaticBuffer},
+};
 use num_traits::Zero;
 use std::{
     cell::{Ref, RefMut},
@@ -13,7 +16,8 @@ use std::{
 };
 
 use super::{
-    BlockchainRng, BlockchainUpdate, TxCache, TxInput, TxManagedTypes, TxResult, TxStaticVars,
+    BlockchainRng, BlockchainUpdate, TxCache, TxInput, TxManagedTypes, TxPanic, TxResult,
+    TxStaticVars,
 };
 
 #[derive(Debug)]
@@ -26,6 +30,7 @@ pub struct TxContext {
     pub tx_result_cell: RefCell<TxResult>,
     pub b_rng: RefCell<BlockchainRng>,
     pub printed_messages: RefCell<Vec<String>>,
+    pub gas_used: Cell<u64>,
 }
 
 impl TxContext {
@@ -40,6 +45,7 @@ impl TxContext {
             tx_result_cell: RefCell::new(TxResult::empty()),
             b_rng,
             printed_messages: RefCell::new(Vec::new()),
+            gas_used: Cell::new(0),
         }
     }
 
@@ -81,6 +87,7 @@ impl TxContext {
             tx_result_cell: RefCell::new(TxResult::empty()),
             b_rng,
             printed_messages: RefCell::new(Vec::new()),
+            gas_used: Cell::new(0),
         }
     }
 
@@ -144,6 +151,39 @@ impl TxContext {
         self.tx_result_cell.replace(TxResult::empty())
     }
 
+    pub fn gas_used(&self) -> u64 {
+        self.gas_used.get()
+    }
+
+    pub fn gas_left(&self) -> u64 {
+        self.tx_input_box.gas_limit.saturating_sub(self.gas_used())
+    }
+
+    /// Consumes gas, kills the execution if the gas limit is exceeded.
+    /// In that case, all the gas is considered used.
+    pub fn use_gas(&self, gas: u64) {
+        let gas_limit = self.tx_input_box.gas_limit;
+        let gas_used = self.gas_used().saturating_add(gas);
+        if gas_used > gas_limit {
+            self.gas_used.set(gas_limit);
+            std::panic::panic_any(TxPanic {
+                status: 5,
+                message: err_msg::NOT_ENOUGH_GAS.to_string(),
+            });
+        }
+        self.gas_used.set(gas_used);
+    }
+
+    /// Consumes the gas given by the gas schedule. Does nothing if gas metering is not enabled.
+    pub fn use_gas_from_schedule<F>(&self, cost: F)
+    where
+        F: FnOnce(&GasSchedule) -> u64,
+    {
+        if let Some(gas_schedule) = &self.blockchain_ref().gas_schedule {
+            self.use_gas(cost(gas_schedule));
+        }
+    }
+
     pub fn rng_borrow_mut(&self) -> RefMut<BlockchainRng> {
         self.b_rng.borrow_mut()
     }
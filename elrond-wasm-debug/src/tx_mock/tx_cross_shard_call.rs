@@ -0,0 +1,92 @@
+use elrond_wasm::{
+    api::{ESDT_MULTI_TRANSFER_FUNC_NAME, ESDT_NFT_TRANSFER_FUNC_NAME, ESDT_TRANSFER_FUNC_NAME},
+    elrond_codec::TopDecode,
+    types::heap::Address,
+};
+use num_traits::Zero;
+
+use crate::{num_bigint::BigUint, world_mock::EsdtInstanceMetadata};
+
+use super::{AsyncCallTxData, Promise, TxInputESDT, TxResult};
+
+/// A call that crosses shards, waiting in the blockchain mock to be delivered in a later step.
+///
+/// The funds travel with the call: they leave the caller when the call is queued,
+/// and reach the recipient when it gets delivered.
+/// If the call fails, they travel back with the callback, as a refund.
+#[derive(Clone, Debug)]
+pub enum CrossShardCall {
+    AsyncCall {
+        async_data: AsyncCallTxData,
+        funds: CrossShardFunds,
+    },
+    AsyncCallback {
+        async_data: AsyncCallTxData,
+        async_result: TxResult,
+        refund: CrossShardFunds,
+    },
+    PromiseCall {
+        caller: Address,
+        promise: Promise,
+        funds: CrossShardFunds,
+    },
+    PromiseCallback {
+        caller: Address,
+        promise: Promise,
+        async_result: TxResult,
+        refund: CrossShardFunds,
+    },
+}
+
+/// EGLD and ESDT in transit between shards.
+#[derive(Clone, Debug, Default)]
+pub struct CrossShardFunds {
+    pub egld_value: BigUint,
+    pub esdt_values: Vec<CrossShardEsdt>,
+}
+
+/// The token metadata is kept, so that NFTs can be restored as they were.
+#[derive(Clone, Debug)]
+pub struct CrossShardEsdt {
+    pub esdt: TxInputESDT,
+    pub metadata: EsdtInstanceMetadata,
+}
+
+impl CrossShardFunds {
+    pub fn is_empty(&self) -> bool {
+        self.egld_value.is_zero() && self.esdt_values.is_empty()
+    }
+}
+
+/// The ESDT sent by a call, when the call is an ESDT transfer builtin function.
+///
+/// Malformed transfers yield no tokens here, the builtin function reports the error upon delivery.
+pub fn esdt_transfers_of_call(func_name: &[u8], args: &[Vec<u8>]) -> Vec<TxInputESDT> {
+    match func_name {
+        ESDT_TRANSFER_FUNC_NAME if args.len() >= 2 => vec![TxInputESDT {
+            token_identifier: args[0].clone(),
+            nonce: 0,
+            value: BigUint::from_bytes_be(args[1].as_slice()),
+        }],
+        ESDT_NFT_TRANSFER_FUNC_NAME if args.len() >= 4 => vec![TxInputESDT {
+            token_identifier: args[0].clone(),
+            nonce: u64::top_decode(args[1].as_slice()).unwrap_or_default(),
+            value: BigUint::from_bytes_be(args[2].as_slice()),
+        }],
+        ESDT_MULTI_TRANSFER_FUNC_NAME if args.len() >= 2 => {
+            let payments = usize::top_decode(args[1].as_slice()).unwrap_or_default();
+            if args.len() < 2 + payments * 3 {
+                return Vec::new();
+            }
+            args[2..2 + payments * 3]
+                .chunks(3)
+                .map(|payment| TxInputESDT {
+                    token_identifier: payment[0].clone(),
+                    nonce: u64::top_decode(payment[1].as_slice()).unwrap_or_default(),
+                    value: BigUint::from_bytes_be(payment[2].as_slice()),
+                })
+                .collect()
+        },
+        _ => Vec::new(),
+    }
+}
@@ -1,4 +1,4 @@
-use crate::num_bigint::BigInt;
+use crate::{api::EllipticCurveParams, num_bigint::BigInt};
 use elrond_wasm::api::{const_handles, use_raw_handle, HandleConstraints, RawHandle};
 use std::collections::HashMap;
 
@@ -57,6 +57,7 @@ pub struct TxManagedTypes {
     pub(crate) big_int_map: HandleMap<BigInt>,
     pub(crate) big_float_map: HandleMap<f64>,
     pub(crate) managed_buffer_map: HandleMap<ManagedBufferImpl>,
+    pub(crate) elliptic_curve_map: HandleMap<EllipticCurveParams>,
 }
 
 impl TxManagedTypes {
@@ -65,6 +66,7 @@ impl TxManagedTypes {
             big_int_map: HandleMap::new(),
             big_float_map: HandleMap::new(),
             managed_buffer_map: HandleMap::new(),
+            elliptic_curve_map: HandleMap::new(),
         }
     }
 }
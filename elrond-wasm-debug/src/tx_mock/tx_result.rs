@@ -1,8 +1,9 @@
 use alloc::vec::Vec;
 
+use elrond_wasm::types::parse_contract_error_code;
 use std::fmt;
 
-use super::{TxLog, TxPanic, TxResultCalls};
+use super::{TxLog, TxPanic, TxResultCalls, TxTrace};
 
 #[derive(Clone, Default, Debug)]
 #[must_use]
@@ -12,6 +13,9 @@ pub struct TxResult {
     pub result_values: Vec<Vec<u8>>,
     pub result_logs: Vec<TxLog>,
     pub result_calls: TxResultCalls,
+    pub gas_used: u64,
+    /// Only filled in by the testing framework, when tracing is enabled.
+    pub trace: Option<TxTrace>,
 }
 
 impl TxResult {
@@ -22,6 +26,8 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            gas_used: 0,
+            trace: None,
         }
     }
 
@@ -36,6 +42,8 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            gas_used: 0,
+            trace: None,
         }
     }
 
@@ -47,6 +55,8 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            gas_used: 0,
+            trace: None,
         }
     }
 
@@ -61,6 +71,8 @@ impl TxResult {
             result_values: Vec::new(),
             result_logs: Vec::new(),
             result_calls: TxResultCalls::empty(),
+            gas_used: 0,
+            trace: None,
         }
     }
 
@@ -109,6 +121,22 @@ impl TxResult {
     pub fn assert_user_error(&self, expected_message: &str) {
         self.assert_error(4, expected_message);
     }
+
+    /// The code of the error, if it was declared via `#[derive(ContractError)]`.
+    pub fn error_code(&self) -> Option<u32> {
+        parse_contract_error_code(self.result_message.as_bytes())
+    }
+
+    /// Checks a user error declared via `#[derive(ContractError)]`, ignoring the message arguments.
+    pub fn assert_error_code(&self, expected_code: u32) {
+        assert!(
+            self.result_status == 4 && self.error_code() == Some(expected_code),
+            "Tx error code mismatch. Want status 4, error code {}. Have status {}, message \"{}\"",
+            expected_code,
+            self.result_status,
+            self.result_message.as_str()
+        );
+    }
 }
 
 impl fmt::Display for TxResult {
@@ -0,0 +1,370 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+    rc::Rc,
+};
+
+use elrond_wasm::types::heap::Address;
+
+use crate::{address_hex, num_bigint::BigUint, world_mock::AccountData};
+
+/// Shared by all the caches of a transaction, so that nested calls also get recorded.
+pub type TxTraceRef = Rc<RefCell<TxTrace>>;
+
+/// Everything a transaction did, in order, plus the resulting changes to the accounts.
+///
+/// Only recorded when tracing is enabled.
+#[derive(Clone, Default, Debug)]
+pub struct TxTrace {
+    pub entries: Vec<TxTraceEntry>,
+    pub state_diff: Vec<AccountStateDiff>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TxTraceCallType {
+    ExecuteOnDestContext,
+    ExecuteOnSameContext,
+    ExecuteReadOnly,
+    TransferExecute,
+    Deploy,
+    AsyncCall,
+    Promise,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TxTraceEntry {
+    StorageRead {
+        address: Address,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    },
+    StorageWrite {
+        address: Address,
+        key: Vec<u8>,
+        old_value: Vec<u8>,
+        new_value: Vec<u8>,
+    },
+    /// EGLD transfers have an empty token identifier.
+    Transfer {
+        from: Address,
+        to: Address,
+        token_identifier: Vec<u8>,
+        nonce: u64,
+        amount: BigUint,
+    },
+    /// Async calls and promises only get registered here, they are executed after the transaction.
+    Call {
+        call_type: TxTraceCallType,
+        from: Address,
+        to: Address,
+        endpoint: Vec<u8>,
+        args: Vec<Vec<u8>>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountStateDiff {
+    pub address: Address,
+    pub nonce: Option<(u64, u64)>,
+    pub egld_balance: Option<(BigUint, BigUint)>,
+    pub esdt_balances: Vec<EsdtBalanceDiff>,
+    pub storage: Vec<StorageDiff>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EsdtBalanceDiff {
+    pub token_identifier: Vec<u8>,
+    pub nonce: u64,
+    pub before: BigUint,
+    pub after: BigUint,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageDiff {
+    pub key: Vec<u8>,
+    pub before: Vec<u8>,
+    pub after: Vec<u8>,
+}
+
+impl TxTrace {
+    pub fn storage_writes(&self) -> impl Iterator<Item = &TxTraceEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry, TxTraceEntry::StorageWrite { .. }))
+    }
+
+    pub fn transfers(&self) -> impl Iterator<Item = &TxTraceEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry, TxTraceEntry::Transfer { .. }))
+    }
+
+    pub fn calls(&self) -> impl Iterator<Item = &TxTraceEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry, TxTraceEntry::Call { .. }))
+    }
+
+    /// Compares the accounts before and after the transaction. Only accounts that changed are listed.
+    pub fn compute_state_diff(
+        &mut self,
+        before: &HashMap<Address, AccountData>,
+        after: &HashMap<Address, AccountData>,
+    ) {
+        let mut addresses: Vec<&Address> = before.keys().chain(after.keys()).collect();
+        addresses.sort_by_key(|address| address.as_bytes());
+        addresses.dedup();
+        self.state_diff = addresses
+            .into_iter()
+            .filter_map(|address| {
+                AccountStateDiff::compute(address, before.get(address), after.get(address))
+            })
+            .collect();
+    }
+}
+
+impl AccountStateDiff {
+    fn compute(
+        address: &Address,
+        before: Option<&AccountData>,
+        after: Option<&AccountData>,
+    ) -> Option<Self> {
+        let nonce_before = before.map(|account| account.nonce).unwrap_or_default();
+        let nonce_after = after.map(|account| account.nonce).unwrap_or_default();
+        let egld_before = before
+            .map(|account| account.egld_balance.clone())
+            .unwrap_or_default();
+        let egld_after = after
+            .map(|account| account.egld_balance.clone())
+            .unwrap_or_default();
+
+        let diff = AccountStateDiff {
+            address: address.clone(),
+            nonce: changed(nonce_before, nonce_after),
+            egld_balance: changed(egld_before, egld_after),
+            esdt_balances: esdt_balance_diffs(before, after),
+            storage: storage_diffs(before, after),
+        };
+        if diff.is_empty() {
+            None
+        } else {
+            Some(diff)
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nonce.is_none()
+            && self.egld_balance.is_none()
+            && self.esdt_balances.is_empty()
+            && self.storage.is_empty()
+    }
+}
+
+fn changed<T: PartialEq>(before: T, after: T) -> Option<(T, T)> {
+    if before == after {
+        None
+    } else {
+        Some((before, after))
+    }
+}
+
+fn esdt_balances(account: Option<&AccountData>) -> BTreeMap<(Vec<u8>, u64), BigUint> {
+    let mut balances = BTreeMap::new();
+    if let Some(account) = account {
+        for (token_identifier, esdt_data) in account.esdt.iter() {
+            for (nonce, instance) in esdt_data.instances.get_instances() {
+                balances.insert((token_identifier.clone(), *nonce), instance.balance.clone());
+            }
+        }
+    }
+    balances
+}
+
+fn esdt_balance_diffs(
+    before: Option<&AccountData>,
+    after: Option<&AccountData>,
+) -> Vec<EsdtBalanceDiff> {
+    let balances_before = esdt_balances(before);
+    let balances_after = esdt_balances(after);
+    let keys: BTreeSet<&(Vec<u8>, u64)> = balances_before
+        .keys()
+        .chain(balances_after.keys())
+        .collect();
+    keys.into_iter()
+        .filter_map(|key| {
+            let balance_before = balances_before.get(key).cloned().unwrap_or_default();
+            let balance_after = balances_after.get(key).cloned().unwrap_or_default();
+            changed(balance_before, balance_after).map(|(before, after)| EsdtBalanceDiff {
+                token_identifier: key.0.clone(),
+                nonce: key.1,
+                before,
+                after,
+            })
+        })
+        .collect()
+}
+
+fn storage_diffs(before: Option<&AccountData>, after: Option<&AccountData>) -> Vec<StorageDiff> {
+    let empty = HashMap::new();
+    let storage_before = before.map(|account| &account.storage).unwrap_or(&empty);
+    let storage_after = after.map(|account| &account.storage).unwrap_or(&empty);
+    let keys: BTreeSet<&Vec<u8>> = storage_before.keys().chain(storage_after.keys()).collect();
+    keys.into_iter()
+        .filter_map(|key| {
+            let value_before = storage_before.get(key).cloned().unwrap_or_default();
+            let value_after = storage_after.get(key).cloned().unwrap_or_default();
+            changed(value_before, value_after).map(|(before, after)| StorageDiff {
+                key: key.clone(),
+                before,
+                after,
+            })
+        })
+        .collect()
+}
+
+/// Formats bytes using the mandos value syntax, where possible.
+///
+/// Printable text becomes `str:...`, a printable prefix followed by binary data
+/// (the typical storage mapper key) becomes `str:...|0x...`, anything else is hex.
+pub fn bytes_to_mandos_expr(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        return "\"\"".to_string();
+    }
+    let printable_len = bytes
+        .iter()
+        .take_while(|b| b.is_ascii_graphic() || **b == b' ')
+        .count();
+    if printable_len == bytes.len() {
+        format!("str:{}", String::from_utf8_lossy(bytes))
+    } else if printable_len >= 2 {
+        format!(
+            "str:{}|0x{}",
+            String::from_utf8_lossy(&bytes[..printable_len]),
+            hex::encode(&bytes[printable_len..])
+        )
+    } else {
+        format!("0x{}", hex::encode(bytes))
+    }
+}
+
+fn token_str(token_identifier: &[u8], nonce: u64) -> String {
+    if token_identifier.is_empty() {
+        "EGLD".to_string()
+    } else if nonce == 0 {
+        String::from_utf8_lossy(token_identifier).to_string()
+    } else {
+        format!("{}-{nonce}", String::from_utf8_lossy(token_identifier))
+    }
+}
+
+impl fmt::Display for TxTraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TxTraceEntry::StorageRead {
+                address,
+                key,
+                value,
+            } => write!(
+                f,
+                "storage read   {} {} = {}",
+                address_hex(address),
+                bytes_to_mandos_expr(key),
+                bytes_to_mandos_expr(value)
+            ),
+            TxTraceEntry::StorageWrite {
+                address,
+                key,
+                old_value,
+                new_value,
+            } => write!(
+                f,
+                "storage write  {} {}: {} -> {}",
+                address_hex(address),
+                bytes_to_mandos_expr(key),
+                bytes_to_mandos_expr(old_value),
+                bytes_to_mandos_expr(new_value)
+            ),
+            TxTraceEntry::Transfer {
+                from,
+                to,
+                token_identifier,
+                nonce,
+                amount,
+            } => write!(
+                f,
+                "transfer       {} -> {}: {amount} {}",
+                address_hex(from),
+                address_hex(to),
+                token_str(token_identifier, *nonce)
+            ),
+            TxTraceEntry::Call {
+                call_type,
+                from,
+                to,
+                endpoint,
+                args,
+            } => {
+                write!(
+                    f,
+                    "call           {call_type:?} {} -> {} {}(",
+                    address_hex(from),
+                    address_hex(to),
+                    String::from_utf8_lossy(endpoint)
+                )?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", bytes_to_mandos_expr(arg))?;
+                }
+                write!(f, ")")
+            },
+        }
+    }
+}
+
+impl fmt::Display for AccountStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "account {}", address_hex(&self.address))?;
+        if let Some((before, after)) = &self.nonce {
+            writeln!(f, "  nonce: {before} -> {after}")?;
+        }
+        if let Some((before, after)) = &self.egld_balance {
+            writeln!(f, "  EGLD: {before} -> {after}")?;
+        }
+        for esdt_diff in &self.esdt_balances {
+            writeln!(
+                f,
+                "  {}: {} -> {}",
+                token_str(&esdt_diff.token_identifier, esdt_diff.nonce),
+                esdt_diff.before,
+                esdt_diff.after
+            )?;
+        }
+        for storage_diff in &self.storage {
+            writeln!(
+                f,
+                "  {}: {} -> {}",
+                bytes_to_mandos_expr(&storage_diff.key),
+                bytes_to_mandos_expr(&storage_diff.before),
+                bytes_to_mandos_expr(&storage_diff.after)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TxTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Trace:")?;
+        for entry in &self.entries {
+            writeln!(f, "  {entry}")?;
+        }
+        writeln!(f, "State diff:")?;
+        for account_diff in &self.state_diff {
+            write!(f, "{account_diff}")?;
+        }
+        Ok(())
+    }
+}
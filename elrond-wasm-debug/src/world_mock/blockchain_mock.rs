@@ -1,10 +1,18 @@
-use crate::{mandos_system::model::Scenario, num_bigint::BigUint, tx_mock::BlockchainUpdate};
+use crate::{
+    mandos_system::model::Scenario,
+    num_bigint::BigUint,
+    tx_mock::{BlockchainUpdate, CrossShardCall},
+};
 use elrond_wasm::types::heap::Address;
 use mandos::{interpret_trait::InterpreterContext, value_interpreter::interpret_string};
 use num_traits::Zero;
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    path::PathBuf,
+};
 
-use super::{AccountData, BlockInfo, ContractMap};
+use super::{AccountData, BlockInfo, ContractMap, CoverageCollector, GasSchedule, ShardConfig};
 
 const ELROND_REWARD_KEY: &[u8] = b"ELRONDreward";
 
@@ -18,6 +26,10 @@ pub struct BlockchainMock {
     pub contract_map: ContractMap,
     pub current_dir: PathBuf,
     pub mandos_trace: Scenario,
+    pub shard_config: Option<ShardConfig>,
+    pub pending_cross_shard_calls: VecDeque<CrossShardCall>,
+    pub gas_schedule: Option<GasSchedule>,
+    pub coverage: RefCell<CoverageCollector>,
 }
 
 impl BlockchainMock {
@@ -31,6 +43,10 @@ impl BlockchainMock {
             contract_map: ContractMap::default(),
             current_dir: std::env::current_dir().unwrap(),
             mandos_trace: Scenario::default(),
+            shard_config: None,
+            pending_cross_shard_calls: VecDeque::new(),
+            gas_schedule: None,
+            coverage: RefCell::new(CoverageCollector::default()),
         }
     }
 }
@@ -55,6 +71,15 @@ impl BlockchainMock {
         self.contract_map.contains_contract(&contract_bytes)
     }
 
+    /// Enables gas metering.
+    ///
+    /// API calls get charged according to the schedule, transactions that exceed their gas limit fail,
+    /// and the gas remaining is checked in mandos `expect` fields.
+    pub fn set_gas_schedule(&mut self, gas_schedule: GasSchedule) -> &mut Self {
+        self.gas_schedule = Some(gas_schedule);
+        self
+    }
+
     pub fn commit_updates(&mut self, updates: BlockchainUpdate) {
         updates.apply(self);
     }
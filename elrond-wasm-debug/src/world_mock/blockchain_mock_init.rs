@@ -43,6 +43,9 @@ impl BlockchainMock {
         contract_container: ContractContainer,
     ) {
         let contract_bytes = interpret_string(expression, &self.interpreter_context());
+        self.coverage
+            .get_mut()
+            .register_contract(contract_bytes.clone(), expression);
         self.contract_map
             .register_contract(contract_bytes, contract_container);
     }
@@ -103,5 +106,10 @@ impl BlockchainMock {
                 Some(sub_contract.all_exported_function_names()),
             ),
         );
+
+        let contract_bytes = interpret_string(expression, &self.interpreter_context());
+        self.coverage
+            .get_mut()
+            .register_contract_abi(contract_bytes.as_slice(), &sub_contract.abi);
     }
 }
@@ -0,0 +1,146 @@
+use elrond_wasm::types::heap::Address;
+
+use crate::{
+    display_util::address_hex,
+    num_bigint::BigUint,
+    tx_execution::execute_next_cross_shard_call,
+    tx_mock::{CrossShardCall, CrossShardEsdt, CrossShardFunds, TxInputESDT, TxResult},
+};
+
+use super::BlockchainMock;
+
+/// Optional sharding model for the blockchain mock.
+///
+/// Addresses are assigned to shards based on their last byte, same as the protocol does.
+/// This is also the byte set by the mandos shard syntax, e.g. `address:user#01` is in shard 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShardConfig {
+    pub num_shards: u32,
+}
+
+impl ShardConfig {
+    pub fn new(num_shards: u32) -> Self {
+        assert!(num_shards > 0, "number of shards must be at least 1");
+        ShardConfig { num_shards }
+    }
+
+    pub fn shard_of_address(&self, address: &Address) -> u32 {
+        let num_bits = u32::BITS - (self.num_shards - 1).leading_zeros();
+        let mask_high = (1u32 << num_bits) - 1;
+        let mask_low = mask_high >> 1;
+
+        let last_byte = *address.as_bytes().last().unwrap() as u32;
+        let shard = last_byte & mask_high;
+        if shard < self.num_shards {
+            shard
+        } else {
+            last_byte & mask_low
+        }
+    }
+}
+
+impl BlockchainMock {
+    /// Enables the sharding model.
+    ///
+    /// Async calls and promises between accounts in different shards are no longer executed
+    /// immediately, they get queued and need to be delivered explicitly,
+    /// via `execute_next_cross_shard_call` or `execute_all_cross_shard_calls`.
+    pub fn set_num_shards(&mut self, num_shards: u32) -> &mut Self {
+        self.shard_config = Some(ShardConfig::new(num_shards));
+        self
+    }
+
+    /// Without a sharding model, all accounts are in shard 0.
+    pub fn get_shard_of_address(&self, address: &Address) -> u32 {
+        self.shard_config
+            .map(|shard_config| shard_config.shard_of_address(address))
+            .unwrap_or_default()
+    }
+
+    pub fn is_cross_shard(&self, from: &Address, to: &Address) -> bool {
+        self.get_shard_of_address(from) != self.get_shard_of_address(to)
+    }
+
+    pub fn has_pending_cross_shard_calls(&self) -> bool {
+        !self.pending_cross_shard_calls.is_empty()
+    }
+
+    pub fn pending_cross_shard_calls(&self) -> impl Iterator<Item = &CrossShardCall> {
+        self.pending_cross_shard_calls.iter()
+    }
+
+    /// Delivers the oldest pending cross-shard call or callback.
+    ///
+    /// Async calls that get executed schedule their callback, which will also be pending.
+    ///
+    /// Returns the result of the execution, or `None` if nothing was pending.
+    pub fn execute_next_cross_shard_call(&mut self) -> Option<TxResult> {
+        self.with_borrowed(execute_next_cross_shard_call)
+    }
+
+    /// Delivers pending cross-shard calls and callbacks, until there are none left.
+    pub fn execute_all_cross_shard_calls(&mut self) -> Vec<TxResult> {
+        let mut results = Vec::new();
+        while let Some(tx_result) = self.execute_next_cross_shard_call() {
+            results.push(tx_result);
+        }
+        results
+    }
+
+    /// Takes the funds sent with a cross-shard call from the caller, when the call is queued.
+    pub(crate) fn take_cross_shard_funds(
+        &mut self,
+        from: &Address,
+        egld_value: &BigUint,
+        esdt_values: Vec<TxInputESDT>,
+    ) -> CrossShardFunds {
+        let account = self
+            .accounts
+            .get_mut(from)
+            .unwrap_or_else(|| panic!("Account not found: 0x{}", address_hex(from)));
+        assert!(
+            &account.egld_balance >= egld_value,
+            "Not enough EGLD balance for cross-shard call"
+        );
+        account.egld_balance -= egld_value;
+
+        let esdt_values = esdt_values
+            .into_iter()
+            .map(|esdt| {
+                let instance = account
+                    .esdt
+                    .get_mut_by_identifier(esdt.token_identifier.as_slice())
+                    .and_then(|esdt_data| esdt_data.instances.get_mut_by_nonce(esdt.nonce))
+                    .filter(|instance| instance.balance >= esdt.value)
+                    .expect("Not enough ESDT balance for cross-shard call");
+                instance.balance -= &esdt.value;
+                CrossShardEsdt {
+                    metadata: instance.metadata.clone(),
+                    esdt,
+                }
+            })
+            .collect();
+
+        CrossShardFunds {
+            egld_value: egld_value.clone(),
+            esdt_values,
+        }
+    }
+
+    /// Credits funds in transit to an account, either the recipient or the caller, in case of a refund.
+    pub(crate) fn put_cross_shard_funds(&mut self, to: &Address, funds: &CrossShardFunds) {
+        let account = self
+            .accounts
+            .get_mut(to)
+            .unwrap_or_else(|| panic!("Account not found: 0x{}", address_hex(to)));
+        account.egld_balance += &funds.egld_value;
+        for cross_shard_esdt in &funds.esdt_values {
+            account.esdt.increase_balance(
+                cross_shard_esdt.esdt.token_identifier.clone(),
+                cross_shard_esdt.esdt.nonce,
+                &cross_shard_esdt.esdt.value,
+                cross_shard_esdt.metadata.clone(),
+            );
+        }
+    }
+}
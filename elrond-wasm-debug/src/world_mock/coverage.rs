@@ -0,0 +1,376 @@
+use elrond_wasm::{abi::ContractAbi, contract_base::ContractAbiProvider};
+use mandos::value_interpreter::interpret_string;
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use super::BlockchainMock;
+
+const CALLBACK_FUNC_NAME: &str = "callBack";
+
+/// Reports of all worlds that wrote to the same directory during the current test run.
+///
+/// Tests run in parallel, each with its own world,
+/// so their results get merged here before being written to disk.
+static COVERAGE_REGISTRY: Mutex<BTreeMap<PathBuf, ContractCoverage>> = Mutex::new(BTreeMap::new());
+
+/// Records which endpoints, callbacks, events and error messages were hit by the contracts in a world.
+///
+/// Disabled by default, see `BlockchainMock::enable_coverage`.
+#[derive(Default, Debug)]
+pub struct CoverageCollector {
+    report_dir: Option<PathBuf>,
+    contracts: HashMap<Vec<u8>, ContractCoverage>,
+    current_contracts: Vec<Vec<u8>>,
+}
+
+/// Coverage data for one contract, identified by the name of the file it was registered with.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct ContractCoverage {
+    pub name: String,
+    pub abi_endpoints: BTreeSet<String>,
+    pub abi_callbacks: BTreeSet<String>,
+    pub abi_events: BTreeSet<String>,
+    pub function_hits: BTreeMap<String, u64>,
+    pub event_hits: BTreeMap<String, u64>,
+    pub error_hits: BTreeMap<String, u64>,
+}
+
+#[derive(Serialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct ContractCoverageReport {
+    pub contract: String,
+    pub endpoints: Vec<CoverageReportEntry>,
+    pub callbacks: Vec<CoverageReportEntry>,
+    pub events: Vec<CoverageReportEntry>,
+    pub errors: Vec<CoverageReportEntry>,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct CoverageReportEntry {
+    pub name: String,
+    pub hits: u64,
+}
+
+impl CoverageCollector {
+    pub fn is_enabled(&self) -> bool {
+        self.report_dir.is_some()
+    }
+
+    pub fn enable(&mut self, report_dir: PathBuf) {
+        self.report_dir = Some(report_dir);
+    }
+
+    /// Called for every registered contract, so that the reports can be named after the contract file.
+    pub fn register_contract(&mut self, contract_identifier: Vec<u8>, expression: &str) {
+        let name = contract_name_from_expression(expression);
+        self.contracts
+            .entry(contract_identifier)
+            .or_insert_with(|| ContractCoverage {
+                name,
+                ..Default::default()
+            });
+    }
+
+    /// Without the ABI, the reports only contain what was hit.
+    /// With it, they also show the endpoints, callbacks and events that were never reached.
+    pub fn register_contract_abi(&mut self, contract_identifier: &[u8], abi: &ContractAbi) {
+        if let Some(contract_coverage) = self.contracts.get_mut(contract_identifier) {
+            contract_coverage.set_abi(abi);
+        }
+    }
+
+    pub fn enter_contract(&mut self, contract_identifier: &[u8], function_name: &[u8]) {
+        self.current_contracts.push(contract_identifier.to_vec());
+        if let Some(contract_coverage) = self.current_contract_coverage() {
+            increment(
+                &mut contract_coverage.function_hits,
+                String::from_utf8_lossy(function_name).to_string(),
+            );
+        }
+    }
+
+    pub fn exit_contract(&mut self) {
+        let _ = self.current_contracts.pop();
+    }
+
+    pub fn record_event(&mut self, identifier: &[u8]) {
+        if let Some(contract_coverage) = self.current_contract_coverage() {
+            increment(
+                &mut contract_coverage.event_hits,
+                String::from_utf8_lossy(identifier).to_string(),
+            );
+        }
+    }
+
+    pub fn record_error(&mut self, message: &[u8]) {
+        if let Some(contract_coverage) = self.current_contract_coverage() {
+            increment(
+                &mut contract_coverage.error_hits,
+                String::from_utf8_lossy(message).to_string(),
+            );
+        }
+    }
+
+    fn current_contract_coverage(&mut self) -> Option<&mut ContractCoverage> {
+        if !self.is_enabled() {
+            return None;
+        }
+        let contract_identifier = self.current_contracts.last()?;
+        self.contracts.get_mut(contract_identifier)
+    }
+
+    /// The coverage of the current world only, for all the registered contracts.
+    pub fn reports(&self) -> Vec<ContractCoverageReport> {
+        let mut reports: Vec<ContractCoverageReport> = self
+            .contracts
+            .values()
+            .map(ContractCoverage::report)
+            .collect();
+        reports.sort_by(|a, b| a.contract.cmp(&b.contract));
+        reports
+    }
+
+    /// Merges the coverage of the current world with that of the other worlds in the same test run,
+    /// then writes a JSON and an HTML report for each contract that was called.
+    pub fn write_reports(&self) {
+        let report_dir = if let Some(report_dir) = &self.report_dir {
+            report_dir
+        } else {
+            return;
+        };
+
+        fs::create_dir_all(report_dir).unwrap_or_else(|error| {
+            panic!(
+                "error creating coverage report directory {}: {error}",
+                report_dir.display()
+            )
+        });
+
+        let mut registry = COVERAGE_REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+        for contract_coverage in self.contracts.values() {
+            if contract_coverage.function_hits.is_empty() {
+                continue;
+            }
+
+            let report_path_base = report_dir.join(&contract_coverage.name);
+            let merged =
+                registry
+                    .entry(report_path_base.clone())
+                    .or_insert_with(|| ContractCoverage {
+                        name: contract_coverage.name.clone(),
+                        ..Default::default()
+                    });
+            merged.merge(contract_coverage);
+
+            let report = merged.report();
+            write_report_file(
+                &report_path_base,
+                "coverage.json",
+                serde_json::to_string_pretty(&report).unwrap(),
+            );
+            write_report_file(&report_path_base, "coverage.html", report.to_html());
+        }
+    }
+}
+
+impl BlockchainMock {
+    /// Starts collecting coverage for all registered contracts.
+    ///
+    /// The reports are written by `mandos_rs` at the end of each scenario,
+    /// or explicitly, via `write_coverage_reports`.
+    /// A relative report directory is resolved against the current directory of the world.
+    pub fn enable_coverage<P: AsRef<Path>>(&mut self, report_dir: P) -> &mut Self {
+        let report_dir = self.current_dir.join(report_dir);
+        self.coverage.get_mut().enable(report_dir);
+        self
+    }
+
+    /// Lets the coverage report also list the endpoints, callbacks and events that were never reached.
+    ///
+    /// The contract needs to be registered first.
+    pub fn register_coverage_abi<Abi: ContractAbiProvider>(
+        &mut self,
+        expression: &str,
+    ) -> &mut Self {
+        let contract_bytes = interpret_string(expression, &self.interpreter_context());
+        self.coverage
+            .get_mut()
+            .register_contract_abi(contract_bytes.as_slice(), &Abi::abi());
+        self
+    }
+
+    pub fn coverage_reports(&self) -> Vec<ContractCoverageReport> {
+        self.coverage.borrow().reports()
+    }
+
+    pub fn write_coverage_reports(&self) {
+        self.coverage.borrow().write_reports();
+    }
+}
+
+impl ContractCoverage {
+    fn set_abi(&mut self, abi: &ContractAbi) {
+        self.abi_endpoints = abi
+            .constructors
+            .iter()
+            .chain(abi.endpoints.iter())
+            .map(|endpoint| endpoint.name.to_string())
+            .collect();
+        self.abi_callbacks = abi
+            .promise_callbacks
+            .iter()
+            .map(|callback| callback.name.to_string())
+            .collect();
+        if abi.has_callback {
+            self.abi_callbacks.insert(CALLBACK_FUNC_NAME.to_string());
+        }
+        self.abi_events = abi
+            .events
+            .iter()
+            .map(|event| event.identifier.to_string())
+            .collect();
+    }
+
+    pub fn merge(&mut self, other: &ContractCoverage) {
+        self.abi_endpoints
+            .extend(other.abi_endpoints.iter().cloned());
+        self.abi_callbacks
+            .extend(other.abi_callbacks.iter().cloned());
+        self.abi_events.extend(other.abi_events.iter().cloned());
+        merge_hits(&mut self.function_hits, &other.function_hits);
+        merge_hits(&mut self.event_hits, &other.event_hits);
+        merge_hits(&mut self.error_hits, &other.error_hits);
+    }
+
+    fn is_callback(&self, function_name: &str) -> bool {
+        function_name == CALLBACK_FUNC_NAME || self.abi_callbacks.contains(function_name)
+    }
+
+    pub fn report(&self) -> ContractCoverageReport {
+        let endpoint_names = self.abi_endpoints.iter().chain(
+            self.function_hits
+                .keys()
+                .filter(|name| !self.is_callback(name)),
+        );
+        let callback_names = self.abi_callbacks.iter().chain(
+            self.function_hits
+                .keys()
+                .filter(|name| self.is_callback(name)),
+        );
+        ContractCoverageReport {
+            contract: self.name.clone(),
+            endpoints: report_entries(endpoint_names, &self.function_hits),
+            callbacks: report_entries(callback_names, &self.function_hits),
+            events: report_entries(
+                self.abi_events.iter().chain(self.event_hits.keys()),
+                &self.event_hits,
+            ),
+            errors: report_entries(self.error_hits.keys(), &self.error_hits),
+        }
+    }
+}
+
+impl ContractCoverageReport {
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        writeln!(html, "<!DOCTYPE html>").unwrap();
+        writeln!(html, "<html>").unwrap();
+        writeln!(html, "<head>").unwrap();
+        writeln!(html, "<meta charset=\"utf-8\">").unwrap();
+        writeln!(
+            html,
+            "<title>{} coverage</title>",
+            escape_html(&self.contract)
+        )
+        .unwrap();
+        writeln!(
+            html,
+            "<style>table {{ border-collapse: collapse; }} td, th {{ border: 1px solid #ccc; padding: 2px 8px; }} .missed {{ background: #fdd; }}</style>"
+        )
+        .unwrap();
+        writeln!(html, "</head>").unwrap();
+        writeln!(html, "<body>").unwrap();
+        writeln!(html, "<h1>{} coverage</h1>", escape_html(&self.contract)).unwrap();
+        write_html_section(&mut html, "Endpoints", &self.endpoints);
+        write_html_section(&mut html, "Callbacks", &self.callbacks);
+        write_html_section(&mut html, "Events", &self.events);
+        write_html_section(&mut html, "Errors", &self.errors);
+        writeln!(html, "</body>").unwrap();
+        writeln!(html, "</html>").unwrap();
+        html
+    }
+}
+
+fn write_html_section(html: &mut String, title: &str, entries: &[CoverageReportEntry]) {
+    let hit_count = entries.iter().filter(|entry| entry.hits > 0).count();
+    writeln!(html, "<h2>{title} ({hit_count}/{})</h2>", entries.len()).unwrap();
+    writeln!(html, "<table>").unwrap();
+    writeln!(html, "<tr><th>Name</th><th>Hits</th></tr>").unwrap();
+    for entry in entries {
+        let class = if entry.hits == 0 {
+            " class=\"missed\""
+        } else {
+            ""
+        };
+        writeln!(
+            html,
+            "<tr{class}><td>{}</td><td>{}</td></tr>",
+            escape_html(&entry.name),
+            entry.hits
+        )
+        .unwrap();
+    }
+    writeln!(html, "</table>").unwrap();
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn write_report_file(report_path_base: &Path, extension: &str, contents: String) {
+    let path = report_path_base.with_extension(extension);
+    fs::write(&path, contents).unwrap_or_else(|error| {
+        panic!("error writing coverage report {}: {error}", path.display())
+    });
+}
+
+fn increment(hits: &mut BTreeMap<String, u64>, name: String) {
+    *hits.entry(name).or_default() += 1;
+}
+
+fn merge_hits(hits: &mut BTreeMap<String, u64>, other: &BTreeMap<String, u64>) {
+    for (name, count) in other {
+        *hits.entry(name.clone()).or_default() += count;
+    }
+}
+
+fn report_entries<'a, I>(names: I, hits: &BTreeMap<String, u64>) -> Vec<CoverageReportEntry>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let names: BTreeSet<&String> = names.collect();
+    names
+        .into_iter()
+        .map(|name| CoverageReportEntry {
+            name: name.clone(),
+            hits: hits.get(name).cloned().unwrap_or_default(),
+        })
+        .collect()
+}
+
+/// `file:output/adder.wasm` becomes `adder`.
+fn contract_name_from_expression(expression: &str) -> String {
+    let path = expression.strip_prefix("file:").unwrap_or(expression);
+    Path::new(path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string())
+}
@@ -0,0 +1,88 @@
+use serde::Deserialize;
+use std::{fs, path::Path};
+
+/// Gas costs used by the debugger when metering is enabled.
+///
+/// Has the same layout as the node gas schedule files (e.g. `gasScheduleV3.toml`),
+/// so these can be loaded directly. Only the sections and costs relevant to the debugger are read,
+/// everything else is ignored. Missing costs default to zero.
+#[derive(Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct GasSchedule {
+    #[serde(rename = "BaseOperationCost")]
+    pub base_operation_cost: BaseOperationCost,
+    #[serde(rename = "BaseOpsAPICost")]
+    pub base_ops_api_cost: BaseOpsApiCost,
+    #[serde(rename = "BigIntAPICost")]
+    pub big_int_api_cost: BigIntApiCost,
+}
+
+#[derive(Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BaseOperationCost {
+    pub store_per_byte: u64,
+    pub data_copy_per_byte: u64,
+}
+
+#[derive(Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BaseOpsApiCost {
+    pub storage_load: u64,
+    pub storage_store: u64,
+    pub transfer_value: u64,
+    pub create_async_call: u64,
+    pub execute_on_dest_context: u64,
+    pub execute_on_same_context: u64,
+    pub execute_read_only: u64,
+    pub create_contract: u64,
+}
+
+#[derive(Deserialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct BigIntApiCost {
+    pub big_int_new: u64,
+    pub big_int_add: u64,
+    pub big_int_sub: u64,
+    pub big_int_mul: u64,
+    pub big_int_t_div: u64,
+    pub big_int_t_mod: u64,
+    pub big_int_abs: u64,
+    pub big_int_neg: u64,
+    pub big_int_sign: u64,
+    pub big_int_cmp: u64,
+    pub big_int_sqrt: u64,
+    pub big_int_pow: u64,
+    pub big_int_log2: u64,
+    pub big_int_and: u64,
+    pub big_int_or: u64,
+    pub big_int_xor: u64,
+    pub big_int_shr: u64,
+    pub big_int_shl: u64,
+}
+
+impl GasSchedule {
+    pub fn from_toml_str(toml_str: &str) -> Self {
+        toml::from_str(toml_str)
+            .unwrap_or_else(|error| panic!("error parsing gas schedule: {error}"))
+    }
+
+    pub fn load_toml_file<P: AsRef<Path>>(path: P) -> Self {
+        let toml_str = fs::read_to_string(path.as_ref()).unwrap_or_else(|error| {
+            panic!(
+                "error reading gas schedule file {}: {error}",
+                path.as_ref().display()
+            )
+        });
+        Self::from_toml_str(toml_str.as_str())
+    }
+
+    pub fn storage_load_cost(&self, value_len: usize) -> u64 {
+        self.base_ops_api_cost.storage_load
+            + self.base_operation_cost.data_copy_per_byte * value_len as u64
+    }
+
+    pub fn storage_store_cost(&self, value_len: usize) -> u64 {
+        self.base_ops_api_cost.storage_store
+            + self.base_operation_cost.store_per_byte * value_len as u64
+    }
+}
@@ -4,25 +4,31 @@ mod blockchain_mock;
 mod blockchain_mock_account_util;
 mod blockchain_mock_init;
 mod blockchain_mock_mandos_gen;
+mod blockchain_mock_shards;
 mod blockchain_tx_info;
 mod contract_container;
 mod contract_map;
+mod coverage;
 mod esdt_data;
 mod esdt_instance;
 mod esdt_instance_metadata;
 mod esdt_instances;
 mod esdt_roles;
+mod gas_schedule;
 
 pub use account_data::*;
 pub use block_info::*;
 pub use blockchain_mock::*;
 pub use blockchain_mock_account_util::is_smart_contract_address;
 pub use blockchain_mock_mandos_gen::*;
+pub use blockchain_mock_shards::ShardConfig;
 pub use blockchain_tx_info::*;
 pub use contract_container::*;
 pub use contract_map::*;
+pub use coverage::*;
 pub use esdt_data::*;
 pub use esdt_instance::*;
 pub use esdt_instance_metadata::*;
 pub use esdt_instances::*;
 pub use esdt_roles::*;
+pub use gas_schedule::*;
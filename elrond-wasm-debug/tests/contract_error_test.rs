@@ -0,0 +1,84 @@
+use elrond_wasm::{
+    derive::ContractError,
+    types::{parse_contract_error_code, ContractError},
+};
+use elrond_wasm_debug::tx_mock::TxResult;
+
+#[derive(ContractError, Clone, Copy)]
+enum SampleError {
+    /// Docs end up in the ABI.
+    #[error(code = 7, message = "Nothing to claim")]
+    NothingToClaim,
+    #[error(code = 1001, message = "Amount {} exceeds limit {}")]
+    LimitExceeded,
+}
+
+#[test]
+fn contract_error_derive_test() {
+    assert_eq!(SampleError::NothingToClaim.error_code(), 7);
+    assert_eq!(SampleError::LimitExceeded.error_code(), 1001);
+    assert_eq!(
+        SampleError::LimitExceeded.error_message_template(),
+        "Amount {} exceeds limit {}"
+    );
+    assert_eq!(
+        SampleError::NothingToClaim.contract_error_placeholder_count(),
+        0
+    );
+    assert_eq!(
+        SampleError::LimitExceeded.contract_error_placeholder_count(),
+        2
+    );
+
+    let error_abis = SampleError::error_abis();
+    assert_eq!(error_abis.len(), 2);
+    assert_eq!(error_abis[0].docs, &["Docs end up in the ABI."]);
+    assert_eq!(error_abis[0].code, 7);
+    assert_eq!(error_abis[0].name, "NothingToClaim");
+    assert_eq!(error_abis[1].message, "Amount {} exceeds limit {}");
+}
+
+#[test]
+fn parse_contract_error_code_test() {
+    assert_eq!(parse_contract_error_code(b"E7: Nothing to claim"), Some(7));
+    assert_eq!(
+        parse_contract_error_code(b"E1001: Amount 5 exceeds limit 3"),
+        Some(1001)
+    );
+    assert_eq!(parse_contract_error_code(b"E0: "), Some(0));
+
+    assert_eq!(parse_contract_error_code(b""), None);
+    assert_eq!(parse_contract_error_code(b"Nothing to claim"), None);
+    assert_eq!(parse_contract_error_code(b"E: missing code"), None);
+    assert_eq!(parse_contract_error_code(b"E12 missing separator"), None);
+    assert_eq!(parse_contract_error_code(b"E99999999999: overflow"), None);
+}
+
+#[test]
+fn tx_result_error_code_test() {
+    let tx_result = TxResult {
+        result_status: 4,
+        result_message: "E1001: Amount 5 exceeds limit 3".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(tx_result.error_code(), Some(1001));
+    tx_result.assert_error_code(1001);
+
+    let tx_result = TxResult {
+        result_status: 4,
+        result_message: "some other error".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(tx_result.error_code(), None);
+}
+
+#[test]
+#[should_panic(expected = "Tx error code mismatch")]
+fn tx_result_error_code_mismatch_test() {
+    let tx_result = TxResult {
+        result_status: 4,
+        result_message: "E7: Nothing to claim".to_string(),
+        ..Default::default()
+    };
+    tx_result.assert_error_code(1001);
+}
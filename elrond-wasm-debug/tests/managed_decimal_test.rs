@@ -0,0 +1,230 @@
+use elrond_wasm::{
+    formatter::{FormatBuffer, SCDisplay},
+    types::{
+        BigInt, BigUint, ManagedBuffer, ManagedBufferCachedBuilder, ManagedDecimal,
+        ManagedDecimalSigned, ManagedDecimalVar, RoundingMode,
+    },
+};
+use elrond_wasm_debug::{check_managed_top_encode_decode, DebugApi};
+
+fn format<T: SCDisplay>(item: &T) -> ManagedBuffer<DebugApi> {
+    let mut builder = ManagedBufferCachedBuilder::<DebugApi>::default();
+    builder.append_display(item);
+    builder.into_managed_buffer()
+}
+
+fn decimal<const DECIMALS: usize>(raw_units: u64) -> ManagedDecimal<DebugApi, DECIMALS> {
+    ManagedDecimal::from_raw_units(BigUint::from(raw_units))
+}
+
+fn signed<const DECIMALS: usize>(raw_units: i64) -> ManagedDecimalSigned<DebugApi, DECIMALS> {
+    ManagedDecimalSigned::from_raw_units(BigInt::from(raw_units))
+}
+
+#[test]
+fn test_managed_decimal_arithmetic() {
+    let _ = DebugApi::dummy();
+
+    let a = decimal::<2>(150); // 1.50
+    let b = decimal::<2>(25); // 0.25
+
+    assert_eq!(&a + &b, decimal::<2>(175));
+    assert_eq!(&a - &b, decimal::<2>(125));
+    assert_eq!(&a * &b, decimal::<2>(37)); // 0.375 truncated
+    assert_eq!(&a / &b, decimal::<2>(600));
+    assert_eq!(a.clone() + b.clone(), decimal::<2>(175));
+
+    assert_eq!(a.trunc(), BigUint::from(1u32));
+    assert_eq!(
+        ManagedDecimal::<DebugApi, 2>::from_integer(BigUint::from(3u32)),
+        decimal::<2>(300)
+    );
+    assert!(a > b);
+}
+
+#[test]
+fn test_managed_decimal_checked() {
+    let _ = DebugApi::dummy();
+
+    let a = decimal::<2>(150);
+    let b = decimal::<2>(25);
+
+    assert_eq!(a.checked_sub(&b), Some(decimal::<2>(125)));
+    assert_eq!(b.checked_sub(&a), None);
+    assert_eq!(
+        a.checked_div(&ManagedDecimal::zero(), RoundingMode::Down),
+        None
+    );
+
+    assert_eq!(
+        a.checked_mul(&b, RoundingMode::Down),
+        Some(decimal::<2>(37))
+    );
+    assert_eq!(a.checked_mul(&b, RoundingMode::Up), Some(decimal::<2>(38)));
+    assert_eq!(
+        a.checked_mul(&b, RoundingMode::HalfUp),
+        Some(decimal::<2>(38))
+    );
+
+    // 1.00 / 3.00
+    let one_third_down = decimal::<2>(100).checked_div(&decimal::<2>(300), RoundingMode::Down);
+    let one_third_up = decimal::<2>(100).checked_div(&decimal::<2>(300), RoundingMode::Up);
+    assert_eq!(one_third_down, Some(decimal::<2>(33)));
+    assert_eq!(one_third_up, Some(decimal::<2>(34)));
+}
+
+#[test]
+fn test_managed_decimal_rescale() {
+    let _ = DebugApi::dummy();
+
+    let a = decimal::<4>(12_345); // 1.2345
+
+    assert_eq!(a.rescale::<6>(RoundingMode::Down), decimal::<6>(1_234_500));
+    assert_eq!(a.rescale::<2>(RoundingMode::Down), decimal::<2>(123));
+    assert_eq!(a.rescale::<2>(RoundingMode::Up), decimal::<2>(124));
+    assert_eq!(a.rescale::<3>(RoundingMode::HalfUp), decimal::<3>(1_235));
+    assert_eq!(a.rescale::<1>(RoundingMode::HalfUp), decimal::<1>(12));
+}
+
+#[test]
+fn test_managed_decimal_format() {
+    let _ = DebugApi::dummy();
+
+    assert_eq!(format(&decimal::<2>(150)), ManagedBuffer::from("1.50"));
+    assert_eq!(
+        format(&decimal::<6>(1_005)),
+        ManagedBuffer::from("0.001005")
+    );
+    assert_eq!(format(&decimal::<3>(7_000)), ManagedBuffer::from("7.000"));
+    assert_eq!(format(&decimal::<0>(42)), ManagedBuffer::from("42"));
+    assert_eq!(
+        format(&ManagedDecimalVar::<DebugApi>::from_raw_units(
+            BigUint::from(25u32),
+            1
+        )),
+        ManagedBuffer::from("2.5")
+    );
+}
+
+#[test]
+fn test_managed_decimal_var() {
+    let _ = DebugApi::dummy();
+
+    let a = ManagedDecimalVar::<DebugApi>::from_raw_units(BigUint::from(15u32), 1); // 1.5
+    let b = ManagedDecimalVar::<DebugApi>::from_raw_units(BigUint::from(125u32), 2); // 1.25
+
+    let sum = &a + &b;
+    assert_eq!(sum.scale(), 2);
+    assert_eq!(sum.raw_units(), &BigUint::from(275u32));
+
+    assert_eq!(
+        a.checked_sub(&b).unwrap().raw_units(),
+        &BigUint::from(25u32)
+    );
+    assert_eq!(b.checked_sub(&a), None);
+
+    // result keeps the scale of the left operand
+    let product = a.checked_mul(&b, RoundingMode::HalfUp).unwrap();
+    assert_eq!(product.scale(), 1);
+    assert_eq!(product.raw_units(), &BigUint::from(19u32)); // 1.875 -> 1.9
+
+    assert_eq!(
+        a,
+        ManagedDecimalVar::from_raw_units(BigUint::from(1_500u32), 3)
+    );
+    assert!(a > b);
+
+    assert_eq!(a.to_const::<2>(RoundingMode::Down), decimal::<2>(150));
+    assert_eq!(ManagedDecimalVar::from(decimal::<2>(150)), a);
+}
+
+#[test]
+fn test_managed_decimal_serialization() {
+    let api = DebugApi::dummy();
+
+    // same encoding as the raw amount
+    check_managed_top_encode_decode(api.clone(), decimal::<18>(5), &[5u8]);
+    check_managed_top_encode_decode(
+        api,
+        ManagedDecimalVar::<DebugApi>::from_raw_units(BigUint::from(5u32), 18),
+        &[0, 0, 0, 1, 5, 0, 0, 0, 18],
+    );
+}
+
+#[test]
+fn test_managed_decimal_signed_arithmetic() {
+    let _ = DebugApi::dummy();
+
+    let a = signed::<2>(-150); // -1.50
+    let b = signed::<2>(25); // 0.25
+
+    assert_eq!(&a + &b, signed::<2>(-125));
+    assert_eq!(&b - &a, signed::<2>(175));
+    assert_eq!(&b - &signed::<2>(100), signed::<2>(-75));
+    assert_eq!(&a * &b, signed::<2>(-37)); // -0.375 truncated towards zero
+    assert_eq!(&a / &b, signed::<2>(-600));
+    assert_eq!(-a.clone(), signed::<2>(150));
+
+    assert_eq!(signed::<2>(-175).trunc(), BigInt::from(-1i64));
+    assert_eq!(
+        ManagedDecimalSigned::<DebugApi, 2>::from_integer(BigInt::from(-3i64)),
+        signed::<2>(-300)
+    );
+    assert_eq!(a.magnitude(), decimal::<2>(150));
+    assert_eq!(ManagedDecimalSigned::from(decimal::<2>(25)), b);
+    assert!(a.is_negative());
+    assert!(a < b);
+}
+
+#[test]
+fn test_managed_decimal_signed_rounding() {
+    let _ = DebugApi::dummy();
+
+    let a = signed::<2>(-150);
+    let b = signed::<2>(25);
+
+    // rounding applies to the absolute value
+    assert_eq!(
+        a.checked_mul(&b, RoundingMode::Down),
+        Some(signed::<2>(-37))
+    );
+    assert_eq!(a.checked_mul(&b, RoundingMode::Up), Some(signed::<2>(-38)));
+    assert_eq!(
+        a.checked_mul(&signed::<2>(-25), RoundingMode::HalfUp),
+        Some(signed::<2>(38))
+    );
+    assert_eq!(
+        signed::<2>(100).checked_div(&signed::<2>(-300), RoundingMode::Up),
+        Some(signed::<2>(-34))
+    );
+    assert_eq!(
+        a.checked_div(&ManagedDecimalSigned::zero(), RoundingMode::Down),
+        None
+    );
+    assert_eq!(b.checked_sub(&a), Some(signed::<2>(175)));
+
+    let c = signed::<4>(-12_345); // -1.2345
+    assert_eq!(c.rescale::<2>(RoundingMode::Down), signed::<2>(-123));
+    assert_eq!(c.rescale::<2>(RoundingMode::Up), signed::<2>(-124));
+    assert_eq!(c.rescale::<3>(RoundingMode::HalfUp), signed::<3>(-1_235));
+    assert_eq!(c.rescale::<6>(RoundingMode::Down), signed::<6>(-1_234_500));
+}
+
+#[test]
+fn test_managed_decimal_signed_format() {
+    let _ = DebugApi::dummy();
+
+    assert_eq!(format(&signed::<2>(-150)), ManagedBuffer::from("-1.50"));
+    assert_eq!(format(&signed::<3>(-5)), ManagedBuffer::from("-0.005"));
+    assert_eq!(format(&signed::<2>(150)), ManagedBuffer::from("1.50"));
+    assert_eq!(format(&signed::<0>(-42)), ManagedBuffer::from("-42"));
+}
+
+#[test]
+fn test_managed_decimal_signed_serialization() {
+    let api = DebugApi::dummy();
+
+    // same encoding as the signed raw amount
+    check_managed_top_encode_decode(api.clone(), signed::<18>(-1), &[0xff]);
+    check_managed_top_encode_decode(api, signed::<18>(256), &[1, 0]);
+}
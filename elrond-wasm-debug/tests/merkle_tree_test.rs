@@ -0,0 +1,75 @@
+use elrond_wasm::types::{
+    ManagedBuffer, ManagedByteArray, ManagedVec, MerkleHash, MerkleHashAlgorithm, MerkleTreeHasher,
+};
+use elrond_wasm_debug::{merkle_tree::MerkleHashBytes, DebugApi, MerkleTree};
+
+fn leaves(num_leaves: usize) -> Vec<Vec<u8>> {
+    (0..num_leaves)
+        .map(|i| format!("leaf-{i}").into_bytes())
+        .collect()
+}
+
+fn managed_hashes(hashes: &[MerkleHashBytes]) -> ManagedVec<DebugApi, MerkleHash<DebugApi>> {
+    hashes
+        .iter()
+        .map(ManagedByteArray::new_from_bytes)
+        .collect()
+}
+
+fn check_tree(algorithm: MerkleHashAlgorithm, num_leaves: usize) {
+    let _ = DebugApi::dummy();
+    let leaves = leaves(num_leaves);
+    let tree = MerkleTree::new(algorithm, &leaves);
+    let hasher = MerkleTreeHasher::<DebugApi>::new(algorithm);
+    let root = ManagedByteArray::new_from_bytes(&tree.root());
+
+    let leaf_hashes: Vec<MerkleHashBytes> = (0..num_leaves).map(|i| tree.leaf_hash(i)).collect();
+    assert_eq!(
+        hasher.root_from_leaves(&managed_hashes(&leaf_hashes)),
+        Some(root.clone())
+    );
+
+    for (i, leaf) in leaves.iter().enumerate() {
+        let leaf_hash = hasher.hash_leaf(&ManagedBuffer::new_from_bytes(leaf));
+        assert_eq!(leaf_hash.to_byte_array(), tree.leaf_hash(i));
+
+        let proof = managed_hashes(&tree.proof(i));
+        assert!(hasher.verify_proof(&root, &leaf_hash, &proof));
+
+        let other_leaf_hash = hasher.hash_leaf(&ManagedBuffer::from(&b"not-a-leaf"[..]));
+        assert!(!hasher.verify_proof(&root, &other_leaf_hash, &proof));
+    }
+}
+
+#[test]
+fn merkle_tree_keccak256_test() {
+    for num_leaves in 1..=9 {
+        check_tree(MerkleHashAlgorithm::Keccak256, num_leaves);
+    }
+}
+
+#[test]
+fn merkle_tree_sha256_test() {
+    for num_leaves in [1, 2, 5, 16] {
+        check_tree(MerkleHashAlgorithm::Sha256, num_leaves);
+    }
+}
+
+#[test]
+fn merkle_tree_structure_test() {
+    let tree = MerkleTree::new(MerkleHashAlgorithm::Keccak256, leaves(5));
+    assert_eq!(tree.num_leaves(), 5);
+    assert_eq!(tree.proof(0).len(), 3);
+    // the 5th leaf has no siblings until the top level
+    assert_eq!(tree.proof(4).len(), 1);
+
+    let single_leaf_tree = MerkleTree::new(MerkleHashAlgorithm::Keccak256, leaves(1));
+    assert_eq!(single_leaf_tree.root(), single_leaf_tree.leaf_hash(0));
+    assert!(single_leaf_tree.proof(0).is_empty());
+
+    let _ = DebugApi::dummy();
+    assert_eq!(
+        MerkleTreeHasher::<DebugApi>::keccak256().root_from_leaves(&ManagedVec::new()),
+        None
+    );
+}
@@ -0,0 +1,69 @@
+use std::path::{Path, PathBuf};
+
+use elrond_wasm_debug::{meta::*, *};
+
+const ESDT_TRANSFER_SCENARIO: &str = "tests/mandos-self/builtin-func-esdt-transfer.scen.json";
+const BALANCE_CHECK_ERR_SCENARIO: &str =
+    "tests/mandos-self/esdt-non-zero-balance-check-err.scen.json";
+
+fn world() -> BlockchainMock {
+    let mut blockchain = BlockchainMock::new();
+    blockchain.set_current_dir_from_workspace("elrond-wasm-debug");
+    blockchain
+}
+
+#[test]
+fn find_scenario_files_test() {
+    let crate_dir = world().current_dir;
+    let scenario_paths: Vec<PathBuf> =
+        find_scenario_files(crate_dir.join("tests/mandos-self").as_path())
+            .into_iter()
+            .map(|path| path.strip_prefix(&crate_dir).unwrap().to_path_buf())
+            .collect();
+
+    assert!(scenario_paths.contains(&PathBuf::from(ESDT_TRANSFER_SCENARIO)));
+    assert!(scenario_paths.contains(&PathBuf::from(
+        "tests/mandos-self/external_steps/external_steps.scen.json"
+    )));
+    assert!(scenario_paths
+        .iter()
+        .all(|path| path.to_string_lossy().ends_with(".scen.json")));
+
+    let mut sorted = scenario_paths.clone();
+    sorted.sort();
+    assert_eq!(scenario_paths, sorted);
+}
+
+#[test]
+fn run_scenarios_test() {
+    let crate_dir = world().current_dir;
+    let scenario_paths = vec![
+        PathBuf::from(ESDT_TRANSFER_SCENARIO),
+        PathBuf::from(BALANCE_CHECK_ERR_SCENARIO),
+    ];
+
+    let results = run_scenarios(
+        crate_dir.as_path(),
+        scenario_paths.as_slice(),
+        Some(&world),
+        false,
+    );
+    print_scenario_results(results.as_slice());
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].path, Path::new(ESDT_TRANSFER_SCENARIO));
+    assert_eq!(results[0].rs, ScenarioOutcome::Passed);
+    assert_eq!(results[0].go, ScenarioOutcome::Skipped);
+    assert!(!results[0].is_failed());
+    assert!(results[0].has_run());
+
+    assert_eq!(results[1].path, Path::new(BALANCE_CHECK_ERR_SCENARIO));
+    assert!(results[1].rs.is_failed());
+    assert!(results[1].is_failed());
+
+    let results = run_scenarios(crate_dir.as_path(), scenario_paths.as_slice(), None, false);
+    assert!(results
+        .iter()
+        .all(|result| result.rs == ScenarioOutcome::Skipped && !result.is_failed()));
+    assert!(!results.iter().any(ScenarioTestResult::has_run));
+}
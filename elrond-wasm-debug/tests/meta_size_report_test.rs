@@ -0,0 +1,189 @@
+use elrond_wasm_debug::meta::*;
+
+fn section(id: u8, payload: Vec<u8>) -> Vec<u8> {
+    let mut result = vec![id, payload.len() as u8];
+    result.extend(payload);
+    result
+}
+
+fn name(s: &str) -> Vec<u8> {
+    let mut result = vec![s.len() as u8];
+    result.extend_from_slice(s.as_bytes());
+    result
+}
+
+/// One imported function, two defined functions (one exported), a data segment and a name section.
+fn sample_wasm(init_body_len: usize, with_names: bool) -> Vec<u8> {
+    let mut wasm = b"\0asm\x01\0\0\0".to_vec();
+
+    // type section: () -> ()
+    wasm.extend(section(1, vec![1, 0x60, 0, 0]));
+
+    let mut imports = vec![1];
+    imports.extend(name("env"));
+    imports.extend(name("getCaller"));
+    imports.extend([0, 0]);
+    wasm.extend(section(2, imports));
+
+    wasm.extend(section(3, vec![2, 0, 0]));
+
+    let mut exports = vec![1];
+    exports.extend(name("init"));
+    exports.extend([0, 1]);
+    wasm.extend(section(7, exports));
+
+    let mut init_body = vec![0; init_body_len - 1];
+    init_body.push(0x0b);
+    let mut code = vec![2, init_body_len as u8];
+    code.extend(init_body);
+    code.extend([2, 0, 0x0b]);
+    wasm.extend(section(10, code));
+
+    wasm.extend(section(11, vec![1, 0, 0x41, 0, 0x0b, 3, 1, 2, 3]));
+
+    if with_names {
+        let mut function_names = vec![1, 2];
+        function_names.extend(name("_ZN5adder4init17h0123456789abcdefE"));
+        let mut payload = name("name");
+        payload.push(1);
+        payload.push(function_names.len() as u8);
+        payload.extend(function_names);
+        wasm.extend(section(0, payload));
+    }
+
+    wasm
+}
+
+#[test]
+fn size_report_test() {
+    let wasm = sample_wasm(10, true);
+    let report = WasmSizeReport::from_wasm_bytes(wasm.as_slice()).unwrap();
+
+    assert_eq!(report.total_size, wasm.len());
+    assert_eq!(report.code_section_size, 15);
+    assert_eq!(report.data_section_size, 9);
+    assert_eq!(report.imports, vec!["getCaller".to_string()]);
+    assert_eq!(
+        report
+            .sections
+            .iter()
+            .map(|section| section.name.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "type",
+            "import",
+            "function",
+            "export",
+            "code",
+            "data",
+            "custom:name"
+        ]
+    );
+    assert_eq!(
+        report.functions,
+        vec![
+            WasmFunctionSize {
+                index: 1,
+                name: "init".to_string(),
+                size: 10,
+            },
+            WasmFunctionSize {
+                index: 2,
+                name: "adder::init".to_string(),
+                size: 2,
+            },
+        ]
+    );
+}
+
+#[test]
+fn size_report_without_names_test() {
+    let report = WasmSizeReport::from_wasm_bytes(sample_wasm(10, false).as_slice()).unwrap();
+    let names: Vec<&str> = report
+        .functions
+        .iter()
+        .map(|function| function.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["init", "func[2]"]);
+
+    assert!(WasmSizeReport::from_wasm_bytes(b"not wasm").is_err());
+}
+
+#[test]
+fn size_comparison_test() {
+    let previous = WasmSizeReport::from_wasm_bytes(sample_wasm(10, false).as_slice()).unwrap();
+    let current = WasmSizeReport::from_wasm_bytes(sample_wasm(30, false).as_slice()).unwrap();
+
+    let comparison = WasmSizeComparison::compare(&previous, &current);
+    assert_eq!(comparison.size_change(), 20);
+    assert_eq!(
+        comparison.function_changes,
+        vec![WasmFunctionSizeChange {
+            name: "init".to_string(),
+            size_before: 10,
+            size_after: 30,
+        }]
+    );
+    assert!(comparison.exceeds_threshold(10.0));
+    assert!(!comparison.exceeds_threshold(50.0));
+
+    let reverse = WasmSizeComparison::compare(&current, &previous);
+    assert_eq!(reverse.size_change(), -20);
+    assert!(!reverse.exceeds_threshold(0.0));
+    assert!(reverse.to_string().contains("-20 bytes"));
+}
+
+#[test]
+fn size_report_json_test() {
+    let report = WasmSizeReport::from_wasm_bytes(sample_wasm(10, true).as_slice()).unwrap();
+    let path = std::path::PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("adder.size.json");
+    report.save_json_file(&path);
+
+    let json = std::fs::read_to_string(&path).unwrap();
+    assert!(json.contains("\"codeSectionSize\": 15"));
+    assert_eq!(WasmSizeReport::load_json_file(&path), Some(report));
+}
+
+#[test]
+fn size_compare_without_previous_report_test() {
+    let report = WasmSizeReport::from_wasm_bytes(sample_wasm(10, false).as_slice()).unwrap();
+    let missing_path =
+        std::path::PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("missing.size.json");
+    report.compare_with_previous_file("adder", missing_path, None);
+}
+
+#[test]
+#[should_panic(expected = "cannot check the size threshold of contract adder")]
+fn size_threshold_without_previous_report_test() {
+    let report = WasmSizeReport::from_wasm_bytes(sample_wasm(10, false).as_slice()).unwrap();
+    let missing_path =
+        std::path::PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("missing.size.json");
+    report.compare_with_previous_file("adder", missing_path, Some(5.0));
+}
+
+fn cli_args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn size_threshold_args_test() {
+    let build_args = BuildArgs::process(&cli_args(&[
+        "build",
+        "--size-compare",
+        "previous-output",
+        "--size-threshold",
+        "5",
+    ]));
+    assert!(build_args.size_report);
+    assert_eq!(
+        build_args.size_compare_dir.as_deref(),
+        Some("previous-output")
+    );
+    assert_eq!(build_args.size_threshold, Some(5.0));
+}
+
+#[test]
+#[should_panic(expected = "argument `--size-threshold` requires `--size-compare`")]
+fn size_threshold_without_compare_test() {
+    BuildArgs::process(&cli_args(&["build", "--size-threshold", "5"]));
+}
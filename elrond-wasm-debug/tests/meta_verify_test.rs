@@ -0,0 +1,89 @@
+use elrond_wasm::abi::ContractAbi;
+use elrond_wasm_debug::{
+    abi_json::{serialize_abi_to_json, ContractAbiJson, ContractBuildAbiJson},
+    meta::*,
+};
+
+const EMPTY_CODE_HASH: &str = "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8";
+
+#[test]
+fn code_hash_test() {
+    assert_eq!(hex::encode(code_hash(&[])), EMPTY_CODE_HASH);
+    assert_eq!(
+        hex::encode(code_hash(b"\0asm")),
+        "c04f4579bbed4a462d59b8c24ba3098e71dc055d6b05bc1f0bfb04bac44b2ea2"
+    );
+}
+
+#[test]
+fn parse_expected_code_hash_test() {
+    let expected = hex::decode(EMPTY_CODE_HASH).unwrap();
+    assert_eq!(
+        parse_expected_code_hash(EMPTY_CODE_HASH),
+        Ok(expected.clone())
+    );
+    assert_eq!(
+        parse_expected_code_hash(format!("0x{EMPTY_CODE_HASH}").as_str()),
+        Ok(expected.clone())
+    );
+
+    let wasm_path = std::path::PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("empty.wasm");
+    std::fs::write(&wasm_path, []).unwrap();
+    assert_eq!(
+        parse_expected_code_hash(wasm_path.to_str().unwrap()),
+        Ok(expected)
+    );
+
+    assert!(parse_expected_code_hash("0x1234").is_err());
+    assert!(parse_expected_code_hash("output/missing.wasm").is_err());
+}
+
+#[test]
+fn build_args_cli_roundtrip_test() {
+    let args: Vec<String> = [
+        "build",
+        "--wasm-suffix",
+        "dbg",
+        "--no-wasm-opt",
+        "--wat",
+        "--locked",
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect();
+    let build_args = BuildArgs::process(args.as_slice());
+    assert_eq!(
+        build_args.to_cli_args(),
+        vec!["--wasm-suffix", "dbg", "--no-wasm-opt", "--wat", "--locked"]
+    );
+
+    let reparsed = BuildArgs::process(build_args.to_cli_args().as_slice());
+    assert_eq!(reparsed.to_cli_args(), build_args.to_cli_args());
+    assert!(BuildArgs::default().to_cli_args().is_empty());
+}
+
+#[test]
+fn recorded_build_args_test() {
+    let abi_dir = std::path::PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+    let mut abi_json = ContractAbiJson::from(&ContractAbi::default());
+
+    let unbuilt_abi_path = abi_dir.join("unbuilt.abi.json");
+    std::fs::write(&unbuilt_abi_path, serialize_abi_to_json(&abi_json)).unwrap();
+    assert!(recorded_build_args(&unbuilt_abi_path)
+        .unwrap_err()
+        .contains("contains no build settings"));
+
+    abi_json.build_info.contract_build = Some(ContractBuildAbiJson {
+        code_hash: EMPTY_CODE_HASH.to_string(),
+        cargo_lock_hash: String::new(),
+        build_flags: vec!["--wasm-symbols".to_string(), "--no-wasm-opt".to_string()],
+    });
+    let built_abi_path = abi_dir.join("built.abi.json");
+    std::fs::write(&built_abi_path, serialize_abi_to_json(&abi_json)).unwrap();
+    let build_args = recorded_build_args(&built_abi_path).unwrap();
+    assert!(build_args.debug_symbols);
+    assert!(!build_args.wasm_opt);
+    assert!(build_args.locked);
+
+    assert!(recorded_build_args(abi_dir.join("missing.abi.json")).is_err());
+}
@@ -0,0 +1,337 @@
+use elrond_wasm::{
+    abi::{
+        ContractAbi, EnumVariantDescription, StorageAbi, StructFieldDescription, TypeAbi,
+        TypeContents, TypeDescription, TypeDescriptionContainer, TypeDescriptionContainerImpl,
+    },
+    elrond_codec::DecodeError,
+    types::{ManagedDecimal, ManagedDecimalSigned},
+};
+use elrond_wasm_debug::{
+    storage_decode::{
+        load_storage_dump, AbiValueDecoder, DecodedStorageEntry, StorageDecoder,
+        UNKNOWN_TYPE_ERR_MSG,
+    },
+    DebugApi,
+};
+
+fn field(name: &'static str, field_type: &str) -> StructFieldDescription {
+    StructFieldDescription {
+        docs: &[],
+        name,
+        field_type: field_type.into(),
+        since: None,
+    }
+}
+
+fn sample_value_decoder() -> AbiValueDecoder {
+    let mut type_descriptions = TypeDescriptionContainerImpl::default();
+    type_descriptions.insert(
+        "Payment".into(),
+        TypeDescription {
+            docs: &[],
+            name: "Payment".into(),
+            contents: TypeContents::Struct(vec![
+                field("token", "TokenIdentifier"),
+                field("amount", "BigUint"),
+            ]),
+            version: None,
+        },
+    );
+    type_descriptions.insert(
+        "Status".into(),
+        TypeDescription {
+            docs: &[],
+            name: "Status".into(),
+            contents: TypeContents::Enum(vec![
+                EnumVariantDescription {
+                    docs: &[],
+                    name: "Inactive",
+                    discriminant: 0,
+                    fields: Vec::new(),
+                },
+                EnumVariantDescription {
+                    docs: &[],
+                    name: "Active",
+                    discriminant: 5,
+                    fields: vec![field("0", "u64")],
+                },
+            ]),
+            version: None,
+        },
+    );
+    type_descriptions.insert(
+        "Config".into(),
+        TypeDescription {
+            docs: &[],
+            name: "Config".into(),
+            contents: TypeContents::Struct(vec![
+                field("fee", "u32"),
+                StructFieldDescription {
+                    since: Some(2),
+                    ..field("paused", "bool")
+                },
+            ]),
+            version: Some(2),
+        },
+    );
+    AbiValueDecoder::new(type_descriptions)
+}
+
+#[test]
+fn decode_top_basic_types_test() {
+    let decoder = sample_value_decoder();
+    assert_eq!(decoder.decode_top("u32", &[1, 0]).unwrap(), "256");
+    assert_eq!(decoder.decode_top("u64", &[]).unwrap(), "0");
+    assert_eq!(decoder.decode_top("i16", &[0xff]).unwrap(), "-1");
+    assert_eq!(decoder.decode_top("bool", &[1]).unwrap(), "true");
+    assert_eq!(decoder.decode_top("BigUint", &[1, 0]).unwrap(), "256");
+    assert_eq!(decoder.decode_top("BigInt", &[0xff, 0]).unwrap(), "-256");
+    assert_eq!(decoder.decode_top("bytes", b"abc").unwrap(), "str:abc");
+    assert_eq!(decoder.decode_top("bytes", &[0, 1]).unwrap(), "0x0001");
+    assert_eq!(decoder.decode_top("bytes", &[]).unwrap(), "\"\"");
+    assert_eq!(
+        decoder.decode_top("u16", &[1, 2, 3]),
+        Err(DecodeError::INPUT_TOO_LONG)
+    );
+    assert_eq!(
+        decoder.decode_top("Unknown", &[1]),
+        Err(DecodeError::from(UNKNOWN_TYPE_ERR_MSG))
+    );
+}
+
+#[test]
+fn decode_top_composite_types_test() {
+    let decoder = sample_value_decoder();
+    assert_eq!(
+        decoder
+            .decode_top(
+                "List<tuple<u8,bytes>>",
+                &[5, 0, 0, 0, 1, b'x', 6, 0, 0, 0, 0]
+            )
+            .unwrap(),
+        "[(5, str:x), (6, \"\")]"
+    );
+    assert_eq!(decoder.decode_top("Option<u32>", &[]).unwrap(), "None");
+    assert_eq!(
+        decoder
+            .decode_top("Option<BigInt>", &[1, 0, 0, 0, 1, 0xff])
+            .unwrap(),
+        "Some(-1)"
+    );
+    assert_eq!(
+        decoder.decode_top("array3<u8>", &[1, 2, 3]).unwrap(),
+        "0x010203"
+    );
+    assert_eq!(
+        decoder.decode_top("array2<u16>", &[0, 1, 0, 2]).unwrap(),
+        "[1, 2]"
+    );
+    assert_eq!(
+        decoder
+            .decode_top("Payment", b"\x00\x00\x00\x03ABC\x00\x00\x00\x01\x64")
+            .unwrap(),
+        "Payment { token: str:ABC, amount: 100 }"
+    );
+    assert_eq!(
+        decoder.decode_top("Status", &[]).unwrap(),
+        "Status::Inactive"
+    );
+    assert_eq!(
+        decoder
+            .decode_top("Status", &[5, 0, 0, 0, 0, 0, 0, 0, 7])
+            .unwrap(),
+        "Status::Active(7)"
+    );
+    assert_eq!(
+        decoder.decode_top("Status", &[1]),
+        Err(DecodeError::INVALID_VALUE)
+    );
+}
+
+#[test]
+fn decode_nested_test() {
+    let decoder = sample_value_decoder();
+    let mut input = &[0u8, 0, 0, 2, 0, 7, 0, 8, 9][..];
+    assert_eq!(
+        decoder.decode_nested("List<u16>", &mut input).unwrap(),
+        "[7, 8]"
+    );
+    assert_eq!(input, &[9]);
+}
+
+#[test]
+fn decode_versioned_struct_test() {
+    let decoder = sample_value_decoder();
+    assert_eq!(
+        decoder.decode_top("Config", &[2, 0, 0, 0, 5, 1]).unwrap(),
+        "Config { fee: 5, paused: true }"
+    );
+    assert_eq!(
+        decoder.decode_top("Config", &[1, 0, 0, 0, 5]).unwrap(),
+        "Config { fee: 5 }"
+    );
+    assert_eq!(
+        decoder.decode_top("Config", &[1, 0, 0, 0, 5, 1]),
+        Err(DecodeError::INPUT_TOO_LONG)
+    );
+    assert_eq!(
+        decoder.decode_top("Config", &[3, 0, 0, 0, 5, 1]),
+        Err(DecodeError::UNSUPPORTED_VERSION)
+    );
+}
+
+#[test]
+fn decode_transparent_decimal_test() {
+    let mut type_descriptions = TypeDescriptionContainerImpl::default();
+    ManagedDecimal::<DebugApi, 2>::provide_type_descriptions(&mut type_descriptions);
+    ManagedDecimalSigned::<DebugApi, 2>::provide_type_descriptions(&mut type_descriptions);
+    type_descriptions.insert(
+        "Price".into(),
+        TypeDescription {
+            docs: &[],
+            name: "Price".into(),
+            contents: TypeContents::Struct(vec![
+                field("value", "ManagedDecimal<2>"),
+                field("change", "ManagedDecimalSigned<2>"),
+            ]),
+            version: None,
+        },
+    );
+    let decoder = AbiValueDecoder::new(type_descriptions);
+
+    assert_eq!(
+        decoder.decode_top("ManagedDecimal<2>", &[1, 0]).unwrap(),
+        "256"
+    );
+    assert_eq!(
+        decoder
+            .decode_top("ManagedDecimalSigned<2>", &[0xff])
+            .unwrap(),
+        "-1"
+    );
+    assert_eq!(
+        decoder
+            .decode_top("Price", &[0, 0, 0, 1, 150, 0, 0, 0, 1, 0xff])
+            .unwrap(),
+        "Price { value: 150, change: -1 }"
+    );
+}
+
+fn mapper_storage_abi(key: &'static str, mapper: &'static str, value_types: &[&str]) -> StorageAbi {
+    let mut storage_abi = StorageAbi {
+        docs: &[],
+        key,
+        mapper,
+        key_args: Vec::new(),
+        value_types: Vec::new(),
+    };
+    for value_type in value_types {
+        storage_abi.add_value_type(value_type.to_string());
+    }
+    storage_abi
+}
+
+fn decoded(key: &str, value: &str) -> DecodedStorageEntry {
+    DecodedStorageEntry {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn decode_mapper_layouts_test() {
+    let abi = ContractAbi {
+        storage: vec![
+            mapper_storage_abi("ordered", "OrderedMapMapper", &["u64", "bytes"]),
+            mapper_storage_abi("queue", "PriorityQueueMapper", &["u64", "bytes"]),
+            mapper_storage_abi("bitmap", "BitmapMapper", &[]),
+            mapper_storage_abi("checkpoints", "CheckpointMapper", &["BigUint"]),
+        ],
+        ..Default::default()
+    };
+    let decoder = StorageDecoder::new(&abi);
+
+    assert_eq!(
+        decoder.decode_entry(b"ordered.info", &[0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2]),
+        decoded(
+            "ordered.info",
+            "OrderedMapMapperInfo { len: 2, root: 1, new: 2 }"
+        )
+    );
+    assert_eq!(
+        decoder.decode_entry(
+            b"ordered.node_links\x00\x00\x00\x01",
+            &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 2]
+        ),
+        decoded(
+            "ordered.node_links(1)",
+            "TreeNode { parent: 0, left: 0, right: 2, height: 2 }"
+        )
+    );
+    assert_eq!(
+        decoder.decode_entry(b"ordered.node_key\x00\x00\x00\x01", &[5]),
+        decoded("ordered.node_key(1)", "5")
+    );
+    assert_eq!(
+        decoder.decode_entry(b"ordered.node_value\x00\x00\x00\x01", b"abc"),
+        decoded("ordered.node_value(1)", "str:abc")
+    );
+    assert_eq!(
+        decoder.decode_entry(b"ordered.node_id\x00\x00\x00\x00\x00\x00\x00\x05", &[1]),
+        decoded("ordered.node_id(5)", "1")
+    );
+
+    assert_eq!(
+        decoder.decode_entry(b"queue.len", &[3]),
+        decoded("queue.len", "3")
+    );
+    assert_eq!(
+        decoder.decode_entry(b"queue.priority\x00\x00\x00\x00", &[7]),
+        decoded("queue.priority(0)", "7")
+    );
+    assert_eq!(
+        decoder.decode_entry(b"queue.value\x00\x00\x00\x00", b"x"),
+        decoded("queue.value(0)", "str:x")
+    );
+
+    assert_eq!(
+        decoder.decode_entry(b"bitmap.num_words", &[1]),
+        decoded("bitmap.num_words", "1")
+    );
+    let mut word = [0u8; 32];
+    word[0] = 0x81;
+    assert_eq!(
+        decoder.decode_entry(b"bitmap.word\x00\x00\x00\x00\x00\x00\x00\x00", &word),
+        decoded(
+            "bitmap.word(0)",
+            format!("0x81{}", "00".repeat(31)).as_str()
+        )
+    );
+
+    assert_eq!(
+        decoder.decode_entry(b"checkpoints.len", &[2]),
+        decoded("checkpoints.len", "2")
+    );
+    assert_eq!(
+        decoder.decode_entry(b"checkpoints.block_nonce\x00\x00\x00\x01", &[10]),
+        decoded("checkpoints.block_nonce(1)", "10")
+    );
+    assert_eq!(
+        decoder.decode_entry(b"checkpoints.value\x00\x00\x00\x01", &[1, 0]),
+        decoded("checkpoints.value(1)", "256")
+    );
+}
+
+#[test]
+fn load_key_value_storage_dump_test() {
+    let account_dumps = load_storage_dump("tests/storage-dump/account_keys.json");
+    assert_eq!(account_dumps.len(), 1);
+    assert_eq!(account_dumps[0].account, None);
+
+    let storage = &account_dumps[0].storage;
+    assert_eq!(storage.len(), 2);
+    assert_eq!(storage.get(&b"counter"[..].to_vec()), Some(&vec![5u8]));
+    let mut owner = vec![0u8; 32];
+    owner[31] = 1;
+    assert_eq!(storage.get(&b"owner"[..].to_vec()), Some(&owner));
+}
@@ -0,0 +1,125 @@
+use elrond_wasm::{
+    storage::{
+        mappers::{BitmapMapper, StorageClearable, StorageMapper},
+        storage_get_len, StorageKey,
+    },
+    types::ManagedType,
+};
+use elrond_wasm_debug::DebugApi;
+
+fn create_bitmap() -> BitmapMapper<DebugApi> {
+    let _ = DebugApi::dummy();
+    let base_key = StorageKey::new(&b"my_bitmap"[..]);
+    BitmapMapper::new(base_key)
+}
+
+#[test]
+fn test_bitmap_set_get() {
+    let mut bitmap = create_bitmap();
+    assert!(!bitmap.get(0));
+    assert!(bitmap.set(0));
+    assert!(bitmap.get(0));
+    assert!(!bitmap.set(0));
+
+    for index in [7, 8, 255, 256, 1_000_000] {
+        assert!(!bitmap.get(index));
+        assert!(bitmap.set(index));
+        assert!(bitmap.get(index));
+    }
+    assert!(!bitmap.get(6));
+    assert!(!bitmap.get(9));
+    assert!(!bitmap.get(257));
+    assert!(!bitmap.get(999_999));
+}
+
+#[test]
+fn test_bitmap_unset() {
+    let mut bitmap = create_bitmap();
+    assert!(!bitmap.unset(3));
+    bitmap.set(3);
+    bitmap.set(4);
+    assert!(bitmap.unset(3));
+    assert!(!bitmap.get(3));
+    assert!(bitmap.get(4));
+    assert!(!bitmap.unset(3));
+
+    // emptied words are removed from storage
+    assert!(bitmap.unset(4));
+    let word_key = {
+        let mut key = StorageKey::<DebugApi>::new(&b"my_bitmap.word"[..]);
+        key.append_item(&0u64);
+        key
+    };
+    assert_eq!(storage_get_len(word_key.as_ref()), 0);
+}
+
+#[test]
+fn test_bitmap_count_ones() {
+    let mut bitmap = create_bitmap();
+    assert_eq!(bitmap.count_ones(..), 0);
+
+    let indexes = [0, 1, 63, 64, 200, 255, 256, 511, 512, 1_000];
+    for index in indexes {
+        bitmap.set(index);
+    }
+    assert_eq!(bitmap.count_ones(..), indexes.len() as u64);
+    assert_eq!(bitmap.count_ones(0..64), 3);
+    assert_eq!(bitmap.count_ones(1..=64), 3);
+    assert_eq!(bitmap.count_ones(64..256), 3);
+    assert_eq!(bitmap.count_ones(255..=256), 2);
+    assert_eq!(bitmap.count_ones(257..511), 0);
+    assert_eq!(bitmap.count_ones(511..), 3);
+    assert_eq!(bitmap.count_ones(1_001..), 0);
+    assert_eq!(bitmap.count_ones(10..10), 0);
+
+    bitmap.unset(256);
+    assert_eq!(bitmap.count_ones(200..600), 4);
+}
+
+#[test]
+fn test_bitmap_iter() {
+    let mut bitmap = create_bitmap();
+    assert_eq!(bitmap.iter().next(), None);
+
+    let indexes = [2, 63, 64, 127, 255, 256, 300, 767, 768, 5_000];
+    for index in indexes.iter().rev() {
+        bitmap.set(*index);
+    }
+    assert_eq!(bitmap.iter().collect::<Vec<u64>>(), indexes.to_vec());
+    assert_eq!(
+        bitmap.iter_range(63..=256).collect::<Vec<u64>>(),
+        vec![63, 64, 127, 255, 256]
+    );
+    assert_eq!(
+        bitmap.iter_range(257..768).collect::<Vec<u64>>(),
+        vec![300, 767]
+    );
+    assert_eq!(
+        bitmap.iter_range(4_000..).collect::<Vec<u64>>(),
+        vec![5_000]
+    );
+    assert_eq!(bitmap.iter_range(5_001..).next(), None);
+
+    bitmap.unset(64);
+    bitmap.unset(5_000);
+    assert_eq!(
+        bitmap.iter().collect::<Vec<u64>>(),
+        vec![2, 63, 127, 255, 256, 300, 767, 768]
+    );
+}
+
+#[test]
+fn test_bitmap_clear() {
+    let mut bitmap = create_bitmap();
+    for index in [1, 300, 10_000] {
+        bitmap.set(index);
+    }
+    bitmap.clear();
+    assert!(!bitmap.get(1));
+    assert!(!bitmap.get(10_000));
+    assert_eq!(bitmap.count_ones(..), 0);
+    assert_eq!(bitmap.iter().next(), None);
+
+    assert!(bitmap.set(300));
+    assert_eq!(bitmap.iter().collect::<Vec<u64>>(), vec![300]);
+}
@@ -0,0 +1,74 @@
+use elrond_wasm::storage::{
+    mappers::{CheckpointMapper, StorageMapper},
+    StorageKey,
+};
+use elrond_wasm_debug::DebugApi;
+
+fn create_checkpoints() -> CheckpointMapper<DebugApi, u64> {
+    let _ = DebugApi::dummy();
+    let base_key = StorageKey::new(&b"my_checkpoints"[..]);
+    CheckpointMapper::new(base_key)
+}
+
+#[test]
+fn test_checkpoint_record() {
+    let mut checkpoints = create_checkpoints();
+    assert!(checkpoints.is_empty());
+    assert_eq!(checkpoints.latest(), 0);
+    assert_eq!(checkpoints.latest_block_nonce(), None);
+    assert_eq!(checkpoints.value_at(100), 0);
+
+    checkpoints.record(10, &100);
+    checkpoints.record(20, &50);
+    assert_eq!(checkpoints.len(), 2);
+    assert_eq!(checkpoints.latest(), 50);
+    assert_eq!(checkpoints.latest_block_nonce(), Some(20));
+
+    // same block overwrites
+    checkpoints.record(20, &70);
+    assert_eq!(checkpoints.len(), 2);
+    assert_eq!(checkpoints.latest(), 70);
+
+    let new_value = checkpoints.update(25, |value| {
+        *value += 5;
+        *value
+    });
+    assert_eq!(new_value, 75);
+    assert_eq!(
+        checkpoints.iter().collect::<Vec<(u64, u64)>>(),
+        vec![(10, 100), (20, 70), (25, 75)]
+    );
+    assert!(checkpoints.check_internal_consistency());
+}
+
+#[test]
+fn test_checkpoint_value_at() {
+    let mut checkpoints = create_checkpoints();
+    for i in 1..=20u64 {
+        checkpoints.record(i * 10, &i);
+    }
+    assert!(checkpoints.check_internal_consistency());
+
+    assert_eq!(checkpoints.value_at(0), 0);
+    assert_eq!(checkpoints.value_at(9), 0);
+    for i in 1..=20u64 {
+        assert_eq!(checkpoints.value_at(i * 10), i);
+        assert_eq!(checkpoints.value_at(i * 10 + 9), i);
+    }
+    assert_eq!(checkpoints.value_at(u64::MAX), 20);
+}
+
+#[test]
+fn test_checkpoint_clear() {
+    let mut checkpoints = create_checkpoints();
+    checkpoints.record(1, &1);
+    checkpoints.record(2, &2);
+    checkpoints.clear();
+    assert!(checkpoints.is_empty());
+    assert_eq!(checkpoints.value_at(2), 0);
+    assert_eq!(checkpoints.iter().next(), None);
+
+    // older block nonces are accepted again after clearing
+    checkpoints.record(1, &3);
+    assert_eq!(checkpoints.latest(), 3);
+}
@@ -46,3 +46,74 @@ fn test_verify_ed25519_invalid_args() {
     let success = ctx.verify_ed25519_legacy(&pub_bytes, &msg_bytes, &sig_bytes);
     assert!(!success);
 }
+
+#[test]
+fn test_verify_bls_basic() {
+    let public_key: &[u8] = b"b5823f6e564251cc03ce7bad3da83e72576e92795d3500bba1acb30ec9a94dce87bb8aa794d67b2d61d15c33f28f6c0c23ba1dfcbf21e8f8b46286ff871afabac925303ddcaddce6254fcff6d3155797db40b3d3b5865e8fc0bd770b3d79b381";
+    let signature: &[u8] = b"af32a2ddf341c08d1eb7232f05dc34e4454155e676b58c40fddf9a036562ac2c01533d2d557cb49d73aa9d7a89744696";
+
+    let pub_bytes: Vec<u8> = FromHex::from_hex(public_key).unwrap();
+    let sig_bytes: Vec<u8> = FromHex::from_hex(signature).unwrap();
+
+    let ctx = DebugApi::dummy();
+    assert!(ctx.verify_bls_legacy(&pub_bytes, b"message to be signed", &sig_bytes));
+    assert!(!ctx.verify_bls_legacy(&pub_bytes, b"message to be signed!", &sig_bytes));
+    assert!(!ctx.verify_bls_legacy(&pub_bytes[..95], b"message to be signed", &sig_bytes));
+
+    // flipping the parity flag selects the opposite point
+    let mut bad_sig_bytes = sig_bytes;
+    bad_sig_bytes[47] ^= 0x80;
+    assert!(!ctx.verify_bls_legacy(&pub_bytes, b"message to be signed", &bad_sig_bytes));
+}
+
+#[test]
+fn test_verify_secp256k1_basic() {
+    let public_key: &[u8] = b"02a673638cb9587cb68ea08dbef685c6f2d2a751a8b3c6f2a7e9a4999e6e4bfaf5";
+    let signature: &[u8] = b"3045022100fab29dbcb623e7e96f44665f312b89ffdb9e4d2e2f53353236fbbcf57b2f49bf02203d6f9c63df8be43121042970a549a2d3baeca093fd37b726ddcc0924638409f5";
+
+    let pub_bytes: Vec<u8> = FromHex::from_hex(public_key).unwrap();
+    let sig_bytes: Vec<u8> = FromHex::from_hex(signature).unwrap();
+
+    let ctx = DebugApi::dummy();
+    assert!(ctx.verify_secp256k1_legacy(&pub_bytes, b"message to sign", &sig_bytes));
+    assert!(!ctx.verify_secp256k1_legacy(&pub_bytes, b"message to sign!", &sig_bytes));
+    assert!(!ctx.verify_secp256k1_legacy(&pub_bytes, b"message to sign", &sig_bytes[..70]));
+}
+
+#[test]
+fn test_encode_secp256k1_der_signature_high_s() {
+    let r: Vec<u8> = FromHex::from_hex("01").unwrap();
+    let s: Vec<u8> =
+        FromHex::from_hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140")
+            .unwrap();
+
+    let ctx = DebugApi::dummy();
+    let encoded = ctx.encode_secp256k1_der_signature_legacy(&r, &s);
+    assert_eq!(
+        encoded.as_slice(),
+        &[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x01]
+    );
+}
+
+#[test]
+fn test_encode_secp256k1_der_signature_long_length() {
+    let r = vec![0x7fu8; 200];
+    let s: Vec<u8> = FromHex::from_hex("01").unwrap();
+
+    let ctx = DebugApi::dummy();
+    let encoded = ctx.encode_secp256k1_der_signature_legacy(&r, &s);
+    assert_eq!(
+        &encoded.as_slice()[..6],
+        &[0x30, 0x81, 206, 0x02, 0x81, 200]
+    );
+    assert_eq!(&encoded.as_slice()[6..206], r.as_slice());
+    assert_eq!(&encoded.as_slice()[206..], &[0x02, 0x01, 0x01]);
+
+    let r = vec![0x7fu8; 300];
+    let encoded = ctx.encode_secp256k1_der_signature_legacy(&r, &s);
+    assert_eq!(
+        &encoded.as_slice()[..8],
+        &[0x30, 0x82, 0x01, 0x33, 0x02, 0x82, 0x01, 0x2c]
+    );
+    assert_eq!(encoded.len(), 4 + 0x133);
+}
@@ -0,0 +1,161 @@
+use std::{collections::BTreeMap, ops::Bound};
+
+use elrond_wasm::storage::{
+    mappers::{OrderedMapMapper, StorageClearable, StorageMapper},
+    StorageKey,
+};
+use elrond_wasm_debug::DebugApi;
+
+fn create_map() -> OrderedMapMapper<DebugApi, u64, u64> {
+    let _ = DebugApi::dummy();
+    let base_key = StorageKey::new(&b"my_ordered_map"[..]);
+    OrderedMapMapper::new(base_key)
+}
+
+fn check_map(map: &OrderedMapMapper<DebugApi, u64, u64>, expected: &BTreeMap<u64, u64>) {
+    assert_eq!(map.len(), expected.len());
+    let entries: Vec<(u64, u64)> = map.iter().collect();
+    let expected_entries: Vec<(u64, u64)> = expected.iter().map(|(k, v)| (*k, *v)).collect();
+    assert_eq!(entries, expected_entries);
+    assert!(map.check_internal_consistency());
+}
+
+#[test]
+fn test_ordered_map_simple() {
+    let mut map = create_map();
+    assert!(map.is_empty());
+    assert_eq!(map.get(&42), None);
+    assert_eq!(map.insert(42, 142), None);
+    assert_eq!(map.insert(40, 140), None);
+    assert_eq!(map.insert(44, 144), None);
+    assert_eq!(map.len(), 3);
+    assert!(map.contains_key(&40));
+    assert!(!map.contains_key(&41));
+    assert_eq!(map.insert(42, 242), Some(142));
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get(&42), Some(242));
+
+    let keys: Vec<u64> = map.keys().collect();
+    assert_eq!(keys, vec![40, 42, 44]);
+    let values: Vec<u64> = map.values().rev().collect();
+    assert_eq!(values, vec![144, 242, 140]);
+    assert!(map.check_internal_consistency());
+}
+
+#[test]
+fn test_ordered_map_min_max_bounds() {
+    let mut map = create_map();
+    assert_eq!(map.min_key(), None);
+    assert_eq!(map.max_key(), None);
+    assert_eq!(map.lower_bound(&10), None);
+
+    for key in [30, 10, 50, 20, 40] {
+        map.insert(key, key + 100);
+    }
+    assert_eq!(map.min_key(), Some(10));
+    assert_eq!(map.max_key(), Some(50));
+
+    assert_eq!(map.lower_bound(&20), Some(20));
+    assert_eq!(map.upper_bound(&20), Some(30));
+    assert_eq!(map.lower_bound(&21), Some(30));
+    assert_eq!(map.upper_bound(&21), Some(30));
+    assert_eq!(map.lower_bound(&0), Some(10));
+    assert_eq!(map.lower_bound(&51), None);
+    assert_eq!(map.upper_bound(&50), None);
+
+    assert_eq!(map.pop_min(), Some((10, 110)));
+    assert_eq!(map.pop_max(), Some((50, 150)));
+    assert_eq!(map.min_key(), Some(20));
+    assert_eq!(map.max_key(), Some(40));
+    assert!(map.check_internal_consistency());
+}
+
+#[test]
+fn test_ordered_map_range() {
+    let mut map = create_map();
+    for key in 1..=20 {
+        map.insert(key * 10, key);
+    }
+
+    let keys = |entries: Vec<(u64, u64)>| entries.iter().map(|(k, _)| *k).collect::<Vec<u64>>();
+    assert_eq!(keys(map.range(30..60).collect()), vec![30, 40, 50]);
+    assert_eq!(keys(map.range(25..=60).collect()), vec![30, 40, 50, 60]);
+    assert_eq!(keys(map.range(..25).collect()), vec![10, 20]);
+    assert_eq!(keys(map.range(185..).collect()), vec![190, 200]);
+    assert_eq!(keys(map.range(30..60).rev().collect()), vec![50, 40, 30]);
+    assert_eq!(keys(map.range(55..=60).collect()), vec![60]);
+    assert!(map.range(51..59).next().is_none());
+    assert!(map
+        .range((Bound::Included(60), Bound::Excluded(30)))
+        .next()
+        .is_none());
+    assert!(map.range(500..).next().is_none());
+
+    let mut range = map.range(10..=40);
+    assert_eq!(range.next(), Some((10, 1)));
+    assert_eq!(range.next_back(), Some((40, 4)));
+    assert_eq!(range.next_back(), Some((30, 3)));
+    assert_eq!(range.next(), Some((20, 2)));
+    assert_eq!(range.next(), None);
+    assert_eq!(range.next_back(), None);
+}
+
+#[test]
+fn test_ordered_map_sequential() {
+    let mut map = create_map();
+    let mut expected = BTreeMap::new();
+    for key in 0..100 {
+        map.insert(key, key * 2);
+        expected.insert(key, key * 2);
+    }
+    check_map(&map, &expected);
+
+    for key in (0..100).step_by(3) {
+        assert_eq!(map.remove(&key), expected.remove(&key));
+    }
+    check_map(&map, &expected);
+
+    while let Some((key, value)) = map.pop_max() {
+        assert_eq!(expected.pop_last(), Some((key, value)));
+        assert!(map.check_internal_consistency());
+    }
+    check_map(&map, &expected);
+}
+
+#[test]
+fn test_ordered_map_mixed_operations() {
+    let mut map = create_map();
+    let mut expected = BTreeMap::new();
+
+    // deterministic pseudo-random sequence, keys repeat so that updates and removals of missing keys also occur
+    let mut seed = 12345u64;
+    for step in 0..500u64 {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let key = (seed >> 33) % 64;
+        if (seed >> 20) % 3 == 0 {
+            assert_eq!(map.remove(&key), expected.remove(&key));
+        } else {
+            assert_eq!(map.insert(key, step), expected.insert(key, step));
+        }
+        assert!(map.check_internal_consistency());
+    }
+    check_map(&map, &expected);
+}
+
+#[test]
+fn test_ordered_map_clear() {
+    let mut map = create_map();
+    for key in [5, 3, 8, 1, 4] {
+        map.insert(key, key);
+    }
+    map.clear();
+    assert!(map.is_empty());
+    assert_eq!(map.get(&3), None);
+    assert_eq!(map.min_key(), None);
+    assert!(map.check_internal_consistency());
+
+    map.insert(7, 70);
+    check_map(&map, &BTreeMap::from([(7, 70)]));
+}
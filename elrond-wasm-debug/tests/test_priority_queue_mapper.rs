@@ -0,0 +1,76 @@
+use elrond_wasm::storage::{
+    mappers::{PriorityQueueMapper, StorageClearable, StorageMapper},
+    StorageKey,
+};
+use elrond_wasm_debug::DebugApi;
+
+fn create_queue() -> PriorityQueueMapper<DebugApi, u64, u64> {
+    let _ = DebugApi::dummy();
+    let base_key = StorageKey::new(&b"my_priority_queue"[..]);
+    PriorityQueueMapper::new(base_key)
+}
+
+#[test]
+fn test_priority_queue_simple() {
+    let mut queue = create_queue();
+    assert!(queue.is_empty());
+    assert_eq!(queue.peek(), None);
+    assert_eq!(queue.pop(), None);
+
+    queue.push(5, 50);
+    queue.push(9, 90);
+    queue.push(1, 10);
+    queue.push(7, 70);
+    assert_eq!(queue.len(), 4);
+    assert!(queue.check_internal_consistency());
+    assert_eq!(queue.peek_priority(), Some(9));
+    assert_eq!(queue.peek(), Some((9, 90)));
+
+    assert_eq!(queue.pop(), Some((9, 90)));
+    assert_eq!(queue.pop(), Some((7, 70)));
+    assert!(queue.check_internal_consistency());
+    assert_eq!(queue.pop(), Some((5, 50)));
+    assert_eq!(queue.pop(), Some((1, 10)));
+    assert_eq!(queue.pop(), None);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_priority_queue_sorted_output() {
+    let mut queue = create_queue();
+
+    // deterministic pseudo-random priorities, with duplicates
+    let mut seed = 42u64;
+    let mut priorities = Vec::new();
+    for i in 0..200 {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let priority = (seed >> 33) % 50;
+        priorities.push(priority);
+        queue.push(priority, i);
+        assert!(queue.check_internal_consistency());
+    }
+    assert_eq!(queue.iter().count(), 200);
+
+    priorities.sort_unstable_by(|a, b| b.cmp(a));
+    let mut popped = Vec::new();
+    while let Some((priority, _)) = queue.pop() {
+        popped.push(priority);
+        assert!(queue.check_internal_consistency());
+    }
+    assert_eq!(popped, priorities);
+}
+
+#[test]
+fn test_priority_queue_clear() {
+    let mut queue = create_queue();
+    queue.push(3, 30);
+    queue.push(4, 40);
+    queue.clear();
+    assert!(queue.is_empty());
+    assert_eq!(queue.iter().next(), None);
+
+    queue.push(1, 10);
+    assert_eq!(queue.pop(), Some((1, 10)));
+}
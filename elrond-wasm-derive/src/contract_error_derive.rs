@@ -0,0 +1,184 @@
+use super::parse::attributes::extract_doc;
+use proc_macro::TokenStream;
+use quote::quote;
+
+static ERROR_ATTR_NAME: &str = "error";
+static PLACEHOLDER: &str = "{}";
+
+struct ContractErrorVariant {
+    docs: Vec<String>,
+    ident: syn::Ident,
+    code: u32,
+    message: String,
+}
+
+fn parse_error_attribute(variant: &syn::Variant) -> (u32, String) {
+    let mut code = None;
+    let mut message = None;
+    for attr in variant.attrs.iter() {
+        if !attr.path.is_ident(ERROR_ATTR_NAME) {
+            continue;
+        }
+        let meta_list = match attr.parse_meta() {
+            Ok(syn::Meta::List(meta_list)) => meta_list,
+            _ => panic!(
+                "error attribute should be of the form #[error(code = N, message = \"...\")]"
+            ),
+        };
+        for item in meta_list.nested.iter() {
+            match item {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                    if name_value.path.is_ident("code") =>
+                {
+                    if let syn::Lit::Int(lit_int) = &name_value.lit {
+                        code = Some(
+                            lit_int
+                                .base10_parse::<u32>()
+                                .expect("error code must be a u32"),
+                        );
+                    } else {
+                        panic!("error code must be an integer literal");
+                    }
+                },
+                syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                    if name_value.path.is_ident("message") =>
+                {
+                    if let syn::Lit::Str(lit_str) = &name_value.lit {
+                        message = Some(lit_str.value());
+                    } else {
+                        panic!("error message must be a string literal");
+                    }
+                },
+                _ => panic!(
+                    "unknown error attribute argument, expected `code = N` or `message = \"...\"`"
+                ),
+            }
+        }
+    }
+
+    let variant_ident = &variant.ident;
+    (
+        code.unwrap_or_else(|| panic!("missing error code for variant {variant_ident}")),
+        message.unwrap_or_else(|| panic!("missing error message for variant {variant_ident}")),
+    )
+}
+
+/// Only `{}` placeholders are supported, the arguments are always displayed as with `{}` in `require!`.
+fn placeholder_count(message: &str) -> usize {
+    let without_placeholders = message.replace(PLACEHOLDER, "");
+    assert!(
+        !without_placeholders.contains('{') && !without_placeholders.contains('}'),
+        "only `{{}}` placeholders are allowed in error messages, found: {message}"
+    );
+    message.matches(PLACEHOLDER).count()
+}
+
+fn contract_error_variants(data_enum: &syn::DataEnum) -> Vec<ContractErrorVariant> {
+    let mut variants = Vec::<ContractErrorVariant>::new();
+    for variant in data_enum.variants.iter() {
+        assert!(
+            variant.fields.is_empty(),
+            "contract error variants cannot have fields, use message placeholders instead"
+        );
+        let (code, message) = parse_error_attribute(variant);
+        if let Some(existing) = variants.iter().find(|existing| existing.code == code) {
+            panic!(
+                "duplicate error code {code} for variants {} and {}",
+                existing.ident, variant.ident
+            );
+        }
+        variants.push(ContractErrorVariant {
+            docs: extract_doc(variant.attrs.as_slice()),
+            ident: variant.ident.clone(),
+            code,
+            message,
+        });
+    }
+    variants
+}
+
+pub fn contract_error_derive(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    assert!(
+        ast.generics.params.is_empty(),
+        "ContractError cannot be derived for generic types"
+    );
+    let variants = if let syn::Data::Enum(data_enum) = &ast.data {
+        contract_error_variants(data_enum)
+    } else {
+        panic!("ContractError can only be derived for enums")
+    };
+    assert!(
+        !variants.is_empty(),
+        "ContractError cannot be derived for enums without variants"
+    );
+
+    let code_arms = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let code = variant.code;
+        quote! { #name::#ident => #code, }
+    });
+    let message_arms = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let message = &variant.message;
+        quote! { #name::#ident => #message, }
+    });
+    let placeholder_count_arms = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let count = placeholder_count(&variant.message);
+        quote! { #name::#ident => #count, }
+    });
+    let error_abi_snippets = variants.iter().map(|variant| {
+        let docs = &variant.docs;
+        let code = variant.code;
+        let ident_str = variant.ident.to_string();
+        let message = &variant.message;
+        quote! {
+            error_abis.push(elrond_wasm::abi::ErrorAbi {
+                docs: &[ #(#docs),* ],
+                code: #code,
+                name: #ident_str,
+                message: #message,
+            });
+        }
+    });
+
+    let contract_error_impl = quote! {
+        impl elrond_wasm::types::ContractError for #name {
+            fn error_code(&self) -> u32 {
+                match self {
+                    #(#code_arms)*
+                }
+            }
+
+            fn error_message_template(&self) -> &'static str {
+                match self {
+                    #(#message_arms)*
+                }
+            }
+
+            fn error_abis() -> elrond_wasm::types::heap::Vec<elrond_wasm::abi::ErrorAbi> {
+                let mut error_abis = elrond_wasm::types::heap::Vec::new();
+                #(#error_abi_snippets)*
+                error_abis
+            }
+        }
+
+        impl #name {
+            /// Allows `require!` and `sc_panic!` to check the number of arguments at compile time.
+            #[doc(hidden)]
+            pub const fn contract_error_placeholder_count(&self) -> usize {
+                match self {
+                    #(#placeholder_count_arms)*
+                }
+            }
+        }
+
+        impl<M: elrond_wasm::api::ManagedTypeApi> elrond_wasm::contract_base::IntoSignalError<M> for #name {
+            fn signal_error_with_message(self) -> ! {
+                elrond_wasm::types::ContractErrorMessageBuilder::<M>::new(&self).signal_error()
+            }
+        }
+    };
+    contract_error_impl.into()
+}
@@ -1,6 +1,7 @@
 use super::util::*;
 use crate::model::{
-    AutoImpl, ContractTrait, EndpointMutabilityMetadata, Method, MethodImpl, PublicRole,
+    AutoImpl, ContractTrait, EndpointMutabilityMetadata, Method, MethodArgument, MethodImpl,
+    PublicRole,
 };
 
 fn generate_endpoint_snippet(
@@ -8,6 +9,7 @@ fn generate_endpoint_snippet(
     endpoint_name: &str,
     only_owner: bool,
     only_admin: bool,
+    only_role: Option<&str>,
     mutability: EndpointMutabilityMetadata,
 ) -> proc_macro2::TokenStream {
     let endpoint_docs = &m.docs;
@@ -47,6 +49,10 @@ fn generate_endpoint_snippet(
     };
 
     let label_names = &m.label_names;
+    let only_role_tokens = match only_role {
+        Some(role_name) => quote! { core::option::Option::Some(#role_name) },
+        None => quote! { core::option::Option::None },
+    };
     let mutability_tokens = mutability.to_tokens();
 
     quote! {
@@ -56,6 +62,7 @@ fn generate_endpoint_snippet(
             rust_method_name: #rust_method_name,
             only_owner: #only_owner,
             only_admin: #only_admin,
+            only_role: #only_role_tokens,
             mutability: #mutability_tokens,
             payable_in_tokens: &[ #(#payable_in_tokens),* ],
             inputs: elrond_wasm::types::heap::Vec::new(),
@@ -78,6 +85,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     "init",
                     false,
                     false,
+                    None,
                     EndpointMutabilityMetadata::Mutable,
                 );
                 Some(quote! {
@@ -91,6 +99,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     &endpoint_metadata.public_name.to_string(),
                     endpoint_metadata.only_owner,
                     endpoint_metadata.only_admin,
+                    endpoint_metadata.only_role.as_deref(),
                     endpoint_metadata.mutability.clone(),
                 );
                 Some(quote! {
@@ -104,6 +113,7 @@ fn generate_endpoint_snippets(contract: &ContractTrait) -> Vec<proc_macro2::Toke
                     &callback_metadata.callback_name.to_string(),
                     false,
                     false,
+                    None,
                     EndpointMutabilityMetadata::Mutable,
                 );
                 Some(quote! {
@@ -162,6 +172,158 @@ fn generate_event_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenSt
         .collect()
 }
 
+/// Splits a storage mapper type, e.g. `MapMapper<Self::Api, K, V>`, into the mapper name and its type arguments.
+/// The API type argument is left out.
+fn storage_mapper_name_and_type_args(ty: &syn::Type) -> (String, Vec<syn::Type>) {
+    let last_segment = if let syn::Type::Path(type_path) = ty {
+        type_path
+            .path
+            .segments
+            .last()
+            .expect("storage mapper type path cannot be empty")
+    } else {
+        panic!("storage mapper should be a named type")
+    };
+    let api_type_str = quote! { Self::Api }.to_string();
+    let mut type_args = Vec::new();
+    if let syn::PathArguments::AngleBracketed(angle_args) = &last_segment.arguments {
+        for arg in angle_args.args.iter() {
+            if let syn::GenericArgument::Type(arg_type) = arg {
+                if quote! { #arg_type }.to_string() != api_type_str {
+                    type_args.push(arg_type.clone());
+                }
+            }
+        }
+    }
+    (last_segment.ident.to_string(), type_args)
+}
+
+/// Describes a storage key or value type, via its `TypeAbi` implementation if it has one.
+fn storage_type_snippet(
+    ty: &syn::Type,
+    add_type_name: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let mut ty = ty.clone();
+    clear_all_type_lifetimes(&mut ty);
+    quote! {
+        {
+            use elrond_wasm::abi::{StorageTypeAbi as _, StorageTypeOpaque as _};
+            let probe = elrond_wasm::abi::StorageTypeProbe::<#ty>::new();
+            let type_name = (&probe).storage_type_name();
+            #add_type_name
+            (&probe).provide_storage_type_descriptions(&mut contract_abi);
+        }
+    }
+}
+
+fn generate_storage_snippet(
+    m: &Method,
+    identifier: &str,
+    mapper: &str,
+    key_args: &[MethodArgument],
+    value_types: &[syn::Type],
+) -> proc_macro2::TokenStream {
+    let storage_docs = &m.docs;
+    let key_arg_snippets: Vec<proc_macro2::TokenStream> = key_args
+        .iter()
+        .map(|arg| {
+            let arg_name = &arg.pat;
+            let arg_name_str = quote! { #arg_name }.to_string();
+            storage_type_snippet(
+                &arg.ty,
+                quote! { storage_abi.add_key_arg(#arg_name_str, type_name); },
+            )
+        })
+        .collect();
+    let value_type_snippets: Vec<proc_macro2::TokenStream> = value_types
+        .iter()
+        .map(|value_type| {
+            storage_type_snippet(
+                value_type,
+                quote! { storage_abi.add_value_type(type_name); },
+            )
+        })
+        .collect();
+
+    quote! {
+        let mut storage_abi = elrond_wasm::abi::StorageAbi{
+            docs: &[ #(#storage_docs),* ],
+            key: #identifier,
+            mapper: #mapper,
+            key_args: elrond_wasm::types::heap::Vec::new(),
+            value_types: elrond_wasm::types::heap::Vec::new(),
+        };
+        #(#key_arg_snippets)*
+        #(#value_type_snippets)*
+    }
+}
+
+fn return_type(m: &Method) -> syn::Type {
+    match &m.return_type {
+        syn::ReturnType::Default => panic!("storage getter or mapper should return some value"),
+        syn::ReturnType::Type(_, ty) => ty.as_ref().clone(),
+    }
+}
+
+/// Storage getters, setters and mappers. Only the first declaration of each key is listed,
+/// since a getter and a setter often share the same key.
+fn generate_storage_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    let mut declared_keys = Vec::<&str>::new();
+    let mut snippets = Vec::new();
+    for m in contract.methods.iter() {
+        let (identifier, mapper, key_args, value_types) = match &m.implementation {
+            MethodImpl::Generated(AutoImpl::StorageGetter { identifier }) => (
+                identifier,
+                "SingleValueMapper".to_string(),
+                m.method_args.as_slice(),
+                vec![return_type(m)],
+            ),
+            MethodImpl::Generated(AutoImpl::StorageSetter { identifier }) => {
+                let (value_arg, key_args) = m
+                    .method_args
+                    .split_last()
+                    .expect("setter must have at least one argument, for the value");
+                (
+                    identifier,
+                    "SingleValueMapper".to_string(),
+                    key_args,
+                    vec![value_arg.ty.clone()],
+                )
+            },
+            MethodImpl::Generated(AutoImpl::StorageMapper { identifier }) => {
+                let (mapper, type_args) = storage_mapper_name_and_type_args(&return_type(m));
+                (identifier, mapper, m.method_args.as_slice(), type_args)
+            },
+            _ => continue,
+        };
+        if declared_keys.contains(&identifier.as_str()) {
+            continue;
+        }
+        declared_keys.push(identifier);
+
+        let storage_def =
+            generate_storage_snippet(m, identifier, &mapper, key_args, value_types.as_slice());
+        snippets.push(quote! {
+            #storage_def
+            contract_abi.storage.push(storage_abi);
+        });
+    }
+    snippets
+}
+
+fn generate_error_snippets(contract: &ContractTrait) -> Vec<proc_macro2::TokenStream> {
+    contract
+        .trait_attributes
+        .errors
+        .iter()
+        .map(|error_type| {
+            quote! {
+                contract_abi.add_errors::<#error_type>();
+            }
+        })
+        .collect()
+}
+
 fn has_callback(contract: &ContractTrait) -> bool {
     contract.methods.iter().any(|m| {
         matches!(
@@ -192,6 +354,8 @@ fn generate_abi_method_body(
     let contract_name = &contract.trait_name.to_string();
     let endpoint_snippets = generate_endpoint_snippets(contract);
     let event_snippets = generate_event_snippets(contract);
+    let storage_snippets = generate_storage_snippets(contract);
+    let error_snippets = generate_error_snippets(contract);
     let has_callbacks = has_callback(contract);
     let supertrait_snippets: Vec<proc_macro2::TokenStream> = if is_contract_main {
         generate_supertrait_snippets(contract)
@@ -215,11 +379,15 @@ fn generate_abi_method_body(
             endpoints: elrond_wasm::types::heap::Vec::new(),
             promise_callbacks: elrond_wasm::types::heap::Vec::new(),
             events: elrond_wasm::types::heap::Vec::new(),
+            storage: elrond_wasm::types::heap::Vec::new(),
+            errors: elrond_wasm::types::heap::Vec::new(),
             has_callback: #has_callbacks,
             type_descriptions: <elrond_wasm::abi::TypeDescriptionContainerImpl as elrond_wasm::abi::TypeDescriptionContainer>::new(),
         };
         #(#endpoint_snippets)*
         #(#event_snippets)*
+        #(#storage_snippets)*
+        #(#error_snippets)*
         #(#supertrait_snippets)*
         contract_abi
     }
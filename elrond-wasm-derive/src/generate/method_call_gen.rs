@@ -66,6 +66,7 @@ pub fn generate_endpoint_call_method_body(m: &Method) -> proc_macro2::TokenStrea
     let payable_snippet = generate_payable_snippet(m);
     let only_owner_snippet = generate_only_owner_snippet(m);
     let only_admin_snippet = generate_only_admin_snippet(m);
+    let only_role_snippet = generate_only_role_snippet(m);
     let only_user_account_snippet = generate_only_user_account_snippet(m);
     let arg_load = generate_call_method_arg_load(m);
 
@@ -77,6 +78,7 @@ pub fn generate_endpoint_call_method_body(m: &Method) -> proc_macro2::TokenStrea
         #payable_snippet
         #only_owner_snippet
         #only_admin_snippet
+        #only_role_snippet
         #only_user_account_snippet
         #arg_load
         #body_with_result
@@ -9,6 +9,7 @@ extern crate syn;
 #[macro_use]
 extern crate quote;
 
+mod contract_error_derive;
 mod contract_impl;
 mod format;
 mod generate;
@@ -60,6 +61,13 @@ pub fn managed_vec_item_derive(input: proc_macro::TokenStream) -> proc_macro::To
     managed_vec_item_derive::managed_vec_item_derive(&ast)
 }
 
+#[proc_macro_derive(ContractError, attributes(error))]
+pub fn contract_error_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse(input).unwrap();
+
+    contract_error_derive::contract_error_derive(&ast)
+}
+
 #[proc_macro]
 pub fn format_receiver_args(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     format::format_receiver_args_macro(input)
@@ -1,3 +1,4 @@
+use crate::parse::enum_discriminants;
 use proc_macro::TokenStream;
 use quote::quote;
 
@@ -111,15 +112,14 @@ fn enum_derive(data_enum: &syn::DataEnum, ast: &syn::DeriveInput) -> TokenStream
 
     let mut reader_match_arms = Vec::<proc_macro2::TokenStream>::new();
     let mut writer_match_arms = Vec::<proc_macro2::TokenStream>::new();
-    for (variant_index, variant) in data_enum.variants.iter().enumerate() {
-        let variant_index_u8 = variant_index as u8;
+    for (variant, discriminant) in data_enum.variants.iter().zip(enum_discriminants(data_enum)) {
         let variant_ident = &variant.ident;
         assert!(variant.fields.is_empty(), "Only fieldless enums supported");
         reader_match_arms.push(quote! {
-            #variant_index_u8 => #name::#variant_ident,
+            #discriminant => #name::#variant_ident,
         });
         writer_match_arms.push(quote! {
-            #name::#variant_ident => #variant_index_u8,
+            #name::#variant_ident => #discriminant,
         });
     }
 
@@ -59,4 +59,7 @@ pub struct TraitProperties {
     pub only_owner: bool,
     pub only_admin: bool,
     pub only_user_account: bool,
+
+    /// The `ContractError` types listed via `#[errors(...)]`, they appear in the ABI.
+    pub errors: Vec<syn::Path>,
 }
@@ -12,6 +12,7 @@ pub struct EndpointMetadata {
     pub only_owner: bool,
     pub only_admin: bool,
     pub only_user_account: bool,
+    pub only_role: Option<String>,
     pub mutability: EndpointMutabilityMetadata,
 }
 
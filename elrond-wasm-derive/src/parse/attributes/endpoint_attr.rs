@@ -16,6 +16,11 @@ pub fn is_only_user_account(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_ONLY_USER_ACCOUNT)
 }
 
+/// Yields the role name, for `#[only_role("ROLE_NAME")]`.
+pub fn only_role_name(attr: &syn::Attribute) -> Option<String> {
+    is_attr_one_string_arg(attr, ATTR_ONLY_ROLE)
+}
+
 pub fn is_callback_raw(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, ATTR_CALLBACK_RAW_DECL)
 }
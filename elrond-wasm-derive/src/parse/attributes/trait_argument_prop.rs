@@ -7,3 +7,15 @@ pub fn is_only_owner_prop(attr: &syn::Attribute) -> bool {
 pub fn is_only_admin_prop(attr: &syn::Attribute) -> bool {
     is_attribute_with_no_args(attr, PROP_ADMIN_OWNER)
 }
+
+/// The error types listed in `#[errors(ErrorType1, ErrorType2)]`.
+pub fn errors_prop_types(attr: &syn::Attribute) -> Option<Vec<syn::Path>> {
+    if !attr.path.is_ident(PROP_ERRORS) {
+        return None;
+    }
+
+    let error_types = attr
+        .parse_args_with(syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated)
+        .expect("errors attribute should list the error types, e.g. #[errors(MyError)]");
+    Some(error_types.into_iter().collect())
+}
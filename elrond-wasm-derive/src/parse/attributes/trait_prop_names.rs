@@ -1,2 +1,3 @@
 pub(super) static PROP_ONLY_OWNER: &str = "only_owner";
 pub(super) static PROP_ADMIN_OWNER: &str = "only_admin";
+pub(super) static PROP_ERRORS: &str = "errors";
@@ -6,8 +6,8 @@ use crate::model::{
 use super::{
     attributes::{
         is_callback_raw, is_init, is_only_admin, is_only_owner, is_only_user_account,
-        CallbackAttribute, EndpointAttribute, ExternalViewAttribute, LabelAttribute,
-        OutputNameAttribute, PromisesCallbackAttribute, ViewAttribute,
+        only_role_name, CallbackAttribute, EndpointAttribute, ExternalViewAttribute,
+        LabelAttribute, OutputNameAttribute, PromisesCallbackAttribute, ViewAttribute,
     },
     MethodAttributesPass1,
 };
@@ -67,6 +67,22 @@ pub fn process_only_user_account_attribute(
     is_only_user_account
 }
 
+pub fn process_only_role_attribute(
+    attr: &syn::Attribute,
+    pass_1_data: &mut MethodAttributesPass1,
+) -> bool {
+    if let Some(role_name) = only_role_name(attr) {
+        assert!(
+            pass_1_data.only_role.is_none(),
+            "only one `#[only_role]` attribute allowed per endpoint"
+        );
+        pass_1_data.only_role = Some(role_name);
+        true
+    } else {
+        false
+    }
+}
+
 pub fn process_endpoint_attribute(
     attr: &syn::Attribute,
     pass_1_data: &MethodAttributesPass1,
@@ -85,6 +101,7 @@ pub fn process_endpoint_attribute(
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
+                only_role: pass_1_data.only_role.clone(),
                 mutability: EndpointMutabilityMetadata::Mutable,
             });
         })
@@ -109,6 +126,7 @@ pub fn process_view_attribute(
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
+                only_role: pass_1_data.only_role.clone(),
                 mutability: EndpointMutabilityMetadata::Readonly,
             });
         })
@@ -133,6 +151,7 @@ pub fn process_external_view_attribute(
                 only_owner: pass_1_data.only_owner,
                 only_admin: pass_1_data.only_admin,
                 only_user_account: pass_1_data.only_user_account,
+                only_role: pass_1_data.only_role.clone(),
                 mutability: EndpointMutabilityMetadata::Readonly,
             });
         })
@@ -0,0 +1,43 @@
+/// The discriminant of each variant, as serialized.
+/// Follows the Rust rules: explicit discriminants (`Variant = 5`) are used as they are,
+/// other variants get the discriminant of the previous variant plus one, starting from 0.
+///
+/// Mirrors `enum_discriminants` in `elrond-codec-derive`, which does the actual encoding.
+/// Proc-macro crates cannot export functions, so the two copies must be kept in sync;
+/// the abi-tester tests check that the ABI discriminants match the encoded ones.
+pub fn enum_discriminants(data_enum: &syn::DataEnum) -> Vec<u8> {
+    let mut discriminants = Vec::<u8>::new();
+    let mut next_discriminant = 0u64;
+    for variant in data_enum.variants.iter() {
+        let discriminant = match &variant.discriminant {
+            Some((_, expr)) => parse_discriminant(expr),
+            None => next_discriminant,
+        };
+        assert!(
+            discriminant <= u8::MAX as u64,
+            "enum discriminants must fit in a u8, found {discriminant} for variant {}",
+            variant.ident
+        );
+        assert!(
+            !discriminants.contains(&(discriminant as u8)),
+            "duplicate enum discriminant {discriminant} for variant {}",
+            variant.ident
+        );
+        discriminants.push(discriminant as u8);
+        next_discriminant = discriminant + 1;
+    }
+    discriminants
+}
+
+fn parse_discriminant(expr: &syn::Expr) -> u64 {
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(lit_int),
+        ..
+    }) = expr
+    {
+        if let Ok(value) = lit_int.base10_parse::<u64>() {
+            return value;
+        }
+    }
+    panic!("only integer literals are supported as enum discriminants")
+}
@@ -11,8 +11,9 @@ use super::{
     extract_method_args, process_callback_attribute, process_callback_raw_attribute,
     process_endpoint_attribute, process_external_view_attribute, process_init_attribute,
     process_label_names_attribute, process_only_admin_attribute, process_only_owner_attribute,
-    process_only_user_account_attribute, process_output_names_attribute, process_payable_attribute,
-    process_promises_callback_attribute, process_view_attribute,
+    process_only_role_attribute, process_only_user_account_attribute,
+    process_output_names_attribute, process_payable_attribute, process_promises_callback_attribute,
+    process_view_attribute,
 };
 pub struct MethodAttributesPass1 {
     pub method_name: String,
@@ -20,6 +21,7 @@ pub struct MethodAttributesPass1 {
     pub only_owner: bool,
     pub only_admin: bool,
     pub only_user_account: bool,
+    pub only_role: Option<String>,
 }
 
 pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperties) -> Method {
@@ -37,6 +39,7 @@ pub fn process_method(m: &syn::TraitItemMethod, trait_attributes: &TraitProperti
         only_owner: trait_attributes.only_owner,
         only_admin: trait_attributes.only_admin,
         only_user_account: trait_attributes.only_user_account,
+        only_role: None,
     };
     let mut first_pass_unprocessed_attributes = Vec::new();
 
@@ -91,6 +94,7 @@ fn process_attribute_first_pass(
         || process_only_owner_attribute(attr, first_pass_data)
         || process_only_admin_attribute(attr, first_pass_data)
         || process_only_user_account_attribute(attr, first_pass_data)
+        || process_only_role_attribute(attr, first_pass_data)
 }
 
 fn process_attributes_second_pass(
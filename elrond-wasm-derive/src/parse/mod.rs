@@ -4,6 +4,7 @@ mod argument_parse;
 mod auto_impl_parse;
 mod contract_trait_parse;
 mod endpoint_parse;
+mod enum_discriminant_parse;
 mod method_parse;
 mod parse_util;
 mod payable_parse;
@@ -14,6 +15,7 @@ mod trait_argument_parse;
 pub use argument_parse::*;
 pub use contract_trait_parse::*;
 pub use endpoint_parse::*;
+pub use enum_discriminant_parse::*;
 pub use method_parse::*;
 pub use payable_parse::*;
 pub use split_path::*;
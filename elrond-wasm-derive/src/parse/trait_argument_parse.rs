@@ -20,6 +20,7 @@ fn process_trait_attribute(
 ) -> bool {
     process_only_owner_argument(attr, trait_arg_metadata)
         || process_only_admin_argument(attr, trait_arg_metadata)
+        || process_errors_argument(attr, trait_arg_metadata)
 }
 
 fn process_only_owner_argument(attr: &syn::Attribute, arg_metadata: &mut TraitProperties) -> bool {
@@ -37,3 +38,12 @@ fn process_only_admin_argument(attr: &syn::Attribute, arg_metadata: &mut TraitPr
     }
     has_attr
 }
+
+fn process_errors_argument(attr: &syn::Attribute, arg_metadata: &mut TraitProperties) -> bool {
+    if let Some(error_types) = errors_prop_types(attr) {
+        arg_metadata.errors.extend(error_types);
+        true
+    } else {
+        false
+    }
+}
@@ -102,6 +102,7 @@ fn add_storage_mapper(
 }
 
 fn add_storage_mappers(substitutions: &mut SubstitutionsMap) {
+    add_storage_mapper_single_generic_arg(substitutions, &quote!(BitmapMapper));
     add_storage_mapper_single_generic_arg(substitutions, &quote!(FungibleTokenMapper));
     add_storage_mapper_single_generic_arg(substitutions, &quote!(NonFungibleTokenMapper));
     add_storage_mapper_single_generic_arg(substitutions, &quote!(TokenAttributesMapper));
@@ -109,9 +110,12 @@ fn add_storage_mappers(substitutions: &mut SubstitutionsMap) {
     add_storage_mapper_single_generic_arg(substitutions, &quote!(UserMapper));
 
     add_storage_mapper(substitutions, &quote!(BiDiMapper));
+    add_storage_mapper(substitutions, &quote!(CheckpointMapper));
     add_storage_mapper(substitutions, &quote!(LinkedListMapper));
     add_storage_mapper(substitutions, &quote!(MapMapper));
     add_storage_mapper(substitutions, &quote!(MapStorageMapper));
+    add_storage_mapper(substitutions, &quote!(OrderedMapMapper));
+    add_storage_mapper(substitutions, &quote!(PriorityQueueMapper));
     add_storage_mapper(substitutions, &quote!(SetMapper));
     add_storage_mapper(substitutions, &quote!(UnorderedSetMapper));
     add_storage_mapper(substitutions, &quote!(SingleValueMapper));
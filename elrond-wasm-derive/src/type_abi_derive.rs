@@ -1,4 +1,4 @@
-use super::parse::attributes::extract_doc;
+use super::parse::{attributes::extract_doc, enum_discriminants};
 use proc_macro::TokenStream;
 use quote::quote;
 
@@ -10,28 +10,76 @@ fn field_snippet(index: usize, field: &syn::Field) -> proc_macro2::TokenStream {
         index.to_string()
     };
     let field_ty = &field.ty;
+    let since = option_u8_tokens(codec_attribute_u8(field.attrs.as_slice(), "since"));
     quote! {
         field_descriptions.push(elrond_wasm::abi::StructFieldDescription {
             docs: &[ #(#field_docs),* ],
             name: #field_name_str,
             field_type: <#field_ty>::type_name(),
+            since: #since,
         });
         <#field_ty>::provide_type_descriptions(accumulator);
     }
 }
 
+/// The items of the `#[codec(...)]` attributes.
+/// They are validated by the codec derives, so anything unexpected is simply ignored here.
+fn codec_attribute_items(attrs: &[syn::Attribute]) -> Vec<syn::NestedMeta> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("codec"))
+        .flat_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::List(meta_list)) => meta_list.nested.into_iter().collect(),
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+/// Fields marked `#[codec(skip)]` are never serialized, so they are left out of the ABI.
+fn is_codec_skipped(field: &syn::Field) -> bool {
+    codec_attribute_items(field.attrs.as_slice())
+        .iter()
+        .any(|item| matches!(item, syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("skip")))
+}
+
+/// The value of a `#[codec(name = N)]` attribute, i.e. the struct `version` or the field `since`.
+fn codec_attribute_u8(attrs: &[syn::Attribute], name: &str) -> Option<u8> {
+    codec_attribute_items(attrs)
+        .iter()
+        .find_map(|item| match item {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))
+                if name_value.path.is_ident(name) =>
+            {
+                match &name_value.lit {
+                    syn::Lit::Int(lit_int) => lit_int.base10_parse::<u8>().ok(),
+                    _ => None,
+                }
+            },
+            _ => None,
+        })
+}
+
+fn option_u8_tokens(value: Option<u8>) -> proc_macro2::TokenStream {
+    match value {
+        Some(value) => quote! { core::option::Option::Some(#value) },
+        None => quote! { core::option::Option::None },
+    }
+}
+
 fn fields_snippets(fields: &syn::Fields) -> Vec<proc_macro2::TokenStream> {
     match fields {
         syn::Fields::Named(fields_named) => fields_named
             .named
             .iter()
             .enumerate()
+            .filter(|(_, field)| !is_codec_skipped(field))
             .map(|(index, field)| field_snippet(index, field))
             .collect(),
         syn::Fields::Unnamed(fields_unnamed) => fields_unnamed
             .unnamed
             .iter()
             .enumerate()
+            .filter(|(_, field)| !is_codec_skipped(field))
             .map(|(index, field)| field_snippet(index, field))
             .collect(),
         syn::Fields::Unit => Vec::new(),
@@ -43,6 +91,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
     let type_description_impl = match &ast.data {
         syn::Data::Struct(data_struct) => {
             let struct_field_snippets = fields_snippets(&data_struct.fields);
+            let version = option_u8_tokens(codec_attribute_u8(ast.attrs.as_slice(), "version"));
             quote! {
                 fn provide_type_descriptions<TDC: elrond_wasm::abi::TypeDescriptionContainer>(accumulator: &mut TDC) {
                     let type_name = Self::type_name();
@@ -56,6 +105,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                                 docs: &[ #(#type_docs),* ],
                                 name: type_name,
                                 contents: elrond_wasm::abi::TypeContents::Struct(field_descriptions),
+                                version: #version,
                             },
                         );
                     }
@@ -66,8 +116,9 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
             let enum_variant_snippets: Vec<proc_macro2::TokenStream> = data_enum
                 .variants
                 .iter()
-                .enumerate()
-                .map(|(variant_index, variant)| {
+                .zip(enum_discriminants(data_enum))
+                .map(|(variant, discriminant)| {
+                    let discriminant = discriminant as usize;
                     let variant_docs = extract_doc(variant.attrs.as_slice());
                     let variant_name_str = variant.ident.to_string();
                     let variant_field_snippets = fields_snippets(&variant.fields);
@@ -76,7 +127,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                         #(#variant_field_snippets)*
                         variant_descriptions.push(elrond_wasm::abi::EnumVariantDescription {
                             docs: &[ #(#variant_docs),* ],
-                            discriminant: #variant_index,
+                            discriminant: #discriminant,
                             name: #variant_name_str,
                             fields: field_descriptions,
                         });
@@ -96,6 +147,7 @@ pub fn type_abi_derive(ast: &syn::DeriveInput) -> TokenStream {
                                 docs: &[ #(#type_docs),* ],
                                 name: type_name,
                                 contents: elrond_wasm::abi::TypeContents::Enum(variant_descriptions),
+                                version: core::option::Option::None,
                             },
                         );
                     }
@@ -8,6 +8,8 @@ elrond_wasm::imports!();
 /// - after a voting period, either putting the action in a queue (if it reached quorum), or canceling
 ///
 /// Voting can only be done by depositing a certain token, decided upon first time setup.  
+/// Alternatively, voting power can be computed from snapshots taken at proposal creation,
+/// in which case voting does not lock any tokens. See `init_snapshot_voting_power`.  
 ///
 /// The module provides the following configurable parameters:  
 /// - `quorum` - the minimum number of (`votes` minus `downvotes`) at the end of voting period  
@@ -59,6 +61,18 @@ pub trait GovernanceConfigurablePropertiesModule {
         );
     }
 
+    /// Switches voting from deposited tokens to snapshot-based voting power.
+    ///
+    /// Voters no longer pay tokens into `vote`. Instead, their voting power is the one recorded
+    /// through `update_voting_power` as of the end of the block before the one in which the proposal was created,
+    /// so tokens moved to another account afterwards cannot be used to vote twice.
+    /// The main contract is responsible for calling `update_voting_power` every time a user's balance changes.
+    ///
+    /// Like the governance token, this is meant to be set once, at first time setup.
+    fn init_snapshot_voting_power(&self) {
+        self.snapshot_voting_power().set(true);
+    }
+
     // endpoints - these can only be called by the SC itself.
     // i.e. only by proposing and executing an action with the SC as dest and the respective func name
 
@@ -154,6 +168,10 @@ pub trait GovernanceConfigurablePropertiesModule {
     #[storage_mapper("governance:governanceTokenId")]
     fn governance_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
 
+    #[view(isSnapshotVotingPower)]
+    #[storage_mapper("governance:snapshotVotingPower")]
+    fn snapshot_voting_power(&self) -> SingleValueMapper<bool>;
+
     // storage - configurable parameters
 
     #[view(getQuorum)]
@@ -178,12 +178,14 @@ pub trait GovernanceModule:
 
     /// Vote on a proposal by depositing any amount of governance tokens
     /// These tokens will be locked until the proposal is executed or cancelled.
+    ///
+    /// With snapshot voting power, no tokens are deposited.
+    /// The caller votes with their voting power from the end of the block before the one in which the proposal was created.
     #[payable("*")]
     #[endpoint]
     fn vote(&self, proposal_id: usize, vote: VoteType) {
         self.require_caller_not_self();
 
-        let payment = self.require_payment_token_governance_token();
         self.require_valid_proposal_id(proposal_id);
         require!(
             self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
@@ -191,33 +193,34 @@ pub trait GovernanceModule:
         );
 
         let voter = self.blockchain().get_caller();
+        let voting_power = self.get_vote_voting_power(&voter, proposal_id);
         let new_user = self.user_voted_proposals(&voter).insert(proposal_id);
         require!(new_user, ALREADY_VOTED_ERR_MSG);
 
         match vote {
             VoteType::UpVote => {
                 self.proposal_votes(proposal_id).update(|total_votes| {
-                    total_votes.up_votes += &payment.amount.clone();
+                    total_votes.up_votes += &voting_power;
                 });
-                self.up_vote_cast_event(&voter, proposal_id, &payment.amount);
+                self.up_vote_cast_event(&voter, proposal_id, &voting_power);
             },
             VoteType::DownVote => {
                 self.proposal_votes(proposal_id).update(|total_votes| {
-                    total_votes.down_votes += &payment.amount.clone();
+                    total_votes.down_votes += &voting_power;
                 });
-                self.down_vote_cast_event(&voter, proposal_id, &payment.amount);
+                self.down_vote_cast_event(&voter, proposal_id, &voting_power);
             },
             VoteType::DownVetoVote => {
                 self.proposal_votes(proposal_id).update(|total_votes| {
-                    total_votes.down_veto_votes += &payment.amount.clone();
+                    total_votes.down_veto_votes += &voting_power;
                 });
-                self.down_veto_vote_cast_event(&voter, proposal_id, &payment.amount);
+                self.down_veto_vote_cast_event(&voter, proposal_id, &voting_power);
             },
             VoteType::AbstainVote => {
                 self.proposal_votes(proposal_id).update(|total_votes| {
-                    total_votes.abstain_votes += &payment.amount.clone();
+                    total_votes.abstain_votes += &voting_power;
                 });
-                self.abstain_vote_cast_event(&voter, proposal_id, &payment.amount);
+                self.abstain_vote_cast_event(&voter, proposal_id, &voting_power);
             },
         }
     }
@@ -413,8 +416,43 @@ pub trait GovernanceModule:
         actions_as_multiarg
     }
 
+    #[view(getVotingPowerAt)]
+    fn get_voting_power_at(&self, user: ManagedAddress, block_nonce: u64) -> BigUint {
+        self.voting_power(&user).value_at(block_nonce)
+    }
+
+    /// Records the voting power of a user, starting with the current block.
+    /// Only relevant with snapshot voting power, see `init_snapshot_voting_power`.
+    fn update_voting_power(&self, user: &ManagedAddress, voting_power: &BigUint) {
+        let current_block = self.blockchain().get_block_nonce();
+        self.voting_power(user).record(current_block, voting_power);
+    }
+
     // private
 
+    fn get_vote_voting_power(&self, voter: &ManagedAddress, proposal_id: ProposalId) -> BigUint {
+        if !self.snapshot_voting_power().get() {
+            return self.require_payment_token_governance_token().amount;
+        }
+
+        require!(
+            self.call_value().egld_value() == 0
+                && self.call_value().all_esdt_transfers().is_empty(),
+            "No payment accepted when voting with snapshot voting power"
+        );
+
+        // updates recorded in the proposal block itself might have come after the proposal,
+        // so the snapshot is the voting power at the end of the previous block
+        let proposal_block = self.proposal_start_block(proposal_id).get();
+        let voting_power = match proposal_block.checked_sub(1) {
+            Some(snapshot_block) => self.voting_power(voter).value_at(snapshot_block),
+            None => BigUint::zero(),
+        };
+        require!(voting_power > 0, "No voting power at proposal creation");
+
+        voting_power
+    }
+
     fn refund_payments(&self, proposal_id: ProposalId) {
         let payments = self.proposals().get(proposal_id).fees;
 
@@ -514,6 +552,10 @@ pub trait GovernanceModule:
         proposal_id: ProposalId,
     ) -> SingleValueMapper<ProposalVotes<Self::Api>>;
 
+    /// Voting power history, only used with snapshot voting power.
+    #[storage_mapper("governance:votingPower")]
+    fn voting_power(&self, user: &ManagedAddress) -> CheckpointMapper<BigUint>;
+
     #[view(getTotalVotes)]
     #[storage_mapper("governance:totalVotes")]
     fn total_votes(&self, proposal_id: usize) -> SingleValueMapper<BigUint>;
@@ -8,10 +8,16 @@ pub mod dns;
 pub mod esdt;
 pub mod features;
 pub mod governance;
+pub mod merkle_airdrop;
 pub mod ongoing_operation;
 pub mod only_admin;
 pub mod pause;
+pub mod rate_limit;
+pub mod roles;
+pub mod royalties;
 pub mod staking;
+pub mod timelock;
 pub mod token_merge;
 pub mod transfer_role_proxy;
 pub mod users;
+pub mod vesting;
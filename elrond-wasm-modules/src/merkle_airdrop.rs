@@ -0,0 +1,92 @@
+elrond_wasm::imports!();
+
+/// Standard smart contract module for airdrops based on Merkle proofs.
+///
+/// Instead of storing all the recipients, only the root of a Merkle tree is stored.
+/// Each leaf is the encoded `(index: u64, address, amount: BigUint)` triple, hashed with keccak256,
+/// as done by `MerkleTreeHasher` on-chain and by `MerkleTree` in `elrond-wasm-debug`.
+///
+/// Recipients claim by providing their index, amount and the proof of their leaf.
+/// Claimed indexes are tracked in a bitmap, so each leaf can only be claimed once.
+///
+/// The contract must hold enough tokens to cover all the claims.
+#[elrond_wasm::module]
+pub trait MerkleAirdropModule {
+    fn init_merkle_airdrop_module(
+        &self,
+        airdrop_token: &EgldOrEsdtTokenIdentifier,
+        merkle_root: &MerkleHash<Self::Api>,
+    ) {
+        require!(
+            self.airdrop_merkle_root().is_empty(),
+            "Airdrop already set up"
+        );
+
+        self.airdrop_token().set(airdrop_token);
+        self.airdrop_merkle_root().set(merkle_root);
+    }
+
+    #[endpoint(claimAirdrop)]
+    fn claim_airdrop(
+        &self,
+        index: u64,
+        amount: BigUint,
+        proof: MultiValueEncoded<MerkleHash<Self::Api>>,
+    ) {
+        let merkle_root_mapper = self.airdrop_merkle_root();
+        require!(!merkle_root_mapper.is_empty(), "Airdrop not set up");
+
+        let caller = self.blockchain().get_caller();
+        let leaf_hash = self.airdrop_leaf_hash(index, &caller, &amount);
+        require!(
+            MerkleTreeHasher::keccak256().verify_proof(
+                &merkle_root_mapper.get(),
+                &leaf_hash,
+                &proof.to_vec()
+            ),
+            "Invalid Merkle proof"
+        );
+        require!(self.airdrop_claimed().set(index), "Airdrop already claimed");
+
+        let airdrop_token = self.airdrop_token().get();
+        self.send().direct(&caller, &airdrop_token, 0, &amount);
+
+        self.airdrop_claimed_event(index, &caller, &amount);
+    }
+
+    #[view(isAirdropClaimed)]
+    fn is_airdrop_claimed(&self, index: u64) -> bool {
+        self.airdrop_claimed().get(index)
+    }
+
+    fn airdrop_leaf_hash(
+        &self,
+        index: u64,
+        address: &ManagedAddress,
+        amount: &BigUint,
+    ) -> MerkleHash<Self::Api> {
+        let leaf = self
+            .serializer()
+            .top_encode_to_managed_buffer(&(index, address, amount));
+        MerkleTreeHasher::keccak256().hash_leaf(&leaf)
+    }
+
+    #[view(getAirdropToken)]
+    #[storage_mapper("merkle_airdrop:token")]
+    fn airdrop_token(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getAirdropMerkleRoot)]
+    #[storage_mapper("merkle_airdrop:merkleRoot")]
+    fn airdrop_merkle_root(&self) -> SingleValueMapper<MerkleHash<Self::Api>>;
+
+    #[storage_mapper("merkle_airdrop:claimed")]
+    fn airdrop_claimed(&self) -> BitmapMapper;
+
+    #[event("airdropClaimed")]
+    fn airdrop_claimed_event(
+        &self,
+        #[indexed] index: u64,
+        #[indexed] address: &ManagedAddress,
+        amount: &BigUint,
+    );
+}
@@ -0,0 +1,339 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// The clock used to measure rate limiting windows.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug,
+)]
+pub enum RateLimitWindowUnit {
+    Rounds,
+    Epochs,
+    Seconds,
+}
+
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug,
+)]
+pub enum RateLimitAlgorithm {
+    /// At most `max_amount` in each window. Windows are aligned, i.e. start at multiples of the window length.
+    FixedWindow,
+
+    /// A bucket holding at most `max_amount`, refilled continuously at a rate of `max_amount` per window.
+    /// Allows bursts of up to `max_amount`, while limiting the sustained rate.
+    TokenBucket,
+}
+
+/// A limit can apply to all callers together, or to each caller separately.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug,
+)]
+pub enum RateLimitScope {
+    Global,
+    PerAddress,
+}
+
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug,
+)]
+pub struct RateLimitConfig<M: ManagedTypeApi> {
+    pub algorithm: RateLimitAlgorithm,
+    pub window_unit: RateLimitWindowUnit,
+    pub window_length: u64,
+    pub max_amount: BigUint<M>,
+
+    /// Changes every time the limit is set, so that buckets filled under an older limit are discarded.
+    pub config_id: u64,
+}
+
+/// The usage recorded for a key, globally or for one address.
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, PartialEq, Eq, Debug)]
+pub struct RateLimitBucket<M: ManagedTypeApi> {
+    pub config_id: u64,
+
+    /// For fixed windows, the start of the current window. For token buckets, the time of the last refill.
+    pub last_update: u64,
+
+    /// For fixed windows, the amount used in the current window. For token buckets, the amount still available.
+    pub amount: BigUint<M>,
+
+    /// For token buckets, the progress towards the next token, in units of 1 / `window_length` tokens.
+    /// Keeps the refill exact when `window_length` is not a multiple of `max_amount`.
+    pub refill_remainder: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> RateLimitConfig<M> {
+    /// The bucket as it would be at the given time, before any new usage.
+    pub fn refreshed_bucket(
+        &self,
+        stored_bucket: Option<RateLimitBucket<M>>,
+        now: u64,
+    ) -> RateLimitBucket<M> {
+        let stored_bucket = stored_bucket.filter(|bucket| bucket.config_id == self.config_id);
+        match self.algorithm {
+            RateLimitAlgorithm::FixedWindow => {
+                let window_start = now - now % self.window_length;
+                match stored_bucket {
+                    Some(bucket) if bucket.last_update == window_start => bucket,
+                    _ => RateLimitBucket {
+                        config_id: self.config_id,
+                        last_update: window_start,
+                        amount: BigUint::zero(),
+                        refill_remainder: BigUint::zero(),
+                    },
+                }
+            },
+            RateLimitAlgorithm::TokenBucket => match stored_bucket {
+                Some(mut bucket) => {
+                    let elapsed = now.saturating_sub(bucket.last_update);
+                    let accumulated = &self.max_amount * elapsed + &bucket.refill_remainder;
+                    bucket.amount += &accumulated / self.window_length;
+                    bucket.refill_remainder = accumulated % self.window_length;
+                    bucket.last_update = now;
+                    if bucket.amount >= self.max_amount {
+                        // a full bucket does not store time for later
+                        bucket.amount = self.max_amount.clone();
+                        bucket.refill_remainder = BigUint::zero();
+                    }
+
+                    bucket
+                },
+                None => RateLimitBucket {
+                    config_id: self.config_id,
+                    last_update: now,
+                    amount: self.max_amount.clone(),
+                    refill_remainder: BigUint::zero(),
+                },
+            },
+        }
+    }
+
+    pub fn remaining_quota(&self, bucket: &RateLimitBucket<M>) -> BigUint<M> {
+        match self.algorithm {
+            RateLimitAlgorithm::FixedWindow => {
+                if bucket.amount >= self.max_amount {
+                    BigUint::zero()
+                } else {
+                    &self.max_amount - &bucket.amount
+                }
+            },
+            RateLimitAlgorithm::TokenBucket => bucket.amount.clone(),
+        }
+    }
+
+    /// Records the usage. The amount must be within the remaining quota.
+    pub fn consume(&self, bucket: &mut RateLimitBucket<M>, amount: &BigUint<M>) {
+        match self.algorithm {
+            RateLimitAlgorithm::FixedWindow => bucket.amount += amount,
+            RateLimitAlgorithm::TokenBucket => bucket.amount -= amount,
+        }
+    }
+}
+
+/// Standard smart contract module for limiting how much can be done in a given time window.
+///
+/// Limits are identified by a key, usually one per endpoint or per kind of operation.
+/// For each key, the owner can set a global limit, shared by all callers, and a limit applying to each caller separately.
+/// Both are checked when present.
+///
+/// A limit is expressed as a maximum amount per window, where the window is measured in rounds, epochs or seconds.
+/// The amount can be a number of calls, a number of tokens, or anything else the contract wants to limit.
+/// Two algorithms are available: fixed windows and token buckets.
+///
+/// Contracts use it by calling `require_within_quota` (or `require_within_call_quota`) at the start of their endpoints.
+/// Keys without any limit set are not restricted.
+#[elrond_wasm::module]
+pub trait RateLimitModule {
+    /// Signals an error if the amount exceeds the remaining quota for the key, globally or for the caller.
+    /// Otherwise, records the usage.
+    fn require_within_quota(&self, key: &'static [u8], amount: &BigUint) {
+        let key_buffer = ManagedBuffer::from(key);
+        let caller = self.blockchain().get_caller();
+
+        let global_ok = self.try_consume_quota(
+            &key_buffer,
+            RateLimitScope::Global,
+            self.rate_limit_global_bucket(&key_buffer),
+            amount,
+        );
+        require!(global_ok, "Global rate limit exceeded for {}", key);
+
+        let address_ok = self.try_consume_quota(
+            &key_buffer,
+            RateLimitScope::PerAddress,
+            self.rate_limit_address_bucket(&key_buffer, &caller),
+            amount,
+        );
+        require!(address_ok, "Address rate limit exceeded for {}", key);
+    }
+
+    /// Counts one call towards the quota for the key.
+    fn require_within_call_quota(&self, key: &'static [u8]) {
+        self.require_within_quota(key, &BigUint::from(1u32));
+    }
+
+    #[only_owner]
+    #[endpoint(setRateLimit)]
+    fn set_rate_limit(
+        &self,
+        key: ManagedBuffer,
+        scope: RateLimitScope,
+        algorithm: RateLimitAlgorithm,
+        window_unit: RateLimitWindowUnit,
+        window_length: u64,
+        max_amount: BigUint,
+    ) {
+        require!(window_length > 0, "Window length must be positive");
+
+        let config_id = self.rate_limit_last_config_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let config = RateLimitConfig {
+            algorithm,
+            window_unit,
+            window_length,
+            max_amount,
+            config_id,
+        };
+        self.rate_limit_config(&key, scope).set(&config);
+        if scope == RateLimitScope::Global {
+            self.rate_limit_global_bucket(&key).clear();
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeRateLimit)]
+    fn remove_rate_limit(&self, key: ManagedBuffer, scope: RateLimitScope) {
+        self.rate_limit_config(&key, scope).clear();
+        if scope == RateLimitScope::Global {
+            self.rate_limit_global_bucket(&key).clear();
+        }
+    }
+
+    #[view(getRateLimit)]
+    fn get_rate_limit(
+        &self,
+        key: ManagedBuffer,
+        scope: RateLimitScope,
+    ) -> OptionalValue<RateLimitConfig<Self::Api>> {
+        let config_mapper = self.rate_limit_config(&key, scope);
+        if config_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(config_mapper.get())
+        }
+    }
+
+    /// Returns nothing if no global limit is set for the key.
+    #[view(getRemainingGlobalQuota)]
+    fn get_remaining_global_quota(&self, key: ManagedBuffer) -> OptionalValue<BigUint> {
+        self.get_remaining_quota(
+            &key,
+            RateLimitScope::Global,
+            self.rate_limit_global_bucket(&key),
+        )
+    }
+
+    /// Returns nothing if no per-address limit is set for the key.
+    #[view(getRemainingAddressQuota)]
+    fn get_remaining_address_quota(
+        &self,
+        key: ManagedBuffer,
+        address: ManagedAddress,
+    ) -> OptionalValue<BigUint> {
+        self.get_remaining_quota(
+            &key,
+            RateLimitScope::PerAddress,
+            self.rate_limit_address_bucket(&key, &address),
+        )
+    }
+
+    fn get_remaining_quota(
+        &self,
+        key: &ManagedBuffer,
+        scope: RateLimitScope,
+        bucket_mapper: SingleValueMapper<RateLimitBucket<Self::Api>>,
+    ) -> OptionalValue<BigUint> {
+        let config_mapper = self.rate_limit_config(key, scope);
+        if config_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        let config = config_mapper.get();
+        let bucket = config.refreshed_bucket(
+            self.load_rate_limit_bucket(&bucket_mapper),
+            self.rate_limit_now(config.window_unit),
+        );
+        OptionalValue::Some(config.remaining_quota(&bucket))
+    }
+
+    /// Returns false if the amount is over the quota, in which case nothing is recorded.
+    fn try_consume_quota(
+        &self,
+        key: &ManagedBuffer,
+        scope: RateLimitScope,
+        bucket_mapper: SingleValueMapper<RateLimitBucket<Self::Api>>,
+        amount: &BigUint,
+    ) -> bool {
+        let config_mapper = self.rate_limit_config(key, scope);
+        if config_mapper.is_empty() {
+            return true;
+        }
+
+        let config = config_mapper.get();
+        let mut bucket = config.refreshed_bucket(
+            self.load_rate_limit_bucket(&bucket_mapper),
+            self.rate_limit_now(config.window_unit),
+        );
+        if amount > &config.remaining_quota(&bucket) {
+            return false;
+        }
+
+        config.consume(&mut bucket, amount);
+        bucket_mapper.set(&bucket);
+
+        true
+    }
+
+    fn load_rate_limit_bucket(
+        &self,
+        bucket_mapper: &SingleValueMapper<RateLimitBucket<Self::Api>>,
+    ) -> Option<RateLimitBucket<Self::Api>> {
+        if bucket_mapper.is_empty() {
+            None
+        } else {
+            Some(bucket_mapper.get())
+        }
+    }
+
+    fn rate_limit_now(&self, window_unit: RateLimitWindowUnit) -> u64 {
+        match window_unit {
+            RateLimitWindowUnit::Rounds => self.blockchain().get_block_round(),
+            RateLimitWindowUnit::Epochs => self.blockchain().get_block_epoch(),
+            RateLimitWindowUnit::Seconds => self.blockchain().get_block_timestamp(),
+        }
+    }
+
+    #[storage_mapper("rateLimit:lastConfigId")]
+    fn rate_limit_last_config_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("rateLimit:config")]
+    fn rate_limit_config(
+        &self,
+        key: &ManagedBuffer,
+        scope: RateLimitScope,
+    ) -> SingleValueMapper<RateLimitConfig<Self::Api>>;
+
+    #[storage_mapper("rateLimit:globalBucket")]
+    fn rate_limit_global_bucket(
+        &self,
+        key: &ManagedBuffer,
+    ) -> SingleValueMapper<RateLimitBucket<Self::Api>>;
+
+    #[storage_mapper("rateLimit:addressBucket")]
+    fn rate_limit_address_bucket(
+        &self,
+        key: &ManagedBuffer,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<RateLimitBucket<Self::Api>>;
+}
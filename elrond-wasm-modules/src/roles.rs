@@ -0,0 +1,117 @@
+elrond_wasm::imports!();
+
+/// Standard smart contract module for role-based access control.
+///
+/// Roles are identified by name, e.g. `MINTER`, `PAUSER`.
+/// Endpoints can be restricted to the members of a role with `#[only_role("MINTER")]`.
+///
+/// Each role can have an admin role, whose members can grant and revoke it.
+/// The owner can always grant and revoke any role, and is the only one who can change the admin roles.
+///
+/// It offers:
+/// * endpoints to grant, revoke and renounce roles
+/// * an endpoint where the owner can set the admin role of a role
+/// * views for the role members and the role admins
+/// * events for all role changes
+///
+#[elrond_wasm::module]
+pub trait RolesModule {
+    #[view(hasRole)]
+    fn has_role(&self, role: ManagedBuffer, address: ManagedAddress) -> bool {
+        self.role_members(&role).contains(&address)
+    }
+
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: ManagedBuffer, address: ManagedAddress) {
+        self.require_caller_can_manage_role(&role);
+        if self.role_members(&role).insert(address.clone()) {
+            self.role_granted_event(&role, &address, &self.blockchain().get_caller());
+        }
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: ManagedBuffer, address: ManagedAddress) {
+        self.require_caller_can_manage_role(&role);
+        if self.role_members(&role).swap_remove(&address) {
+            self.role_revoked_event(&role, &address, &self.blockchain().get_caller());
+        }
+    }
+
+    /// Members can give up their own roles, e.g. if their key is compromised.
+    #[endpoint(renounceRole)]
+    fn renounce_role(&self, role: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.role_members(&role).swap_remove(&caller),
+            "Caller does not have the role"
+        );
+        self.role_revoked_event(&role, &caller, &caller);
+    }
+
+    /// An empty admin role means that only the owner can manage the role.
+    #[only_owner]
+    #[endpoint(setRoleAdmin)]
+    fn set_role_admin(&self, role: ManagedBuffer, admin_role: ManagedBuffer) {
+        let previous_admin_role = self.role_admin(&role).get();
+        self.role_admin(&role).set(&admin_role);
+        self.role_admin_changed_event(&role, &previous_admin_role, &admin_role);
+    }
+
+    #[view(getRoleMembers)]
+    fn get_role_members(&self, role: ManagedBuffer) -> MultiValueEncoded<ManagedAddress> {
+        self.role_members(&role).iter().collect()
+    }
+
+    #[view(getRoleAdmin)]
+    #[storage_mapper("roles_module:role_admin")]
+    fn role_admin(&self, role: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("roles_module:role_members")]
+    fn role_members(&self, role: &ManagedBuffer) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Called by the code generated for `#[only_role("ROLE_NAME")]` endpoints.
+    fn require_caller_has_role(&self, role: &[u8]) {
+        require!(
+            self.role_members(&ManagedBuffer::new_from_bytes(role))
+                .contains(&self.blockchain().get_caller()),
+            "Endpoint can only be called by role members"
+        );
+    }
+
+    fn require_caller_can_manage_role(&self, role: &ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+        if caller == self.blockchain().get_owner_address() {
+            return;
+        }
+
+        let admin_role = self.role_admin(role).get();
+        require!(
+            !admin_role.is_empty() && self.role_members(&admin_role).contains(&caller),
+            "Caller is not allowed to manage the role"
+        );
+    }
+
+    #[event("roleGranted")]
+    fn role_granted_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("roleRevoked")]
+    fn role_revoked_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] sender: &ManagedAddress,
+    );
+
+    #[event("roleAdminChanged")]
+    fn role_admin_changed_event(
+        &self,
+        #[indexed] role: &ManagedBuffer,
+        #[indexed] previous_admin_role: &ManagedBuffer,
+        #[indexed] new_admin_role: &ManagedBuffer,
+    );
+}
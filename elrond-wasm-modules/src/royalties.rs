@@ -0,0 +1,133 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Royalties are expressed in basis points, i.e. 10_000 means 100%.
+pub const ROYALTIES_MAX: u64 = 10_000;
+
+pub static INVALID_ROYALTIES_ERR_MSG: &[u8] = b"Invalid royalties";
+pub static NO_ROYALTY_SHARES_ERR_MSG: &[u8] = b"No royalty shares";
+
+/// A payment amount, split into the part owed to the creator(s) and what is left for the seller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoyaltiesSplit<M: ManagedTypeApi> {
+    pub royalties_amount: BigUint<M>,
+    pub remainder: BigUint<M>,
+}
+
+/// Splits a payment amount, according to the given royalties, in basis points.
+pub fn split_royalties<M: ManagedTypeApi>(
+    payment_amount: &BigUint<M>,
+    royalties: &BigUint<M>,
+) -> RoyaltiesSplit<M> {
+    if royalties > &ROYALTIES_MAX {
+        M::error_api_impl().signal_error(INVALID_ROYALTIES_ERR_MSG);
+    }
+
+    let royalties_amount = payment_amount * royalties / ROYALTIES_MAX;
+    let remainder = payment_amount - &royalties_amount;
+    RoyaltiesSplit {
+        royalties_amount,
+        remainder,
+    }
+}
+
+/// The weight of a creator when royalties are shared between several creators.
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    ManagedVecItem,
+    TypeAbi,
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub struct CreatorRoyaltyShare<M: ManagedTypeApi> {
+    pub creator: ManagedAddress<M>,
+    pub share: BigUint<M>,
+}
+
+pub type CreatorRoyaltyShares<M> = ManagedVec<M, CreatorRoyaltyShare<M>>;
+
+/// Standard smart contract module with helpers for paying NFT/SFT royalties, typically upon a sale.
+///
+/// It offers:
+/// * paying the royalties of a token to its creator, as described by its `EsdtTokenData`
+/// * paying royalties to several creators, proportionally to their shares,
+/// as recorded for instance by the `token_merge` module when merging tokens from different creators
+///
+/// All methods return the amount left for the seller, after paying the royalties.
+#[elrond_wasm::module]
+pub trait RoyaltiesModule {
+    fn split_royalties_for_token(
+        &self,
+        token_data: &EsdtTokenData<Self::Api>,
+        payment_amount: &BigUint,
+    ) -> RoyaltiesSplit<Self::Api> {
+        split_royalties(payment_amount, &token_data.royalties)
+    }
+
+    /// Sends the royalties to the creator of the token, and returns the remainder.
+    fn send_royalties(
+        &self,
+        token_data: &EsdtTokenData<Self::Api>,
+        payment: &EgldOrEsdtTokenPayment<Self::Api>,
+    ) -> BigUint {
+        let split = self.split_royalties_for_token(token_data, &payment.amount);
+        if split.royalties_amount > 0 {
+            self.send().direct(
+                &token_data.creator,
+                &payment.token_identifier,
+                payment.token_nonce,
+                &split.royalties_amount,
+            );
+        }
+
+        split.remainder
+    }
+
+    /// Splits the royalties between several creators, proportionally to their shares, and returns the remainder.
+    /// Rounding leftovers go to the last creator, so that the full royalties amount is always paid out.
+    fn send_royalties_by_shares(
+        &self,
+        royalties: &BigUint,
+        creator_shares: &CreatorRoyaltyShares<Self::Api>,
+        payment: &EgldOrEsdtTokenPayment<Self::Api>,
+    ) -> BigUint {
+        let split = split_royalties(&payment.amount, royalties);
+        if split.royalties_amount == 0 {
+            return split.remainder;
+        }
+
+        let mut total_shares = BigUint::zero();
+        for creator_share in creator_shares.iter() {
+            total_shares += &creator_share.share;
+        }
+        require!(total_shares > 0, NO_ROYALTY_SHARES_ERR_MSG);
+
+        let last_index = creator_shares.len() - 1;
+        let mut amount_left = split.royalties_amount.clone();
+        for (index, creator_share) in creator_shares.iter().enumerate() {
+            let creator_amount = if index == last_index {
+                amount_left.clone()
+            } else {
+                &split.royalties_amount * &creator_share.share / &total_shares
+            };
+            if creator_amount == 0 {
+                continue;
+            }
+
+            amount_left -= &creator_amount;
+            self.send().direct(
+                &creator_share.creator,
+                &payment.token_identifier,
+                payment.token_nonce,
+                &creator_amount,
+            );
+        }
+
+        split.remainder
+    }
+}
@@ -0,0 +1,260 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+use crate::roles;
+
+// The role names used by the `#[only_role]` endpoints below, which only accept literals.
+// Kept in sync with them by `timelock_module_role_constants_test`.
+pub const TIMELOCK_PROPOSER_ROLE: &[u8] = b"TIMELOCK_PROPOSER";
+pub const TIMELOCK_EXECUTOR_ROLE: &[u8] = b"TIMELOCK_EXECUTOR";
+pub const TIMELOCK_CANCELLER_ROLE: &[u8] = b"TIMELOCK_CANCELLER";
+
+pub type TimelockOperationId = u64;
+
+/// A call to be performed by the contract once the delay passes.
+/// Payments are made from the contract's own balance at execution time.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone)]
+pub struct TimelockCall<M: ManagedTypeApi> {
+    pub to: ManagedAddress<M>,
+    pub egld_amount: BigUint<M>,
+    pub esdt_payments: ManagedVec<M, EsdtTokenPayment<M>>,
+    pub endpoint_name: ManagedBuffer<M>,
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+    pub gas_limit: u64,
+}
+
+/// One or more calls, executed together, in order.
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct TimelockOperation<M: ManagedTypeApi> {
+    pub calls: ManagedVec<M, TimelockCall<M>>,
+    pub ready_timestamp: u64,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Eq, Debug)]
+pub enum TimelockOperationStatus {
+    /// Never scheduled, or already executed or cancelled.
+    None,
+    Waiting,
+    Ready,
+}
+
+/// Standard smart contract module that puts arbitrary calls behind a delay.
+///
+/// Operations are scheduled by members of the `TIMELOCK_PROPOSER` role, with a delay of at least the minimum delay.
+/// Once the delay has passed, members of the `TIMELOCK_EXECUTOR` role can execute them.
+/// Until then, members of the `TIMELOCK_CANCELLER` role can cancel them.
+/// Roles are managed through the `RolesModule`.
+///
+/// An operation is a batch of calls, each with a destination, an endpoint, arguments, an EGLD or ESDT payment and a gas limit.
+/// The calls are launched as transfer-execute calls, in order, from the contract's own balance.
+///
+/// The minimum delay can only be changed by the contract itself, i.e. through a timelocked call to `updateTimelockMinDelay`.
+///
+/// Every stage emits an event, so that pending admin actions can be monitored off-chain.
+#[elrond_wasm::module]
+pub trait TimelockModule: roles::RolesModule {
+    fn init_timelock_module(&self, min_delay_seconds: u64) {
+        self.timelock_min_delay().set(min_delay_seconds);
+    }
+
+    /// Schedules a single call. Returns the ID of the new operation.
+    #[only_role("TIMELOCK_PROPOSER")]
+    #[endpoint(scheduleCall)]
+    fn schedule_call(
+        &self,
+        delay_seconds: u64,
+        call: TimelockCall<Self::Api>,
+    ) -> TimelockOperationId {
+        self.schedule_operation(delay_seconds, ManagedVec::from_single_item(call))
+    }
+
+    /// Schedules several calls, to be executed together, in order. Returns the ID of the new operation.
+    #[only_role("TIMELOCK_PROPOSER")]
+    #[endpoint(scheduleBatch)]
+    fn schedule_batch(
+        &self,
+        delay_seconds: u64,
+        calls: MultiValueEncoded<TimelockCall<Self::Api>>,
+    ) -> TimelockOperationId {
+        self.schedule_operation(delay_seconds, calls.to_vec())
+    }
+
+    #[only_role("TIMELOCK_EXECUTOR")]
+    #[endpoint(executeOperation)]
+    fn execute_operation(&self, operation_id: TimelockOperationId) {
+        require!(
+            self.get_timelock_operation_status(operation_id) == TimelockOperationStatus::Ready,
+            "Operation is not ready"
+        );
+
+        let operation = self.timelock_operation(operation_id).take();
+        let mut total_gas_needed = 0u64;
+        for call in operation.calls.iter() {
+            total_gas_needed = total_gas_needed
+                .checked_add(call.gas_limit)
+                .unwrap_or_else(|| sc_panic!("Total gas limit of the calls overflows"));
+        }
+        require!(
+            self.blockchain().get_gas_left() > total_gas_needed,
+            "Not enough gas to execute all calls"
+        );
+
+        for (index, call) in operation.calls.into_iter().enumerate() {
+            self.timelock_call_executed_event(operation_id, index, &call.to, &call.endpoint_name);
+
+            let mut contract_call = self
+                .send()
+                .contract_call::<()>(call.to, call.endpoint_name)
+                .with_gas_limit(call.gas_limit);
+            contract_call = if call.esdt_payments.is_empty() {
+                contract_call.with_egld_transfer(call.egld_amount)
+            } else {
+                contract_call.with_multi_token_transfer(call.esdt_payments)
+            };
+            for arg in &call.arguments {
+                contract_call.push_arg_managed_buffer(arg);
+            }
+
+            contract_call.transfer_execute();
+        }
+
+        self.timelock_operation_executed_event(operation_id, &self.blockchain().get_caller());
+    }
+
+    #[only_role("TIMELOCK_CANCELLER")]
+    #[endpoint(cancelOperation)]
+    fn cancel_operation(&self, operation_id: TimelockOperationId) {
+        require!(
+            self.get_timelock_operation_status(operation_id) != TimelockOperationStatus::None,
+            "Operation does not exist"
+        );
+
+        self.timelock_operation(operation_id).clear();
+        self.timelock_operation_cancelled_event(operation_id, &self.blockchain().get_caller());
+    }
+
+    #[endpoint(updateTimelockMinDelay)]
+    fn update_timelock_min_delay(&self, new_min_delay_seconds: u64) {
+        require!(
+            self.blockchain().get_caller() == self.blockchain().get_sc_address(),
+            "Only the SC itself may call this function"
+        );
+
+        let old_min_delay_seconds = self.timelock_min_delay().replace(new_min_delay_seconds);
+        self.timelock_min_delay_changed_event(old_min_delay_seconds, new_min_delay_seconds);
+    }
+
+    #[view(getTimelockOperationStatus)]
+    fn get_timelock_operation_status(
+        &self,
+        operation_id: TimelockOperationId,
+    ) -> TimelockOperationStatus {
+        let operation_mapper = self.timelock_operation(operation_id);
+        if operation_mapper.is_empty() {
+            return TimelockOperationStatus::None;
+        }
+
+        let ready_timestamp = operation_mapper.get().ready_timestamp;
+        if self.blockchain().get_block_timestamp() < ready_timestamp {
+            TimelockOperationStatus::Waiting
+        } else {
+            TimelockOperationStatus::Ready
+        }
+    }
+
+    fn schedule_operation(
+        &self,
+        delay_seconds: u64,
+        calls: ManagedVec<TimelockCall<Self::Api>>,
+    ) -> TimelockOperationId {
+        require!(!calls.is_empty(), "No calls to schedule");
+        require!(
+            delay_seconds >= self.timelock_min_delay().get(),
+            "Delay is lower than the minimum delay"
+        );
+        for call in calls.iter() {
+            require!(
+                call.egld_amount == 0 || call.esdt_payments.is_empty(),
+                "Cannot transfer both EGLD and ESDT in the same call"
+            );
+        }
+
+        let ready_timestamp = self
+            .blockchain()
+            .get_block_timestamp()
+            .checked_add(delay_seconds)
+            .unwrap_or_else(|| sc_panic!("Delay is too large"));
+
+        let operation_id = self.timelock_last_operation_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let operation = TimelockOperation {
+            calls,
+            ready_timestamp,
+        };
+        self.timelock_operation(operation_id).set(&operation);
+
+        self.timelock_operation_scheduled_event(
+            operation_id,
+            ready_timestamp,
+            &self.blockchain().get_caller(),
+            &operation.calls,
+        );
+
+        operation_id
+    }
+
+    #[view(getTimelockMinDelay)]
+    #[storage_mapper("timelock:minDelay")]
+    fn timelock_min_delay(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("timelock:lastOperationId")]
+    fn timelock_last_operation_id(&self) -> SingleValueMapper<TimelockOperationId>;
+
+    #[view(getTimelockOperation)]
+    #[storage_mapper("timelock:operation")]
+    fn timelock_operation(
+        &self,
+        operation_id: TimelockOperationId,
+    ) -> SingleValueMapper<TimelockOperation<Self::Api>>;
+
+    #[event("timelockOperationScheduled")]
+    fn timelock_operation_scheduled_event(
+        &self,
+        #[indexed] operation_id: TimelockOperationId,
+        #[indexed] ready_timestamp: u64,
+        #[indexed] proposer: &ManagedAddress,
+        calls: &ManagedVec<TimelockCall<Self::Api>>,
+    );
+
+    #[event("timelockCallExecuted")]
+    fn timelock_call_executed_event(
+        &self,
+        #[indexed] operation_id: TimelockOperationId,
+        #[indexed] call_index: usize,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] endpoint_name: &ManagedBuffer,
+    );
+
+    #[event("timelockOperationExecuted")]
+    fn timelock_operation_executed_event(
+        &self,
+        #[indexed] operation_id: TimelockOperationId,
+        #[indexed] executor: &ManagedAddress,
+    );
+
+    #[event("timelockOperationCancelled")]
+    fn timelock_operation_cancelled_event(
+        &self,
+        #[indexed] operation_id: TimelockOperationId,
+        #[indexed] canceller: &ManagedAddress,
+    );
+
+    #[event("timelockMinDelayChanged")]
+    fn timelock_min_delay_changed_event(
+        &self,
+        #[indexed] old_min_delay_seconds: u64,
+        #[indexed] new_min_delay_seconds: u64,
+    );
+}
@@ -4,6 +4,8 @@ use core::marker::PhantomData;
 
 use elrond_wasm::elrond_codec::Empty;
 
+use crate::royalties::CreatorRoyaltyShares;
+
 use super::merged_token_instances::MergedTokenInstances;
 
 pub trait AllMergeScTraits = super::merged_token_setup::MergedTokenSetupModule
@@ -20,6 +22,12 @@ pub trait MergedTokenAttributesCreator {
         merged_token_id: &TokenIdentifier<<Self::ScType as ContractBase>::Api>,
         merged_token_raw_attributes: &MergedTokenInstances<<Self::ScType as ContractBase>::Api>,
     ) -> Self::AttributesType;
+
+    /// By default, all merged parts must have the same creator.
+    /// Attribute creators that record the royalty shares of each creator can lift this restriction.
+    fn allows_mixed_creators(&self) -> bool {
+        false
+    }
 }
 
 pub struct DefaultMergedAttributesWrapper<Sc: AllMergeScTraits> {
@@ -63,3 +71,53 @@ where
         Empty
     }
 }
+
+/// Allows merging parts from different creators.
+/// The merged token attributes hold the royalty share of each creator,
+/// which can be used to split royalties on sale, through `RoyaltiesModule::send_royalties_by_shares`.
+pub struct MixedCreatorsMergedAttributesWrapper<Sc: AllMergeScTraits> {
+    _phantom: PhantomData<Sc>,
+}
+
+impl<Sc> MixedCreatorsMergedAttributesWrapper<Sc>
+where
+    Sc: AllMergeScTraits,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<Sc> Default for MixedCreatorsMergedAttributesWrapper<Sc>
+where
+    Sc: AllMergeScTraits,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Sc> MergedTokenAttributesCreator for MixedCreatorsMergedAttributesWrapper<Sc>
+where
+    Sc: AllMergeScTraits,
+{
+    type ScType = Sc;
+    type AttributesType = CreatorRoyaltyShares<<Self::ScType as ContractBase>::Api>;
+
+    fn get_merged_token_attributes(
+        &self,
+        sc: &Self::ScType,
+        _merged_token_id: &TokenIdentifier<<Self::ScType as ContractBase>::Api>,
+        merged_token_raw_attributes: &MergedTokenInstances<<Self::ScType as ContractBase>::Api>,
+    ) -> Self::AttributesType {
+        let all_token_data = sc.collect_token_data(merged_token_raw_attributes);
+        sc.get_creator_royalty_shares(merged_token_raw_attributes, &all_token_data)
+    }
+
+    fn allows_mixed_creators(&self) -> bool {
+        true
+    }
+}
@@ -1,5 +1,7 @@
 elrond_wasm::imports!();
 
+use crate::royalties::{CreatorRoyaltyShare, CreatorRoyaltyShares};
+
 use super::{
     custom_merged_token_attributes::MergedTokenAttributesCreator,
     merged_token_instances::{MergedTokenInstances, MAX_MERGED_TOKENS},
@@ -53,7 +55,9 @@ pub trait MergedTokenSetupModule {
         let empty_buffer = ManagedBuffer::new();
 
         let all_token_data = self.collect_token_data(merged_instances);
-        self.require_all_parts_same_creator(&all_token_data);
+        if !attr_creator.allows_mixed_creators() {
+            self.require_all_parts_same_creator(&all_token_data);
+        }
 
         let royalties = self.get_max_royalties(&all_token_data);
         let uri = self.create_uri_for_merged_token(merged_instances);
@@ -139,6 +143,38 @@ pub trait MergedTokenSetupModule {
         max_ref.clone()
     }
 
+    /// The royalty share of each creator is the sum of royalties of their parts, weighted by the amount of each part.
+    /// Parts without royalties, like fungible tokens, are ignored.
+    fn get_creator_royalty_shares(
+        &self,
+        merged_instances: &MergedTokenInstances<Self::Api>,
+        all_token_data: &ArrayVec<EsdtTokenData<Self::Api>, MAX_MERGED_TOKENS>,
+    ) -> CreatorRoyaltyShares<Self::Api> {
+        let mut creator_shares =
+            ArrayVec::<CreatorRoyaltyShare<Self::Api>, MAX_MERGED_TOKENS>::new();
+        for (inst, token_data) in merged_instances.get_instances().iter().zip(all_token_data) {
+            if token_data.royalties == 0 {
+                continue;
+            }
+
+            let part_share = &token_data.royalties * &inst.amount;
+            match creator_shares
+                .iter_mut()
+                .find(|creator_share| creator_share.creator == token_data.creator)
+            {
+                Some(creator_share) => creator_share.share += part_share,
+                None => unsafe {
+                    creator_shares.push_unchecked(CreatorRoyaltyShare {
+                        creator: token_data.creator.clone(),
+                        share: part_share,
+                    });
+                },
+            }
+        }
+
+        creator_shares.into_iter().collect()
+    }
+
     #[view(getMergedTokenId)]
     #[storage_mapper("mergedToken")]
     fn merged_token(&self) -> NonFungibleTokenMapper;
@@ -0,0 +1,374 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+pub type VestingGrantId = u64;
+
+/// How the total amount of a grant unlocks over time. All timestamps and durations are in seconds.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, PartialEq, Eq, Debug,
+)]
+pub enum VestingSchedule {
+    /// Unlocks continuously, from `start_timestamp` until `start_timestamp + duration`.
+    Linear { start_timestamp: u64, duration: u64 },
+
+    /// Nothing unlocks until `start_timestamp + cliff_duration`.
+    /// At the cliff, everything that would have unlocked linearly since the start is unlocked at once,
+    /// then the rest unlocks linearly until `start_timestamp + duration`.
+    CliffLinear {
+        start_timestamp: u64,
+        cliff_duration: u64,
+        duration: u64,
+    },
+
+    /// Unlocks an equal part of the total at the end of each of the `num_periods` periods.
+    Periodic {
+        start_timestamp: u64,
+        period: u64,
+        num_periods: u64,
+    },
+}
+
+impl VestingSchedule {
+    /// Also checks that the schedule ends at a timestamp that fits in a `u64`.
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            VestingSchedule::Linear {
+                start_timestamp,
+                duration,
+            } => duration > 0 && start_timestamp.checked_add(duration).is_some(),
+            VestingSchedule::CliffLinear {
+                start_timestamp,
+                cliff_duration,
+                duration,
+            } => {
+                duration > 0
+                    && cliff_duration <= duration
+                    && start_timestamp.checked_add(duration).is_some()
+            },
+            VestingSchedule::Periodic {
+                start_timestamp,
+                period,
+                num_periods,
+            } => {
+                period > 0
+                    && num_periods > 0
+                    && period
+                        .checked_mul(num_periods)
+                        .and_then(|duration| start_timestamp.checked_add(duration))
+                        .is_some()
+            },
+        }
+    }
+
+    /// The part of `total_amount` unlocked at the given timestamp.
+    pub fn vested_amount<M: ManagedTypeApi>(
+        &self,
+        total_amount: &BigUint<M>,
+        timestamp: u64,
+    ) -> BigUint<M> {
+        match *self {
+            VestingSchedule::Linear {
+                start_timestamp,
+                duration,
+            } => linear_vested_amount(total_amount, start_timestamp, duration, timestamp),
+            VestingSchedule::CliffLinear {
+                start_timestamp,
+                cliff_duration,
+                duration,
+            } => {
+                if timestamp < start_timestamp.saturating_add(cliff_duration) {
+                    BigUint::zero()
+                } else {
+                    linear_vested_amount(total_amount, start_timestamp, duration, timestamp)
+                }
+            },
+            VestingSchedule::Periodic {
+                start_timestamp,
+                period,
+                num_periods,
+            } => {
+                if timestamp < start_timestamp {
+                    return BigUint::zero();
+                }
+                let periods_passed =
+                    core::cmp::min((timestamp - start_timestamp) / period, num_periods);
+                total_amount * periods_passed / num_periods
+            },
+        }
+    }
+}
+
+fn linear_vested_amount<M: ManagedTypeApi>(
+    total_amount: &BigUint<M>,
+    start_timestamp: u64,
+    duration: u64,
+    timestamp: u64,
+) -> BigUint<M> {
+    if timestamp < start_timestamp {
+        BigUint::zero()
+    } else if timestamp >= start_timestamp.saturating_add(duration) {
+        total_amount.clone()
+    } else {
+        total_amount * (timestamp - start_timestamp) / duration
+    }
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct VestingGrant<M: ManagedTypeApi> {
+    pub beneficiary: ManagedAddress<M>,
+    /// The token and total amount of the grant.
+    pub total: EgldOrEsdtTokenPayment<M>,
+    pub claimed_amount: BigUint<M>,
+    pub schedule: VestingSchedule,
+    pub revocable: bool,
+    /// Once revoked, nothing unlocks anymore after this timestamp.
+    pub revoked_timestamp: Option<u64>,
+}
+
+impl<M: ManagedTypeApi> VestingGrant<M> {
+    pub fn vested_amount(&self, timestamp: u64) -> BigUint<M> {
+        let effective_timestamp = match self.revoked_timestamp {
+            Some(revoked_timestamp) => core::cmp::min(timestamp, revoked_timestamp),
+            None => timestamp,
+        };
+        self.schedule
+            .vested_amount(&self.total.amount, effective_timestamp)
+    }
+
+    pub fn claimable_amount(&self, timestamp: u64) -> BigUint<M> {
+        let vested_amount = self.vested_amount(timestamp);
+        if vested_amount > self.claimed_amount {
+            vested_amount - &self.claimed_amount
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    /// The amount still waiting to unlock. Zero for revoked grants, since that amount was returned.
+    pub fn locked_amount(&self, timestamp: u64) -> BigUint<M> {
+        if self.revoked_timestamp.is_some() {
+            return BigUint::zero();
+        }
+        &self.total.amount - &self.vested_amount(timestamp)
+    }
+}
+
+/// Standard smart contract module for token vesting.
+///
+/// The owner creates grants by paying the full amount, in EGLD or any ESDT, upfront.
+/// Each grant has a beneficiary and a schedule: linear, cliff followed by linear, or periodic unlocks.
+/// The schedules generalize the ones from the `token-release` example, but apply to each beneficiary separately.
+///
+/// Beneficiaries claim the unlocked amounts whenever they want, either grant by grant or all at once.
+///
+/// Grants can be marked as revocable when created. Revoking a grant stops the unlocking:
+/// what was already unlocked stays claimable by the beneficiary, the rest is returned to the owner.
+///
+/// The views can be used to check the claimable and the locked amounts at any timestamp.
+#[elrond_wasm::module]
+pub trait VestingModule {
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(createVestingGrant)]
+    fn create_vesting_grant(
+        &self,
+        beneficiary: ManagedAddress,
+        schedule: VestingSchedule,
+        revocable: bool,
+    ) -> VestingGrantId {
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(payment.amount > 0, "Vesting amount must be positive");
+        require!(schedule.is_valid(), "Invalid vesting schedule");
+
+        let grant_id = self.vesting_last_grant_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let grant = VestingGrant {
+            beneficiary: beneficiary.clone(),
+            total: payment,
+            claimed_amount: BigUint::zero(),
+            schedule,
+            revocable,
+            revoked_timestamp: None,
+        };
+        self.vesting_grant(grant_id).set(&grant);
+        self.beneficiary_vesting_grants(&beneficiary)
+            .insert(grant_id);
+
+        self.vesting_grant_created_event(grant_id, &beneficiary, &grant.total, &grant.schedule);
+
+        grant_id
+    }
+
+    /// Sends the unlocked, not yet claimed, amount of a grant to its beneficiary.
+    #[endpoint(claimVested)]
+    fn claim_vested(&self, grant_id: VestingGrantId) -> EgldOrEsdtTokenPayment {
+        self.require_vesting_grant_exists(grant_id);
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.vesting_grant(grant_id).get().beneficiary == caller,
+            "Only the beneficiary can claim"
+        );
+
+        let payment = self.claim_vesting_grant(grant_id, &caller);
+        require!(payment.amount > 0, "Nothing to claim");
+
+        payment
+    }
+
+    /// Claims all grants of the caller. Returns the payments made, one per grant with a claimable amount.
+    #[endpoint(claimAllVested)]
+    fn claim_all_vested(&self) -> MultiValueEncoded<EgldOrEsdtTokenPayment> {
+        let caller = self.blockchain().get_caller();
+        let mut payments = MultiValueEncoded::new();
+        for grant_id in self.beneficiary_vesting_grants(&caller).iter() {
+            let payment = self.claim_vesting_grant(grant_id, &caller);
+            if payment.amount > 0 {
+                payments.push(payment);
+            }
+        }
+        require!(!payments.is_empty(), "Nothing to claim");
+
+        payments
+    }
+
+    /// Stops a revocable grant. The amount unlocked so far stays claimable by the beneficiary,
+    /// the locked amount is returned to the owner.
+    #[only_owner]
+    #[endpoint(revokeVestingGrant)]
+    fn revoke_vesting_grant(&self, grant_id: VestingGrantId) -> BigUint {
+        self.require_vesting_grant_exists(grant_id);
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut grant = self.vesting_grant(grant_id).get();
+        require!(grant.revocable, "Vesting grant is not revocable");
+        require!(
+            grant.revoked_timestamp.is_none(),
+            "Vesting grant already revoked"
+        );
+
+        let returned_amount = grant.locked_amount(current_timestamp);
+        grant.revoked_timestamp = Some(current_timestamp);
+        self.vesting_grant(grant_id).set(&grant);
+
+        if returned_amount > 0 {
+            self.send().direct(
+                &self.blockchain().get_caller(),
+                &grant.total.token_identifier,
+                grant.total.token_nonce,
+                &returned_amount,
+            );
+        }
+
+        self.vesting_grant_revoked_event(grant_id, &grant.beneficiary, &returned_amount);
+
+        returned_amount
+    }
+
+    #[view(getVestedAmount)]
+    fn get_vested_amount(&self, grant_id: VestingGrantId, timestamp: u64) -> BigUint {
+        self.require_vesting_grant_exists(grant_id);
+        self.vesting_grant(grant_id).get().vested_amount(timestamp)
+    }
+
+    #[view(getClaimableAmount)]
+    fn get_claimable_amount(&self, grant_id: VestingGrantId, timestamp: u64) -> BigUint {
+        self.require_vesting_grant_exists(grant_id);
+        self.vesting_grant(grant_id)
+            .get()
+            .claimable_amount(timestamp)
+    }
+
+    #[view(getLockedAmount)]
+    fn get_locked_amount(&self, grant_id: VestingGrantId, timestamp: u64) -> BigUint {
+        self.require_vesting_grant_exists(grant_id);
+        self.vesting_grant(grant_id).get().locked_amount(timestamp)
+    }
+
+    #[view(getBeneficiaryVestingGrants)]
+    fn get_beneficiary_vesting_grants(
+        &self,
+        beneficiary: ManagedAddress,
+    ) -> MultiValueEncoded<VestingGrantId> {
+        self.beneficiary_vesting_grants(&beneficiary)
+            .iter()
+            .collect()
+    }
+
+    fn claim_vesting_grant(
+        &self,
+        grant_id: VestingGrantId,
+        beneficiary: &ManagedAddress,
+    ) -> EgldOrEsdtTokenPayment {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut grant = self.vesting_grant(grant_id).get();
+        let claimable_amount = grant.claimable_amount(current_timestamp);
+        let payment = EgldOrEsdtTokenPayment::new(
+            grant.total.token_identifier.clone(),
+            grant.total.token_nonce,
+            claimable_amount,
+        );
+        if payment.amount == 0 {
+            return payment;
+        }
+
+        grant.claimed_amount += &payment.amount;
+        self.vesting_grant(grant_id).set(&grant);
+
+        self.send().direct(
+            beneficiary,
+            &payment.token_identifier,
+            payment.token_nonce,
+            &payment.amount,
+        );
+        self.vested_claimed_event(grant_id, beneficiary, &payment);
+
+        payment
+    }
+
+    fn require_vesting_grant_exists(&self, grant_id: VestingGrantId) {
+        require!(
+            !self.vesting_grant(grant_id).is_empty(),
+            "Vesting grant does not exist"
+        );
+    }
+
+    #[storage_mapper("vesting:lastGrantId")]
+    fn vesting_last_grant_id(&self) -> SingleValueMapper<VestingGrantId>;
+
+    #[view(getVestingGrant)]
+    #[storage_mapper("vesting:grant")]
+    fn vesting_grant(&self, grant_id: VestingGrantId)
+        -> SingleValueMapper<VestingGrant<Self::Api>>;
+
+    #[storage_mapper("vesting:beneficiaryGrants")]
+    fn beneficiary_vesting_grants(
+        &self,
+        beneficiary: &ManagedAddress,
+    ) -> UnorderedSetMapper<VestingGrantId>;
+
+    #[event("vestingGrantCreated")]
+    fn vesting_grant_created_event(
+        &self,
+        #[indexed] grant_id: VestingGrantId,
+        #[indexed] beneficiary: &ManagedAddress,
+        #[indexed] total: &EgldOrEsdtTokenPayment,
+        schedule: &VestingSchedule,
+    );
+
+    #[event("vestedClaimed")]
+    fn vested_claimed_event(
+        &self,
+        #[indexed] grant_id: VestingGrantId,
+        #[indexed] beneficiary: &ManagedAddress,
+        payment: &EgldOrEsdtTokenPayment,
+    );
+
+    #[event("vestingGrantRevoked")]
+    fn vesting_grant_revoked_event(
+        &self,
+        #[indexed] grant_id: VestingGrantId,
+        #[indexed] beneficiary: &ManagedAddress,
+        returned_amount: &BigUint,
+    );
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::types::ContractError;
 use alloc::{string::String, vec::Vec};
 
 #[derive(Debug, Default, Clone)]
@@ -10,6 +11,8 @@ pub struct ContractAbi {
     pub endpoints: Vec<EndpointAbi>,
     pub promise_callbacks: Vec<EndpointAbi>,
     pub events: Vec<EventAbi>,
+    pub storage: Vec<StorageAbi>,
+    pub errors: Vec<ErrorAbi>,
     pub has_callback: bool,
     pub type_descriptions: TypeDescriptionContainerImpl,
 }
@@ -20,6 +23,10 @@ impl ContractAbi {
             .extend_from_slice(other.constructors.as_slice());
         self.endpoints.extend_from_slice(other.endpoints.as_slice());
         self.events.extend_from_slice(other.events.as_slice());
+        self.storage.extend_from_slice(other.storage.as_slice());
+        for error_abi in other.errors {
+            self.add_error_abi(error_abi);
+        }
         self.promise_callbacks
             .extend_from_slice(other.promise_callbacks.as_slice());
         self.has_callback |= other.has_callback;
@@ -32,6 +39,29 @@ impl ContractAbi {
         T::provide_type_descriptions(&mut self.type_descriptions);
     }
 
+    pub fn add_errors<E: ContractError>(&mut self) {
+        for error_abi in E::error_abis() {
+            self.add_error_abi(error_abi);
+        }
+    }
+
+    /// Errors are identified by their code, so a code cannot be used for several errors.
+    /// The same error can be declared by several modules, it only appears once.
+    fn add_error_abi(&mut self, error_abi: ErrorAbi) {
+        if let Some(existing) = self.errors.iter().find(|e| e.code == error_abi.code) {
+            assert!(
+                existing.name == error_abi.name && existing.message == error_abi.message,
+                "contract error code {} is used for both {} and {}",
+                error_abi.code,
+                existing.name,
+                error_abi.name,
+            );
+            return;
+        }
+
+        self.errors.push(error_abi);
+    }
+
     /// Contract main crate name.
     pub fn get_crate_name(&self) -> &str {
         self.build_info.contract_crate.name
@@ -33,6 +33,8 @@ pub struct EndpointAbi {
     pub rust_method_name: &'static str,
     pub only_owner: bool,
     pub only_admin: bool,
+    /// Set for `#[only_role("ROLE_NAME")]` endpoints.
+    pub only_role: Option<&'static str>,
     pub labels: &'static [&'static str],
     pub mutability: EndpointMutabilityAbi,
     pub payable_in_tokens: &'static [&'static str],
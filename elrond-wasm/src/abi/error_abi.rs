@@ -0,0 +1,10 @@
+/// Describes an error that the contract can signal, as declared via `#[derive(ContractError)]`.
+#[derive(Clone, Debug)]
+pub struct ErrorAbi {
+    pub docs: &'static [&'static str],
+    pub code: u32,
+    pub name: &'static str,
+
+    /// Each `{}` in the message is replaced by an argument, when the error is signaled.
+    pub message: &'static str,
+}
@@ -0,0 +1,86 @@
+use super::*;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// The type name recorded for storage keys and values whose type does not implement `TypeAbi`.
+pub const OPAQUE_STORAGE_TYPE_NAME: &str = "opaque";
+
+#[derive(Clone, Debug)]
+pub struct StorageKeyArgAbi {
+    pub arg_name: &'static str,
+    pub type_name: TypeName,
+}
+
+/// Describes a storage entry, as declared via `#[storage_mapper]`, `#[storage_get]` or `#[storage_set]`.
+#[derive(Clone, Debug)]
+pub struct StorageAbi {
+    pub docs: &'static [&'static str],
+
+    /// The base key. The key arguments are nested-encoded and appended to it, to obtain the full key.
+    pub key: &'static str,
+
+    /// The name of the storage mapper type, e.g. `MapMapper`.
+    /// Values accessed via `#[storage_get]` and `#[storage_set]` are stored the same way as in a `SingleValueMapper`.
+    pub mapper: &'static str,
+
+    pub key_args: Vec<StorageKeyArgAbi>,
+
+    /// The type arguments of the mapper, e.g. the key and value types of a `MapMapper`.
+    ///
+    /// Storage types are not required to implement `TypeAbi`.
+    /// Those that do not are recorded as `opaque`.
+    pub value_types: Vec<TypeName>,
+}
+
+impl StorageAbi {
+    pub fn add_key_arg(&mut self, arg_name: &'static str, type_name: TypeName) {
+        self.key_args.push(StorageKeyArgAbi {
+            arg_name,
+            type_name,
+        });
+    }
+
+    pub fn add_value_type(&mut self, type_name: TypeName) {
+        self.value_types.push(type_name);
+    }
+}
+
+/// Used by the ABI generator to describe storage types, whether or not they implement `TypeAbi`.
+///
+/// Method resolution on a `&StorageTypeProbe<T>` picks `StorageTypeAbi` when `T: TypeAbi`,
+/// and falls back to `StorageTypeOpaque`, implemented one reference level further, otherwise.
+/// This only works for concrete types, which is the case in the generated ABI provider.
+pub struct StorageTypeProbe<T>(PhantomData<T>);
+
+impl<T> StorageTypeProbe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        StorageTypeProbe(PhantomData)
+    }
+}
+
+pub trait StorageTypeAbi {
+    fn storage_type_name(&self) -> TypeName;
+
+    fn provide_storage_type_descriptions(&self, contract_abi: &mut ContractAbi);
+}
+
+impl<T: TypeAbi> StorageTypeAbi for StorageTypeProbe<T> {
+    fn storage_type_name(&self) -> TypeName {
+        T::type_name()
+    }
+
+    fn provide_storage_type_descriptions(&self, contract_abi: &mut ContractAbi) {
+        contract_abi.add_type_descriptions::<T>();
+    }
+}
+
+pub trait StorageTypeOpaque {
+    fn storage_type_name(&self) -> TypeName {
+        OPAQUE_STORAGE_TYPE_NAME.into()
+    }
+
+    fn provide_storage_type_descriptions(&self, _contract_abi: &mut ContractAbi) {}
+}
+
+impl<T> StorageTypeOpaque for &StorageTypeProbe<T> {}
@@ -5,6 +5,8 @@ pub struct TypeDescription {
     pub docs: &'static [&'static str],
     pub name: String,
     pub contents: TypeContents,
+    /// Set for versioned structs (`#[codec(version = N)]`), which are serialized with a leading version byte.
+    pub version: Option<u8>,
 }
 
 impl TypeDescription {
@@ -16,6 +18,7 @@ impl TypeDescription {
         docs: &[],
         name: String::new(),
         contents: TypeContents::NotSpecified,
+        version: None,
     };
 }
 
@@ -24,6 +27,8 @@ pub enum TypeContents {
     NotSpecified,
     Enum(Vec<EnumVariantDescription>),
     Struct(Vec<StructFieldDescription>),
+    /// Encoded exactly like the named underlying type, e.g. fixed-point decimals are encoded as their units.
+    Transparent(String),
 }
 
 impl TypeContents {
@@ -45,4 +50,6 @@ pub struct StructFieldDescription {
     pub docs: &'static [&'static str],
     pub name: &'static str,
     pub field_type: String,
+    /// In versioned structs, the first version that serializes the field (`#[codec(since = N)]`).
+    pub since: Option<u8>,
 }
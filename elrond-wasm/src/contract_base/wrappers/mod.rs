@@ -12,7 +12,7 @@ pub use blockchain_wrapper::BlockchainWrapper;
 pub use call_value_wrapper::CallValueWrapper;
 pub use callback_args_wrapper::CallbackArgApiWrapper;
 pub use crypto_wrapper::CryptoWrapper;
-pub use error_helper::ErrorHelper;
+pub use error_helper::{ErrorHelper, IntoSignalError};
 pub(crate) use send_raw_wrapper::SendRawWrapper;
 pub use send_wrapper::SendWrapper;
 pub use serializer::{ExitCodecErrorHandler, ManagedSerializer};
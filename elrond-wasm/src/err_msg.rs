@@ -44,6 +44,10 @@ pub const NUMBER_IS_NOT_NORMAL: &[u8] =
     b"number is not normal. It is either infinite, NaN or subnormal";
 pub const CANNOT_COMPARE_VALUES: &[u8] = b"values are not comparable";
 
+pub const EC_CURVE_NOT_SUPPORTED: &str = "elliptic curve not supported";
+pub const EC_POINT_NOT_ON_CURVE: &str = "point is not on curve";
+pub const EC_BUFFER_LENGTH_INCORRECT: &str = "length of buffer is not correct";
+
 pub const DESERIALIZATION_INVALID_BYTE: &str = "call data deserialization error: not a valid byte";
 pub const DESERIALIZATION_NOT_32_BYTES: &str =
     "call data deserialization error: 32 as_bytes expected";
@@ -63,3 +67,6 @@ pub const STORAGE_NOT_32_BYTES: &[u8] = b"32 bytes of data expected in storage a
 
 /// Mirrors the error message from the VM.
 pub const ERROR_SIGNALLED_BY_SMARTCONTRACT: &str = "error signalled by smartcontract";
+pub const STORAGE_WRITE_READONLY: &str = "cannot write on read only mode";
+pub const OPERATION_NOT_PERMITTED_READONLY: &str = "operation not permitted in read only mode";
+pub const NOT_ENOUGH_GAS: &str = "not enough gas";
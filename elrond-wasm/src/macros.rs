@@ -44,7 +44,7 @@ macro_rules! imports {
 macro_rules! derive_imports {
     () => {
         use elrond_wasm::{
-            derive::{ManagedVecItem, TypeAbi},
+            derive::{ContractError, ManagedVecItem, TypeAbi},
             elrond_codec,
             elrond_codec::elrond_codec_derive::{
                 NestedDecode, NestedEncode, TopDecode, TopDecodeOrDefault, TopEncode,
@@ -99,6 +99,26 @@ macro_rules! sc_panic {
     ($msg:expr $(,)?) => {
         elrond_wasm::contract_base::ErrorHelper::<Self::Api>::signal_error_with_message($msg);
     };
+    ($error:path, $($arg:expr),+ $(,)?) => {
+        elrond_wasm::sc_panic_contract_error!($error, $($arg),+);
+    };
+}
+
+/// Signals an error declared via `#[derive(ContractError)]`, filling in its message template.
+/// Used by `sc_panic!` and `require!`, the number of arguments is checked at compile time.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! sc_panic_contract_error {
+    ($error:path, $($arg:expr),+ $(,)?) => {{
+        const _: () = assert!(
+            $error.contract_error_placeholder_count() == [$(stringify!($arg)),+].len(),
+            "Number of placeholders does not match number of arguments."
+        );
+        let mut ___builder___ =
+            elrond_wasm::types::ContractErrorMessageBuilder::<Self::Api>::new(&$error);
+        $(___builder___.append_arg(&$arg);)+
+        ___builder___.signal_error();
+    }};
 }
 
 /// Allows us to write Solidity style `require!(<condition>, <error_msg>)` and avoid if statements.
@@ -107,6 +127,8 @@ macro_rules! sc_panic {
 ///
 /// It is also possible to give the error as a variable of types such as `&str`, `&[u8]` or `ManagedBuffer`.
 ///
+/// Errors declared via `#[derive(ContractError)]` can also be used, followed by the arguments for their message template.
+///
 /// Examples:
 ///
 /// ```rust
@@ -133,6 +155,16 @@ macro_rules! require {
             elrond_wasm::sc_panic!($($msg_tokens),+);
         }
     };
+    ($expression:expr, $error:expr $(,)?) => {
+        if (!($expression)) {
+            elrond_wasm::sc_panic!($error);
+        }
+    };
+    ($expression:expr, $error:path, $($arg:expr),+ $(,)?) => {
+        if (!($expression)) {
+            elrond_wasm::sc_panic_contract_error!($error, $($arg),+);
+        }
+    };
 }
 
 #[macro_export]
@@ -0,0 +1,293 @@
+use core::{
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+use super::{StorageClearable, StorageMapper};
+use crate::{
+    api::StorageMapperApi,
+    storage::{storage_clear, storage_get, storage_set, StorageKey},
+    types::{ManagedBuffer, ManagedType},
+};
+
+const WORD_IDENTIFIER: &[u8] = b".word";
+const NUM_WORDS_IDENTIFIER: &[u8] = b".num_words";
+
+/// Number of flags stored under a single storage key.
+pub const BITMAP_WORD_BITS: u64 = 256;
+const BITMAP_WORD_BYTES: usize = (BITMAP_WORD_BITS / 8) as usize;
+const LIMB_BITS: u64 = 64;
+const NUM_LIMBS: usize = (BITMAP_WORD_BITS / LIMB_BITS) as usize;
+
+/// A word is handled as 4 little-endian 64-bit limbs, so flag `i` of the word is bit `i % 64` of limb `i / 64`.
+type Word = [u64; NUM_LIMBS];
+
+/// Stores a set of boolean flags, indexed by `u64`, all initially unset.
+///
+/// The flags are packed into 256-bit words, each word having its own storage key,
+/// so setting many nearby flags costs far less storage than one entry per flag.
+/// Words that become empty are removed from storage.
+///
+/// Also keeps track of the highest word ever written, which bounds counting and iteration.
+/// Sparse flags with very large indexes therefore make counting and iterating over the whole bitmap costly,
+/// use ranges in that case.
+pub struct BitmapMapper<SA>
+where
+    SA: StorageMapperApi,
+{
+    _phantom_api: PhantomData<SA>,
+    base_key: StorageKey<SA>,
+}
+
+impl<SA> StorageMapper<SA> for BitmapMapper<SA>
+where
+    SA: StorageMapperApi,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        BitmapMapper {
+            _phantom_api: PhantomData,
+            base_key,
+        }
+    }
+}
+
+impl<SA> StorageClearable for BitmapMapper<SA>
+where
+    SA: StorageMapperApi,
+{
+    fn clear(&mut self) {
+        for word_index in 0..self.num_words() {
+            storage_clear(self.build_word_key(word_index).as_ref());
+        }
+        storage_clear(self.build_name_key(NUM_WORDS_IDENTIFIER).as_ref());
+    }
+}
+
+impl<SA> BitmapMapper<SA>
+where
+    SA: StorageMapperApi,
+{
+    fn build_name_key(&self, name: &[u8]) -> StorageKey<SA> {
+        let mut name_key = self.base_key.clone();
+        name_key.append_bytes(name);
+        name_key
+    }
+
+    fn build_word_key(&self, word_index: u64) -> StorageKey<SA> {
+        let mut word_key = self.base_key.clone();
+        word_key.append_bytes(WORD_IDENTIFIER);
+        word_key.append_item(&word_index);
+        word_key
+    }
+
+    /// One more than the highest word index ever written.
+    fn num_words(&self) -> u64 {
+        storage_get(self.build_name_key(NUM_WORDS_IDENTIFIER).as_ref())
+    }
+
+    fn get_word(&self, word_index: u64) -> Word {
+        let buffer: ManagedBuffer<SA> = storage_get(self.build_word_key(word_index).as_ref());
+        let mut bytes = [0u8; BITMAP_WORD_BYTES];
+        let _ = buffer.load_to_byte_array(&mut bytes);
+
+        let mut word = [0u64; NUM_LIMBS];
+        for (limb, limb_bytes) in word.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(limb_bytes.try_into().unwrap());
+        }
+        word
+    }
+
+    fn set_word(&self, word_index: u64, word: &Word) {
+        let word_key = self.build_word_key(word_index);
+        if word.iter().all(|limb| *limb == 0) {
+            storage_clear(word_key.as_ref());
+            return;
+        }
+
+        let mut bytes = [0u8; BITMAP_WORD_BYTES];
+        for (limb, limb_bytes) in word.iter().zip(bytes.chunks_exact_mut(8)) {
+            limb_bytes.copy_from_slice(&limb.to_le_bytes());
+        }
+        storage_set(
+            word_key.as_ref(),
+            &ManagedBuffer::<SA>::new_from_bytes(&bytes[..]),
+        );
+    }
+
+    /// The word index, the limb inside the word, and the bit mask inside the limb.
+    fn position(index: u64) -> (u64, usize, u64) {
+        let word_index = index / BITMAP_WORD_BITS;
+        let bit_index = index % BITMAP_WORD_BITS;
+        (
+            word_index,
+            (bit_index / LIMB_BITS) as usize,
+            1u64 << (bit_index % LIMB_BITS),
+        )
+    }
+
+    /// Converts the range to the `[start, end)` form, the end being limited by the highest word written.
+    fn resolve_range<R: RangeBounds<u64>>(&self, range: R) -> (u64, u64) {
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let max_end = self.num_words().saturating_mul(BITMAP_WORD_BITS);
+        let end = match range.end_bound() {
+            Bound::Included(end) => end.saturating_add(1).min(max_end),
+            Bound::Excluded(end) => (*end).min(max_end),
+            Bound::Unbounded => max_end,
+        };
+        (start, end)
+    }
+
+    /// Returns `true` if the flag at the given index is set.
+    pub fn get(&self, index: u64) -> bool {
+        let (word_index, limb_index, mask) = Self::position(index);
+        self.get_word(word_index)[limb_index] & mask != 0
+    }
+
+    /// Sets the flag at the given index.
+    ///
+    /// Returns `true` if the flag was not set before.
+    pub fn set(&mut self, index: u64) -> bool {
+        let (word_index, limb_index, mask) = Self::position(index);
+        let mut word = self.get_word(word_index);
+        if word[limb_index] & mask != 0 {
+            return false;
+        }
+
+        let was_empty = word.iter().all(|limb| *limb == 0);
+        word[limb_index] |= mask;
+        self.set_word(word_index, &word);
+        if was_empty && word_index >= self.num_words() {
+            storage_set(
+                self.build_name_key(NUM_WORDS_IDENTIFIER).as_ref(),
+                &(word_index + 1),
+            );
+        }
+        true
+    }
+
+    /// Unsets the flag at the given index.
+    ///
+    /// Returns `true` if the flag was set before.
+    pub fn unset(&mut self, index: u64) -> bool {
+        let (word_index, limb_index, mask) = Self::position(index);
+        let mut word = self.get_word(word_index);
+        if word[limb_index] & mask == 0 {
+            return false;
+        }
+
+        word[limb_index] &= !mask;
+        self.set_word(word_index, &word);
+        true
+    }
+
+    /// Counts the flags that are set in the given range of indexes.
+    ///
+    /// Reads one storage entry for every 256 indexes in the range.
+    pub fn count_ones<R: RangeBounds<u64>>(&self, range: R) -> u64 {
+        let (start, end) = self.resolve_range(range);
+        if start >= end {
+            return 0;
+        }
+
+        let first_word_index = start / BITMAP_WORD_BITS;
+        let last_word_index = (end - 1) / BITMAP_WORD_BITS;
+        let mut count = 0u64;
+        for word_index in first_word_index..=last_word_index {
+            let word = self.get_word(word_index);
+            let word_start = word_index * BITMAP_WORD_BITS;
+            for (limb_index, limb) in word.iter().enumerate() {
+                let limb_start = word_start + limb_index as u64 * LIMB_BITS;
+                count += (limb & limb_range_mask(limb_start, start, end)).count_ones() as u64;
+            }
+        }
+        count
+    }
+
+    /// Iterates over the indexes of all the flags that are set, in ascending order.
+    pub fn iter(&self) -> Iter<SA> {
+        self.iter_range(..)
+    }
+
+    /// Iterates over the indexes of the flags that are set in the given range, in ascending order.
+    pub fn iter_range<R: RangeBounds<u64>>(&self, range: R) -> Iter<SA> {
+        let (start, end) = self.resolve_range(range);
+        Iter::new(self, start, end)
+    }
+}
+
+/// The bits of the limb that fall in the `[start, end)` range.
+fn limb_range_mask(limb_start: u64, start: u64, end: u64) -> u64 {
+    let limb_end = limb_start + LIMB_BITS;
+    if end <= limb_start || start >= limb_end {
+        return 0;
+    }
+    let low_bits_to_skip = start.saturating_sub(limb_start);
+    let high_bits_to_skip = limb_end.saturating_sub(end);
+    (u64::MAX << low_bits_to_skip) & (u64::MAX >> high_bits_to_skip)
+}
+
+/// An iterator over the indexes of the flags that are set in a `BitmapMapper`.
+///
+/// This `struct` is created by [`BitmapMapper::iter()`] and [`BitmapMapper::iter_range()`].
+pub struct Iter<'a, SA>
+where
+    SA: StorageMapperApi,
+{
+    /// The next index to check.
+    index: u64,
+    end: u64,
+    /// The word containing `index`, loaded lazily.
+    current_word: Option<Word>,
+    bitmap: &'a BitmapMapper<SA>,
+}
+
+impl<'a, SA> Iter<'a, SA>
+where
+    SA: StorageMapperApi,
+{
+    fn new(bitmap: &'a BitmapMapper<SA>, start: u64, end: u64) -> Iter<'a, SA> {
+        Iter {
+            index: start,
+            end,
+            current_word: None,
+            bitmap,
+        }
+    }
+}
+
+impl<'a, SA> Iterator for Iter<'a, SA>
+where
+    SA: StorageMapperApi,
+{
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        while self.index < self.end {
+            let (word_index, limb_index, _) = BitmapMapper::<SA>::position(self.index);
+            let word = *self
+                .current_word
+                .get_or_insert_with(|| self.bitmap.get_word(word_index));
+
+            let limb_start = self.index - self.index % LIMB_BITS;
+            let remaining = word[limb_index] & limb_range_mask(limb_start, self.index, self.end);
+            if remaining != 0 {
+                let found = limb_start + remaining.trailing_zeros() as u64;
+                self.index = found + 1;
+                if self.index % BITMAP_WORD_BITS == 0 {
+                    self.current_word = None;
+                }
+                return Some(found);
+            }
+
+            self.index = limb_start + LIMB_BITS;
+            if self.index % BITMAP_WORD_BITS == 0 {
+                self.current_word = None;
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,311 @@
+use super::{StorageClearable, StorageMapper};
+use crate::{
+    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    api::{ErrorApiImpl, StorageMapperApi},
+    storage::{storage_clear, storage_get, storage_set, StorageKey},
+    types::{ManagedType, MultiValueEncoded},
+};
+use core::marker::PhantomData;
+use elrond_codec::{
+    multi_encode_iter_or_handle_err, multi_types::MultiValue2, CodecFrom, EncodeErrorHandler,
+    TopDecode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
+};
+
+const LEN_SUFFIX: &[u8] = b".len";
+const BLOCK_NONCE_SUFFIX: &[u8] = b".block_nonce";
+const VALUE_SUFFIX: &[u8] = b".value";
+
+static CHECKPOINT_ORDER_ERR_MSG: &[u8] = b"checkpoint block nonce lower than the latest checkpoint";
+
+/// Keeps the history of a value, as a list of checkpoints.
+/// Each checkpoint holds the block nonce at which it was recorded and the value from that block onwards.
+///
+/// Checkpoints can only be recorded in increasing block nonce order.
+/// Recording twice in the same block overwrites the last checkpoint, so there is at most one checkpoint per block.
+///
+/// Looking up the value at a past block nonce is a binary search over the checkpoints,
+/// only loading the block nonces, plus one final value read.
+/// Before the first checkpoint, the value is the zero-value of the type.
+///
+/// Typically used for balances and voting power, where the value at a fixed block in the past
+/// cannot be changed anymore by later transfers.
+pub struct CheckpointMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + 'static,
+{
+    _phantom_api: PhantomData<SA>,
+    base_key: StorageKey<SA>,
+    _phantom_item: PhantomData<T>,
+}
+
+impl<SA, T> StorageMapper<SA> for CheckpointMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        CheckpointMapper {
+            _phantom_api: PhantomData,
+            base_key,
+            _phantom_item: PhantomData,
+        }
+    }
+}
+
+impl<SA, T> StorageClearable for CheckpointMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
+
+impl<SA, T> CheckpointMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    fn build_name_key(&self, name: &[u8]) -> StorageKey<SA> {
+        let mut name_key = self.base_key.clone();
+        name_key.append_bytes(name);
+        name_key
+    }
+
+    fn build_indexed_key(&self, name: &[u8], index: usize) -> StorageKey<SA> {
+        let mut indexed_key = self.build_name_key(name);
+        indexed_key.append_item(&index);
+        indexed_key
+    }
+
+    fn save_len(&self, len: usize) {
+        storage_set(self.build_name_key(LEN_SUFFIX).as_ref(), &len);
+    }
+
+    /// Checkpoint indexes start from 1.
+    fn block_nonce_at_index(&self, index: usize) -> u64 {
+        storage_get(self.build_indexed_key(BLOCK_NONCE_SUFFIX, index).as_ref())
+    }
+
+    /// Index 0 is never written, so it yields the zero-value.
+    fn value_at_index(&self, index: usize) -> T {
+        storage_get(self.build_indexed_key(VALUE_SUFFIX, index).as_ref())
+    }
+
+    /// Number of checkpoints recorded.
+    pub fn len(&self) -> usize {
+        storage_get(self.build_name_key(LEN_SUFFIX).as_ref())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The block nonce of the most recent checkpoint, if any.
+    pub fn latest_block_nonce(&self) -> Option<u64> {
+        let len = self.len();
+        if len == 0 {
+            None
+        } else {
+            Some(self.block_nonce_at_index(len))
+        }
+    }
+
+    /// The current value, i.e. the value of the most recent checkpoint.
+    pub fn latest(&self) -> T {
+        self.value_at_index(self.len())
+    }
+
+    /// Records a new value, starting with the given block nonce.
+    ///
+    /// Will signal an error if the block nonce is lower than the one of the latest checkpoint.
+    /// If it is equal, the latest checkpoint is overwritten.
+    pub fn record(&mut self, block_nonce: u64, value: &T) {
+        let len = self.len();
+        let index = if len > 0 {
+            let latest_block_nonce = self.block_nonce_at_index(len);
+            if block_nonce < latest_block_nonce {
+                SA::error_api_impl().signal_error(CHECKPOINT_ORDER_ERR_MSG);
+            }
+            if block_nonce == latest_block_nonce {
+                len
+            } else {
+                len + 1
+            }
+        } else {
+            1
+        };
+
+        if index > len {
+            storage_set(
+                self.build_indexed_key(BLOCK_NONCE_SUFFIX, index).as_ref(),
+                &block_nonce,
+            );
+            self.save_len(index);
+        }
+        storage_set(self.build_indexed_key(VALUE_SUFFIX, index).as_ref(), value);
+    }
+
+    /// Takes the latest value, lets the closure modify it, then records the result at the given block nonce.
+    pub fn update<R, F: FnOnce(&mut T) -> R>(&mut self, block_nonce: u64, f: F) -> R {
+        let mut value = self.latest();
+        let result = f(&mut value);
+        self.record(block_nonce, &value);
+        result
+    }
+
+    /// The value as it was at the end of the given block.
+    ///
+    /// Yields the zero-value if there are no checkpoints up to that block.
+    pub fn value_at(&self, block_nonce: u64) -> T {
+        self.value_at_index(self.index_at(block_nonce))
+    }
+
+    /// Index of the last checkpoint recorded at or before the given block nonce, or 0 if none.
+    fn index_at(&self, block_nonce: u64) -> usize {
+        let len = self.len();
+        if len == 0 {
+            return 0;
+        }
+
+        // most lookups are for recent blocks
+        if self.block_nonce_at_index(len) <= block_nonce {
+            return len;
+        }
+
+        // invariant: the checkpoint at `low` (if any) is at or before the block nonce,
+        // the one at `high` is after it
+        let mut low = 0;
+        let mut high = len;
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if self.block_nonce_at_index(mid) <= block_nonce {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    /// Deletes all checkpoints from storage.
+    pub fn clear(&mut self) {
+        let len = self.len();
+        for index in 1..=len {
+            storage_clear(self.build_indexed_key(BLOCK_NONCE_SUFFIX, index).as_ref());
+            storage_clear(self.build_indexed_key(VALUE_SUFFIX, index).as_ref());
+        }
+        storage_clear(self.build_name_key(LEN_SUFFIX).as_ref());
+    }
+
+    /// Iterates over all checkpoints, as `(block nonce, value)` pairs, from oldest to most recent.
+    pub fn iter(&self) -> Iter<SA, T> {
+        Iter::new(self)
+    }
+
+    /// Checks that the checkpoints are in strictly increasing block nonce order.
+    /// Used for unit testing.
+    ///
+    /// This operation should compute in *O*(n) time.
+    pub fn check_internal_consistency(&self) -> bool {
+        let len = self.len();
+        for index in 2..=len {
+            if self.block_nonce_at_index(index - 1) >= self.block_nonce_at_index(index) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// An iterator over the checkpoints of a `CheckpointMapper`.
+///
+/// This `struct` is created by [`CheckpointMapper::iter()`]. See its
+/// documentation for more.
+pub struct Iter<'a, SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + 'static,
+{
+    index: usize,
+    len: usize,
+    checkpoints: &'a CheckpointMapper<SA, T>,
+}
+
+impl<'a, SA, T> Iter<'a, SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + 'static,
+{
+    fn new(checkpoints: &'a CheckpointMapper<SA, T>) -> Iter<'a, SA, T> {
+        Iter {
+            index: 1,
+            len: checkpoints.len(),
+            checkpoints,
+        }
+    }
+}
+
+impl<'a, SA, T> Iterator for Iter<'a, SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + 'static,
+{
+    type Item = (u64, T);
+
+    fn next(&mut self) -> Option<(u64, T)> {
+        let current_index = self.index;
+        if current_index > self.len {
+            return None;
+        }
+        self.index += 1;
+        Some((
+            self.checkpoints.block_nonce_at_index(current_index),
+            self.checkpoints.value_at_index(current_index),
+        ))
+    }
+}
+
+/// Behaves like a MultiResultVec<MultiValue2<u64, T>> when an endpoint result.
+impl<SA, T> TopEncodeMulti for CheckpointMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        let iter = self.iter().map(MultiValue2::<u64, T>::from);
+        multi_encode_iter_or_handle_err(iter, output, h)
+    }
+}
+
+impl<SA, T> CodecFrom<CheckpointMapper<SA, T>> for MultiValueEncoded<SA, MultiValue2<u64, T>>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode,
+{
+}
+
+/// Behaves like a MultiResultVec<MultiValue2<u64, T>> when an endpoint result.
+impl<SA, T> TypeAbi for CheckpointMapper<SA, T>
+where
+    SA: StorageMapperApi,
+    T: TopEncode + TopDecode + TypeAbi,
+{
+    fn type_name() -> TypeName {
+        MultiValueEncoded::<SA, MultiValue2<u64, T>>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        T::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+}
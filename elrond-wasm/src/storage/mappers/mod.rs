@@ -1,10 +1,14 @@
 mod bi_di_mapper;
+mod bitmap_mapper;
+mod checkpoint_mapper;
 mod fungible_token_mapper;
 mod linked_list_mapper;
 mod map_mapper;
 mod map_storage_mapper;
 mod mapper;
 mod non_fungible_token_mapper;
+mod ordered_map_mapper;
+mod priority_queue_mapper;
 mod queue_mapper;
 mod set_mapper;
 mod single_value_mapper;
@@ -17,12 +21,16 @@ mod vec_mapper;
 mod whitelist_mapper;
 
 pub use bi_di_mapper::BiDiMapper;
+pub use bitmap_mapper::{BitmapMapper, BITMAP_WORD_BITS};
+pub use checkpoint_mapper::CheckpointMapper;
 pub use fungible_token_mapper::FungibleTokenMapper;
 pub use linked_list_mapper::{LinkedListMapper, LinkedListNode};
 pub use map_mapper::MapMapper;
 pub use map_storage_mapper::MapStorageMapper;
 pub use mapper::{StorageClearable, StorageMapper};
 pub use non_fungible_token_mapper::NonFungibleTokenMapper;
+pub use ordered_map_mapper::OrderedMapMapper;
+pub use priority_queue_mapper::PriorityQueueMapper;
 pub use queue_mapper::QueueMapper;
 pub use set_mapper::SetMapper;
 pub use single_value_mapper::{SingleValue, SingleValueMapper};
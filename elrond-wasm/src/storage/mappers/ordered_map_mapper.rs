@@ -0,0 +1,1016 @@
+use core::{
+    cmp::Ordering,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+use super::{StorageClearable, StorageMapper};
+use crate::{
+    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    api::StorageMapperApi,
+    storage::{storage_clear, storage_get, storage_set, StorageKey},
+    types::{ManagedType, ManagedVec, MultiValueEncoded},
+};
+use elrond_codec::{
+    elrond_codec_derive::{TopDecode, TopDecodeOrDefault, TopEncode, TopEncodeOrDefault},
+    multi_encode_iter_or_handle_err,
+    multi_types::MultiValue2,
+    CodecFrom, DecodeDefault, EncodeDefault, EncodeErrorHandler, NestedDecode, NestedEncode,
+    TopDecode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
+};
+
+const NULL_ENTRY: u32 = 0;
+const INFO_IDENTIFIER: &[u8] = b".info";
+const NODE_LINKS_IDENTIFIER: &[u8] = b".node_links";
+const NODE_KEY_IDENTIFIER: &[u8] = b".node_key";
+const NODE_VALUE_IDENTIFIER: &[u8] = b".node_value";
+const NODE_ID_IDENTIFIER: &[u8] = b".node_id";
+
+#[derive(TopEncode, TopDecode, PartialEq, Eq, Clone, Copy)]
+pub struct TreeNode {
+    pub parent: u32,
+    pub left: u32,
+    pub right: u32,
+    pub height: u32,
+}
+
+#[derive(TopEncodeOrDefault, TopDecodeOrDefault, PartialEq, Eq, Clone, Copy)]
+pub struct OrderedMapMapperInfo {
+    pub len: u32,
+    pub root: u32,
+    pub new: u32,
+}
+
+impl EncodeDefault for OrderedMapMapperInfo {
+    fn is_default(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl DecodeDefault for OrderedMapMapperInfo {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            root: 0,
+            new: 0,
+        }
+    }
+}
+
+impl OrderedMapMapperInfo {
+    pub fn generate_new_node_id(&mut self) -> u32 {
+        self.new += 1;
+        self.new
+    }
+}
+
+/// A map that keeps its entries sorted by key.
+///
+/// It is stored as an AVL tree, so insertion and removal take *O*(log n) storage reads and writes.
+/// Looking up a key takes *O*(1) time, since the tree node of each key is also indexed separately.
+///
+/// Besides the usual map operations, it can find the smallest and the largest keys,
+/// the closest keys to a given key, and it can iterate over ranges of keys, in either direction.
+pub struct OrderedMapMapper<SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    _phantom_api: PhantomData<SA>,
+    base_key: StorageKey<SA>,
+    _phantom_key: PhantomData<K>,
+    _phantom_value: PhantomData<V>,
+}
+
+impl<SA, K, V> StorageMapper<SA> for OrderedMapMapper<SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        OrderedMapMapper {
+            _phantom_api: PhantomData,
+            base_key,
+            _phantom_key: PhantomData,
+            _phantom_value: PhantomData,
+        }
+    }
+}
+
+impl<SA, K, V> StorageClearable for OrderedMapMapper<SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    fn clear(&mut self) {
+        // the links are needed to walk the tree, so nothing gets cleared before the walk is over
+        let mut node_ids = ManagedVec::<SA, u32>::new();
+        for node_id in self.node_ids(..) {
+            node_ids.push(node_id);
+        }
+        for node_id in node_ids.iter() {
+            self.clear_node_id(&self.get_key(node_id));
+            self.clear_node_entries(node_id);
+        }
+        self.set_info(OrderedMapMapperInfo::default());
+    }
+}
+
+impl<SA, K, V> OrderedMapMapper<SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    fn build_name_key(&self, name: &[u8]) -> StorageKey<SA> {
+        let mut name_key = self.base_key.clone();
+        name_key.append_bytes(name);
+        name_key
+    }
+
+    fn build_node_id_named_key(&self, name: &[u8], node_id: u32) -> StorageKey<SA> {
+        let mut named_key = self.base_key.clone();
+        named_key.append_bytes(name);
+        named_key.append_item(&node_id);
+        named_key
+    }
+
+    fn build_key_named_key(&self, name: &[u8], key: &K) -> StorageKey<SA> {
+        let mut named_key = self.base_key.clone();
+        named_key.append_bytes(name);
+        named_key.append_item(key);
+        named_key
+    }
+
+    fn get_info(&self) -> OrderedMapMapperInfo {
+        storage_get(self.build_name_key(INFO_IDENTIFIER).as_ref())
+    }
+
+    fn set_info(&self, value: OrderedMapMapperInfo) {
+        storage_set(self.build_name_key(INFO_IDENTIFIER).as_ref(), &value);
+    }
+
+    fn get_node(&self, node_id: u32) -> TreeNode {
+        storage_get(
+            self.build_node_id_named_key(NODE_LINKS_IDENTIFIER, node_id)
+                .as_ref(),
+        )
+    }
+
+    fn set_node(&self, node_id: u32, node: &TreeNode) {
+        storage_set(
+            self.build_node_id_named_key(NODE_LINKS_IDENTIFIER, node_id)
+                .as_ref(),
+            node,
+        );
+    }
+
+    fn get_key(&self, node_id: u32) -> K {
+        storage_get(
+            self.build_node_id_named_key(NODE_KEY_IDENTIFIER, node_id)
+                .as_ref(),
+        )
+    }
+
+    fn set_key(&self, node_id: u32, key: &K) {
+        storage_set(
+            self.build_node_id_named_key(NODE_KEY_IDENTIFIER, node_id)
+                .as_ref(),
+            key,
+        );
+    }
+
+    fn get_value(&self, node_id: u32) -> V {
+        storage_get(
+            self.build_node_id_named_key(NODE_VALUE_IDENTIFIER, node_id)
+                .as_ref(),
+        )
+    }
+
+    fn set_value(&self, node_id: u32, value: &V) {
+        storage_set(
+            self.build_node_id_named_key(NODE_VALUE_IDENTIFIER, node_id)
+                .as_ref(),
+            value,
+        );
+    }
+
+    fn clear_node_entries(&self, node_id: u32) {
+        storage_clear(
+            self.build_node_id_named_key(NODE_LINKS_IDENTIFIER, node_id)
+                .as_ref(),
+        );
+        storage_clear(
+            self.build_node_id_named_key(NODE_KEY_IDENTIFIER, node_id)
+                .as_ref(),
+        );
+        storage_clear(
+            self.build_node_id_named_key(NODE_VALUE_IDENTIFIER, node_id)
+                .as_ref(),
+        );
+    }
+
+    fn get_node_id(&self, key: &K) -> u32 {
+        storage_get(self.build_key_named_key(NODE_ID_IDENTIFIER, key).as_ref())
+    }
+
+    fn set_node_id(&self, key: &K, node_id: u32) {
+        storage_set(
+            self.build_key_named_key(NODE_ID_IDENTIFIER, key).as_ref(),
+            &node_id,
+        );
+    }
+
+    fn clear_node_id(&self, key: &K) {
+        storage_clear(self.build_key_named_key(NODE_ID_IDENTIFIER, key).as_ref());
+    }
+
+    fn height(&self, node_id: u32) -> u32 {
+        if node_id == NULL_ENTRY {
+            return 0;
+        }
+        self.get_node(node_id).height
+    }
+
+    fn set_parent(&self, node_id: u32, parent_id: u32) {
+        if node_id == NULL_ENTRY {
+            return;
+        }
+        let mut node = self.get_node(node_id);
+        node.parent = parent_id;
+        self.set_node(node_id, &node);
+    }
+
+    /// Makes the parent point to the new child instead of the old one.
+    /// A null parent means that the old child was the root.
+    fn replace_child(
+        &self,
+        info: &mut OrderedMapMapperInfo,
+        parent_id: u32,
+        old_child_id: u32,
+        new_child_id: u32,
+    ) {
+        if parent_id == NULL_ENTRY {
+            info.root = new_child_id;
+            return;
+        }
+        let mut parent = self.get_node(parent_id);
+        if parent.left == old_child_id {
+            parent.left = new_child_id;
+        } else {
+            parent.right = new_child_id;
+        }
+        self.set_node(parent_id, &parent);
+    }
+
+    /// Returns the node id of the new root of the subtree.
+    fn rotate_left(&self, info: &mut OrderedMapMapperInfo, node_id: u32) -> u32 {
+        let mut node = self.get_node(node_id);
+        let pivot_id = node.right;
+        let mut pivot = self.get_node(pivot_id);
+
+        node.right = pivot.left;
+        self.set_parent(pivot.left, node_id);
+        pivot.parent = node.parent;
+        self.replace_child(info, node.parent, node_id, pivot_id);
+        pivot.left = node_id;
+        node.parent = pivot_id;
+
+        node.height = 1 + self.height(node.left).max(self.height(node.right));
+        pivot.height = 1 + node.height.max(self.height(pivot.right));
+        self.set_node(node_id, &node);
+        self.set_node(pivot_id, &pivot);
+        pivot_id
+    }
+
+    /// Returns the node id of the new root of the subtree.
+    fn rotate_right(&self, info: &mut OrderedMapMapperInfo, node_id: u32) -> u32 {
+        let mut node = self.get_node(node_id);
+        let pivot_id = node.left;
+        let mut pivot = self.get_node(pivot_id);
+
+        node.left = pivot.right;
+        self.set_parent(pivot.right, node_id);
+        pivot.parent = node.parent;
+        self.replace_child(info, node.parent, node_id, pivot_id);
+        pivot.right = node_id;
+        node.parent = pivot_id;
+
+        node.height = 1 + self.height(node.left).max(self.height(node.right));
+        pivot.height = 1 + node.height.max(self.height(pivot.left));
+        self.set_node(node_id, &node);
+        self.set_node(pivot_id, &pivot);
+        pivot_id
+    }
+
+    /// Restores the heights and the balance of the nodes, from the given node up to the root.
+    ///
+    /// Stops early once a subtree keeps its height, since the nodes above it are not affected.
+    fn rebalance(&self, info: &mut OrderedMapMapperInfo, mut node_id: u32) {
+        while node_id != NULL_ENTRY {
+            let mut node = self.get_node(node_id);
+            let left_height = self.height(node.left);
+            let right_height = self.height(node.right);
+
+            let subtree_root_id = if left_height > right_height + 1 {
+                let left = self.get_node(node.left);
+                if self.height(left.left) < self.height(left.right) {
+                    self.rotate_left(info, node.left);
+                }
+                self.rotate_right(info, node_id)
+            } else if right_height > left_height + 1 {
+                let right = self.get_node(node.right);
+                if self.height(right.right) < self.height(right.left) {
+                    self.rotate_right(info, node.right);
+                }
+                self.rotate_left(info, node_id)
+            } else {
+                let height = 1 + left_height.max(right_height);
+                if height == node.height {
+                    return;
+                }
+                node.height = height;
+                self.set_node(node_id, &node);
+                node_id
+            };
+
+            node_id = self.get_node(subtree_root_id).parent;
+        }
+    }
+
+    fn leftmost(&self, mut node_id: u32) -> u32 {
+        loop {
+            let left = self.get_node(node_id).left;
+            if left == NULL_ENTRY {
+                return node_id;
+            }
+            node_id = left;
+        }
+    }
+
+    fn rightmost(&self, mut node_id: u32) -> u32 {
+        loop {
+            let right = self.get_node(node_id).right;
+            if right == NULL_ENTRY {
+                return node_id;
+            }
+            node_id = right;
+        }
+    }
+
+    fn first_node_id(&self) -> u32 {
+        let root = self.get_info().root;
+        if root == NULL_ENTRY {
+            return NULL_ENTRY;
+        }
+        self.leftmost(root)
+    }
+
+    fn last_node_id(&self) -> u32 {
+        let root = self.get_info().root;
+        if root == NULL_ENTRY {
+            return NULL_ENTRY;
+        }
+        self.rightmost(root)
+    }
+
+    /// The node that comes next in key order.
+    fn successor(&self, node_id: u32) -> u32 {
+        let node = self.get_node(node_id);
+        if node.right != NULL_ENTRY {
+            return self.leftmost(node.right);
+        }
+        let mut child_id = node_id;
+        let mut parent_id = node.parent;
+        while parent_id != NULL_ENTRY {
+            let parent = self.get_node(parent_id);
+            if parent.left == child_id {
+                return parent_id;
+            }
+            child_id = parent_id;
+            parent_id = parent.parent;
+        }
+        NULL_ENTRY
+    }
+
+    /// The node that comes before in key order.
+    fn predecessor(&self, node_id: u32) -> u32 {
+        let node = self.get_node(node_id);
+        if node.left != NULL_ENTRY {
+            return self.rightmost(node.left);
+        }
+        let mut child_id = node_id;
+        let mut parent_id = node.parent;
+        while parent_id != NULL_ENTRY {
+            let parent = self.get_node(parent_id);
+            if parent.right == child_id {
+                return parent_id;
+            }
+            child_id = parent_id;
+            parent_id = parent.parent;
+        }
+        NULL_ENTRY
+    }
+
+    /// The node with the smallest key that is greater than (or equal to, if `inclusive`) the given key.
+    fn ceiling_node_id(&self, key: &K, inclusive: bool) -> u32 {
+        if inclusive {
+            let node_id = self.get_node_id(key);
+            if node_id != NULL_ENTRY {
+                return node_id;
+            }
+        }
+
+        let mut result = NULL_ENTRY;
+        let mut node_id = self.get_info().root;
+        while node_id != NULL_ENTRY {
+            let node = self.get_node(node_id);
+            if self.get_key(node_id).cmp(key) == Ordering::Greater {
+                result = node_id;
+                node_id = node.left;
+            } else {
+                node_id = node.right;
+            }
+        }
+        result
+    }
+
+    /// The node with the largest key that is less than (or equal to, if `inclusive`) the given key.
+    fn floor_node_id(&self, key: &K, inclusive: bool) -> u32 {
+        if inclusive {
+            let node_id = self.get_node_id(key);
+            if node_id != NULL_ENTRY {
+                return node_id;
+            }
+        }
+
+        let mut result = NULL_ENTRY;
+        let mut node_id = self.get_info().root;
+        while node_id != NULL_ENTRY {
+            let node = self.get_node(node_id);
+            if self.get_key(node_id).cmp(key) == Ordering::Less {
+                result = node_id;
+                node_id = node.right;
+            } else {
+                node_id = node.left;
+            }
+        }
+        result
+    }
+
+    fn key_option(&self, node_id: u32) -> Option<K> {
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+        Some(self.get_key(node_id))
+    }
+
+    fn node_ids<R: RangeBounds<K>>(&self, range: R) -> NodeIds<SA, K, V> {
+        let front = match range.start_bound() {
+            Bound::Included(key) => self.ceiling_node_id(key, true),
+            Bound::Excluded(key) => self.ceiling_node_id(key, false),
+            Bound::Unbounded => self.first_node_id(),
+        };
+        let back = match range.end_bound() {
+            Bound::Included(key) => self.floor_node_id(key, true),
+            Bound::Excluded(key) => self.floor_node_id(key, false),
+            Bound::Unbounded => self.last_node_id(),
+        };
+
+        if front == NULL_ENTRY
+            || back == NULL_ENTRY
+            || (front != back && self.get_key(front) > self.get_key(back))
+        {
+            return NodeIds::new(self, NULL_ENTRY, NULL_ENTRY);
+        }
+        NodeIds::new(self, front, back)
+    }
+
+    /// Removes the node holding the given key from the tree, together with all its storage entries.
+    fn remove_node(&self, node_id: u32, key: &K) {
+        let mut info = self.get_info();
+        self.clear_node_id(key);
+
+        let node = self.get_node(node_id);
+        let mut removed_id = node_id;
+        let mut removed = node;
+        if node.left != NULL_ENTRY && node.right != NULL_ENTRY {
+            // the successor has no left child, so it is the one that is unlinked,
+            // after moving its entry in place of the removed one
+            let successor_id = self.leftmost(node.right);
+            let successor_key = self.get_key(successor_id);
+            let successor_value = self.get_value(successor_id);
+            self.set_key(node_id, &successor_key);
+            self.set_value(node_id, &successor_value);
+            self.set_node_id(&successor_key, node_id);
+
+            removed_id = successor_id;
+            removed = self.get_node(successor_id);
+        }
+
+        let child_id = if removed.left != NULL_ENTRY {
+            removed.left
+        } else {
+            removed.right
+        };
+        self.set_parent(child_id, removed.parent);
+        self.replace_child(&mut info, removed.parent, removed_id, child_id);
+        self.clear_node_entries(removed_id);
+
+        info.len -= 1;
+        self.rebalance(&mut info, removed.parent);
+        self.set_info(info);
+    }
+
+    /// Returns `true` if the map contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.get_info().len == 0
+    }
+
+    /// Returns the number of elements in the map.
+    pub fn len(&self) -> usize {
+        self.get_info().len as usize
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get_node_id(key) != NULL_ENTRY
+    }
+
+    /// Gets the value corresponding to the key.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn get(&self, key: &K) -> Option<V> {
+        let node_id = self.get_node_id(key);
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+        Some(self.get_value(node_id))
+    }
+
+    /// Sets the value of the entry, and returns the entry's old value.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let existing_node_id = self.get_node_id(&key);
+        if existing_node_id != NULL_ENTRY {
+            let old_value = self.get_value(existing_node_id);
+            self.set_value(existing_node_id, &value);
+            return Some(old_value);
+        }
+
+        let mut info = self.get_info();
+        let mut parent_id = NULL_ENTRY;
+        let mut parent = None;
+        let mut is_left_child = false;
+        let mut node_id = info.root;
+        while node_id != NULL_ENTRY {
+            let node = self.get_node(node_id);
+            is_left_child = key < self.get_key(node_id);
+            parent_id = node_id;
+            node_id = if is_left_child { node.left } else { node.right };
+            parent = Some(node);
+        }
+
+        let new_node_id = info.generate_new_node_id();
+        self.set_node(
+            new_node_id,
+            &TreeNode {
+                parent: parent_id,
+                left: NULL_ENTRY,
+                right: NULL_ENTRY,
+                height: 1,
+            },
+        );
+        self.set_key(new_node_id, &key);
+        self.set_value(new_node_id, &value);
+        self.set_node_id(&key, new_node_id);
+
+        if let Some(mut parent) = parent {
+            if is_left_child {
+                parent.left = new_node_id;
+            } else {
+                parent.right = new_node_id;
+            }
+            self.set_node(parent_id, &parent);
+        } else {
+            info.root = new_node_id;
+        }
+
+        info.len += 1;
+        self.rebalance(&mut info, parent_id);
+        self.set_info(info);
+        None
+    }
+
+    /// Takes the value out of the entry, and returns it.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let node_id = self.get_node_id(key);
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+        let value = self.get_value(node_id);
+        self.remove_node(node_id, key);
+        Some(value)
+    }
+
+    /// The smallest key in the map, or `None` if it is empty.
+    pub fn min_key(&self) -> Option<K> {
+        self.key_option(self.first_node_id())
+    }
+
+    /// The largest key in the map, or `None` if it is empty.
+    pub fn max_key(&self) -> Option<K> {
+        self.key_option(self.last_node_id())
+    }
+
+    /// Removes the entry with the smallest key and returns it, or `None` if the map is empty.
+    pub fn pop_min(&mut self) -> Option<(K, V)> {
+        let node_id = self.first_node_id();
+        self.pop_node(node_id)
+    }
+
+    /// Removes the entry with the largest key and returns it, or `None` if the map is empty.
+    pub fn pop_max(&mut self) -> Option<(K, V)> {
+        let node_id = self.last_node_id();
+        self.pop_node(node_id)
+    }
+
+    fn pop_node(&mut self, node_id: u32) -> Option<(K, V)> {
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+        let key = self.get_key(node_id);
+        let value = self.get_value(node_id);
+        self.remove_node(node_id, &key);
+        Some((key, value))
+    }
+
+    /// The smallest key that is greater than or equal to the given key.
+    pub fn lower_bound(&self, key: &K) -> Option<K> {
+        self.key_option(self.ceiling_node_id(key, true))
+    }
+
+    /// The smallest key that is strictly greater than the given key.
+    pub fn upper_bound(&self, key: &K) -> Option<K> {
+        self.key_option(self.ceiling_node_id(key, false))
+    }
+
+    /// An iterator over the key-value pairs with keys in the given range, in ascending key order.
+    /// It can also be reversed, to get the entries in descending key order.
+    ///
+    /// Finding the start and the end of the range takes *O*(log n) time,
+    /// after which each step takes *O*(1) time on average.
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Iter<SA, K, V> {
+        Iter::new(self.node_ids(range))
+    }
+
+    /// An iterator visiting all key-value pairs in ascending key order.
+    pub fn iter(&self) -> Iter<SA, K, V> {
+        self.range(..)
+    }
+
+    /// An iterator visiting all keys in ascending order.
+    pub fn keys(&self) -> Keys<SA, K, V> {
+        Keys::new(self.node_ids(..))
+    }
+
+    /// An iterator visiting all values, in ascending order of their keys.
+    pub fn values(&self) -> Values<SA, K, V> {
+        Values::new(self.node_ids(..))
+    }
+
+    /// Checks the links, the heights and the balance of all the tree nodes,
+    /// as well as the ordering of the keys and the key index.
+    /// Used for unit testing.
+    ///
+    /// This operation should compute in *O*(n) time.
+    pub fn check_internal_consistency(&self) -> bool {
+        let info = self.get_info();
+        let mut count = 0;
+        if self
+            .check_subtree_consistency(info.root, NULL_ENTRY, &mut count)
+            .is_none()
+        {
+            return false;
+        }
+        if count != info.len {
+            return false;
+        }
+
+        let mut previous_key: Option<K> = None;
+        for key in self.keys() {
+            if let Some(previous_key) = &previous_key {
+                if *previous_key >= key {
+                    return false;
+                }
+            }
+            previous_key = Some(key);
+        }
+        self.keys().rev().count() == info.len as usize
+    }
+
+    /// Returns the height of the subtree, or `None` if it is inconsistent.
+    fn check_subtree_consistency(
+        &self,
+        node_id: u32,
+        parent_id: u32,
+        count: &mut u32,
+    ) -> Option<u32> {
+        if node_id == NULL_ENTRY {
+            return Some(0);
+        }
+        let node = self.get_node(node_id);
+        if node.parent != parent_id || self.get_node_id(&self.get_key(node_id)) != node_id {
+            return None;
+        }
+        let left_height = self.check_subtree_consistency(node.left, node_id, count)?;
+        let right_height = self.check_subtree_consistency(node.right, node_id, count)?;
+        if left_height.abs_diff(right_height) > 1
+            || node.height != 1 + left_height.max(right_height)
+        {
+            return None;
+        }
+        *count += 1;
+        Some(node.height)
+    }
+}
+
+/// Walks the node ids in key order, from both ends. `front` and `back` are both inclusive.
+struct NodeIds<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    front: u32,
+    back: u32,
+    map: &'a OrderedMapMapper<SA, K, V>,
+}
+
+impl<'a, SA, K, V> NodeIds<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    fn new(map: &'a OrderedMapMapper<SA, K, V>, front: u32, back: u32) -> Self {
+        NodeIds { front, back, map }
+    }
+}
+
+impl<'a, SA, K, V> Iterator for NodeIds<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        let node_id = self.front;
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+        if node_id == self.back {
+            self.front = NULL_ENTRY;
+            self.back = NULL_ENTRY;
+        } else {
+            self.front = self.map.successor(node_id);
+        }
+        Some(node_id)
+    }
+}
+
+impl<'a, SA, K, V> DoubleEndedIterator for NodeIds<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    fn next_back(&mut self) -> Option<u32> {
+        let node_id = self.back;
+        if node_id == NULL_ENTRY {
+            return None;
+        }
+        if node_id == self.front {
+            self.front = NULL_ENTRY;
+            self.back = NULL_ENTRY;
+        } else {
+            self.back = self.map.predecessor(node_id);
+        }
+        Some(node_id)
+    }
+}
+
+/// An iterator over the entries of an `OrderedMapMapper`, in key order.
+///
+/// This `struct` is created by [`OrderedMapMapper::iter()`] and [`OrderedMapMapper::range()`].
+pub struct Iter<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    node_ids: NodeIds<'a, SA, K, V>,
+}
+
+impl<'a, SA, K, V> Iter<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    fn new(node_ids: NodeIds<'a, SA, K, V>) -> Self {
+        Iter { node_ids }
+    }
+
+    fn entry(&self, node_id: u32) -> (K, V) {
+        let map = self.node_ids.map;
+        (map.get_key(node_id), map.get_value(node_id))
+    }
+}
+
+impl<'a, SA, K, V> Iterator for Iter<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(K, V)> {
+        let node_id = self.node_ids.next()?;
+        Some(self.entry(node_id))
+    }
+}
+
+impl<'a, SA, K, V> DoubleEndedIterator for Iter<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<(K, V)> {
+        let node_id = self.node_ids.next_back()?;
+        Some(self.entry(node_id))
+    }
+}
+
+/// An iterator over the keys of an `OrderedMapMapper`, in ascending order.
+///
+/// This `struct` is created by [`OrderedMapMapper::keys()`].
+pub struct Keys<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    node_ids: NodeIds<'a, SA, K, V>,
+}
+
+impl<'a, SA, K, V> Keys<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    fn new(node_ids: NodeIds<'a, SA, K, V>) -> Self {
+        Keys { node_ids }
+    }
+}
+
+impl<'a, SA, K, V> Iterator for Keys<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    type Item = K;
+
+    #[inline]
+    fn next(&mut self) -> Option<K> {
+        let node_id = self.node_ids.next()?;
+        Some(self.node_ids.map.get_key(node_id))
+    }
+}
+
+impl<'a, SA, K, V> DoubleEndedIterator for Keys<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<K> {
+        let node_id = self.node_ids.next_back()?;
+        Some(self.node_ids.map.get_key(node_id))
+    }
+}
+
+/// An iterator over the values of an `OrderedMapMapper`, in ascending order of their keys.
+///
+/// This `struct` is created by [`OrderedMapMapper::values()`].
+pub struct Values<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    node_ids: NodeIds<'a, SA, K, V>,
+}
+
+impl<'a, SA, K, V> Values<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    fn new(node_ids: NodeIds<'a, SA, K, V>) -> Self {
+        Values { node_ids }
+    }
+}
+
+impl<'a, SA, K, V> Iterator for Values<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    type Item = V;
+
+    #[inline]
+    fn next(&mut self) -> Option<V> {
+        let node_id = self.node_ids.next()?;
+        Some(self.node_ids.map.get_value(node_id))
+    }
+}
+
+impl<'a, SA, K, V> DoubleEndedIterator for Values<'a, SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<V> {
+        let node_id = self.node_ids.next_back()?;
+        Some(self.node_ids.map.get_value(node_id))
+    }
+}
+
+/// Behaves like a MultiResultVec<MultiValue<K, V>> when an endpoint result,
+/// with the entries sorted by key.
+impl<SA, K, V> TopEncodeMulti for OrderedMapMapper<SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        let iter = self.iter().map(MultiValue2::<K, V>::from);
+        multi_encode_iter_or_handle_err(iter, output, h)
+    }
+}
+
+impl<SA, K, V> CodecFrom<OrderedMapMapper<SA, K, V>> for MultiValueEncoded<SA, MultiValue2<K, V>>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+}
+
+/// Behaves like a MultiResultVec<MultiValue<K, V>> when an endpoint result.
+impl<SA, K, V> TypeAbi for OrderedMapMapper<SA, K, V>
+where
+    SA: StorageMapperApi,
+    K: TopEncode + TopDecode + NestedEncode + NestedDecode + Ord + TypeAbi + 'static,
+    V: TopEncode + TopDecode + TypeAbi + 'static,
+{
+    fn type_name() -> TypeName {
+        MultiValueEncoded::<SA, MultiValue2<K, V>>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        K::provide_type_descriptions(accumulator);
+        V::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+}
@@ -0,0 +1,344 @@
+use core::marker::PhantomData;
+
+use super::{StorageClearable, StorageMapper};
+use crate::{
+    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    api::StorageMapperApi,
+    storage::{storage_clear, storage_get, storage_set, StorageKey},
+    types::{ManagedType, MultiValueEncoded},
+};
+use elrond_codec::{
+    multi_encode_iter_or_handle_err, multi_types::MultiValue2, CodecFrom, EncodeErrorHandler,
+    TopDecode, TopEncode, TopEncodeMulti, TopEncodeMultiOutput,
+};
+
+const LEN_IDENTIFIER: &[u8] = b".len";
+const PRIORITY_IDENTIFIER: &[u8] = b".priority";
+const VALUE_IDENTIFIER: &[u8] = b".value";
+
+/// A priority queue, where each value is pushed together with its priority.
+///
+/// The value with the greatest priority is always the first one to be popped.
+/// Values with equal priorities are popped in no particular order.
+///
+/// It is stored as a binary heap, so pushing and popping take *O*(log n) storage reads and writes.
+/// Indexes start from 1, just like in the `VecMapper`.
+pub struct PriorityQueueMapper<SA, P, V>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    _phantom_api: PhantomData<SA>,
+    base_key: StorageKey<SA>,
+    _phantom_priority: PhantomData<P>,
+    _phantom_value: PhantomData<V>,
+}
+
+impl<SA, P, V> StorageMapper<SA> for PriorityQueueMapper<SA, P, V>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    fn new(base_key: StorageKey<SA>) -> Self {
+        PriorityQueueMapper {
+            _phantom_api: PhantomData,
+            base_key,
+            _phantom_priority: PhantomData,
+            _phantom_value: PhantomData,
+        }
+    }
+}
+
+impl<SA, P, V> StorageClearable for PriorityQueueMapper<SA, P, V>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    fn clear(&mut self) {
+        for index in 1..=self.len() {
+            self.clear_entry(index);
+        }
+        self.set_len(0);
+    }
+}
+
+impl<SA, P, V> PriorityQueueMapper<SA, P, V>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + Ord,
+    V: TopEncode + TopDecode,
+{
+    fn build_name_key(&self, name: &[u8]) -> StorageKey<SA> {
+        let mut name_key = self.base_key.clone();
+        name_key.append_bytes(name);
+        name_key
+    }
+
+    fn build_index_named_key(&self, name: &[u8], index: usize) -> StorageKey<SA> {
+        let mut named_key = self.base_key.clone();
+        named_key.append_bytes(name);
+        named_key.append_item(&index);
+        named_key
+    }
+
+    fn set_len(&self, len: usize) {
+        storage_set(self.build_name_key(LEN_IDENTIFIER).as_ref(), &len);
+    }
+
+    fn get_priority(&self, index: usize) -> P {
+        storage_get(
+            self.build_index_named_key(PRIORITY_IDENTIFIER, index)
+                .as_ref(),
+        )
+    }
+
+    fn get_value(&self, index: usize) -> V {
+        storage_get(self.build_index_named_key(VALUE_IDENTIFIER, index).as_ref())
+    }
+
+    fn set_entry(&self, index: usize, priority: &P, value: &V) {
+        storage_set(
+            self.build_index_named_key(PRIORITY_IDENTIFIER, index)
+                .as_ref(),
+            priority,
+        );
+        storage_set(
+            self.build_index_named_key(VALUE_IDENTIFIER, index).as_ref(),
+            value,
+        );
+    }
+
+    fn clear_entry(&self, index: usize) {
+        storage_clear(
+            self.build_index_named_key(PRIORITY_IDENTIFIER, index)
+                .as_ref(),
+        );
+        storage_clear(self.build_index_named_key(VALUE_IDENTIFIER, index).as_ref());
+    }
+
+    /// Moves the entry from one index to another, the priority being already loaded.
+    fn move_entry(&self, from_index: usize, to_index: usize, priority: &P) {
+        let value = self.get_value(from_index);
+        self.set_entry(to_index, priority, &value);
+    }
+
+    /// Moves the entries with lower priorities one level down, starting from the empty slot at the given index.
+    /// Returns the index where the new entry should be saved.
+    fn sift_up(&self, mut index: usize, priority: &P) -> usize {
+        while index > 1 {
+            let parent_index = index / 2;
+            let parent_priority = self.get_priority(parent_index);
+            if &parent_priority >= priority {
+                break;
+            }
+            self.move_entry(parent_index, index, &parent_priority);
+            index = parent_index;
+        }
+        index
+    }
+
+    /// Moves the entries with higher priorities one level up, starting from the empty slot at the given index.
+    /// Returns the index where the new entry should be saved.
+    fn sift_down(&self, mut index: usize, len: usize, priority: &P) -> usize {
+        loop {
+            let mut child_index = 2 * index;
+            if child_index > len {
+                break;
+            }
+            let mut child_priority = self.get_priority(child_index);
+            if child_index < len {
+                let right_priority = self.get_priority(child_index + 1);
+                if right_priority > child_priority {
+                    child_index += 1;
+                    child_priority = right_priority;
+                }
+            }
+            if &child_priority <= priority {
+                break;
+            }
+            self.move_entry(child_index, index, &child_priority);
+            index = child_index;
+        }
+        index
+    }
+
+    /// Returns `true` if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of values in the queue.
+    pub fn len(&self) -> usize {
+        storage_get(self.build_name_key(LEN_IDENTIFIER).as_ref())
+    }
+
+    /// Adds a value to the queue.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn push(&mut self, priority: P, value: V) {
+        let len = self.len() + 1;
+        let index = self.sift_up(len, &priority);
+        self.set_entry(index, &priority, &value);
+        self.set_len(len);
+    }
+
+    /// The greatest priority in the queue, or `None` if it is empty.
+    pub fn peek_priority(&self) -> Option<P> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.get_priority(1))
+    }
+
+    /// The value with the greatest priority, together with its priority, or `None` if the queue is empty.
+    pub fn peek(&self) -> Option<(P, V)> {
+        if self.is_empty() {
+            return None;
+        }
+        Some((self.get_priority(1), self.get_value(1)))
+    }
+
+    /// Removes the value with the greatest priority and returns it, together with its priority,
+    /// or `None` if the queue is empty.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn pop(&mut self) -> Option<(P, V)> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        let top = (self.get_priority(1), self.get_value(1));
+
+        let last_priority = self.get_priority(len);
+        let last_value = self.get_value(len);
+        self.clear_entry(len);
+        let new_len = len - 1;
+        if new_len > 0 {
+            let index = self.sift_down(1, new_len, &last_priority);
+            self.set_entry(index, &last_priority, &last_value);
+        }
+        self.set_len(new_len);
+        Some(top)
+    }
+
+    /// Provides an iterator over the priorities and values, in storage order, not in priority order.
+    pub fn iter(&self) -> Iter<SA, P, V> {
+        Iter::new(self)
+    }
+
+    /// Checks that no value has a greater priority than its parent in the heap.
+    /// Used for unit testing.
+    ///
+    /// This operation should compute in *O*(n) time.
+    pub fn check_internal_consistency(&self) -> bool {
+        let len = self.len();
+        for index in 2..=len {
+            if self.get_priority(index) > self.get_priority(index / 2) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// An iterator over the elements of a `PriorityQueueMapper`, in storage order.
+///
+/// This `struct` is created by [`PriorityQueueMapper::iter()`]. See its
+/// documentation for more.
+pub struct Iter<'a, SA, P, V>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    index: usize,
+    len: usize,
+    queue: &'a PriorityQueueMapper<SA, P, V>,
+}
+
+impl<'a, SA, P, V> Iter<'a, SA, P, V>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    fn new(queue: &'a PriorityQueueMapper<SA, P, V>) -> Iter<'a, SA, P, V> {
+        Iter {
+            index: 1,
+            len: queue.len(),
+            queue,
+        }
+    }
+}
+
+impl<'a, SA, P, V> Iterator for Iter<'a, SA, P, V>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    type Item = (P, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<(P, V)> {
+        let current_index = self.index;
+        if current_index > self.len {
+            return None;
+        }
+        self.index += 1;
+        Some((
+            self.queue.get_priority(current_index),
+            self.queue.get_value(current_index),
+        ))
+    }
+}
+
+/// Behaves like a MultiResultVec<MultiValue<P, V>> when an endpoint result,
+/// with the entries in storage order.
+impl<SA, P, V> TopEncodeMulti for PriorityQueueMapper<SA, P, V>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+    fn multi_encode_or_handle_err<O, H>(&self, output: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeMultiOutput,
+        H: EncodeErrorHandler,
+    {
+        let iter = self.iter().map(MultiValue2::<P, V>::from);
+        multi_encode_iter_or_handle_err(iter, output, h)
+    }
+}
+
+impl<SA, P, V> CodecFrom<PriorityQueueMapper<SA, P, V>> for MultiValueEncoded<SA, MultiValue2<P, V>>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + Ord + 'static,
+    V: TopEncode + TopDecode + 'static,
+{
+}
+
+/// Behaves like a MultiResultVec<MultiValue<P, V>> when an endpoint result.
+impl<SA, P, V> TypeAbi for PriorityQueueMapper<SA, P, V>
+where
+    SA: StorageMapperApi,
+    P: TopEncode + TopDecode + Ord + TypeAbi + 'static,
+    V: TopEncode + TopDecode + TypeAbi + 'static,
+{
+    fn type_name() -> TypeName {
+        MultiValueEncoded::<SA, MultiValue2<P, V>>::type_name()
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        P::provide_type_descriptions(accumulator);
+        V::provide_type_descriptions(accumulator);
+    }
+
+    fn is_variadic() -> bool {
+        true
+    }
+}
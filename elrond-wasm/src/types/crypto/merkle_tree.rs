@@ -0,0 +1,131 @@
+use core::marker::PhantomData;
+
+use crate::{
+    api::CryptoApi,
+    contract_base::CryptoWrapper,
+    types::{ManagedBuffer, ManagedByteArray, ManagedVec},
+};
+
+pub const MERKLE_HASH_LEN: usize = 32;
+
+/// Prefixes the hashed data, so that leaves can never be mistaken for inner nodes.
+pub const MERKLE_LEAF_PREFIX: u8 = 0;
+pub const MERKLE_NODE_PREFIX: u8 = 1;
+
+pub type MerkleHash<A> = ManagedByteArray<A, MERKLE_HASH_LEN>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MerkleHashAlgorithm {
+    Keccak256,
+    Sha256,
+}
+
+/// Computes the hashes of binary Merkle trees, and verifies Merkle proofs.
+///
+/// Leaves are hashed as `hash(0x00 | data)`, inner nodes as `hash(0x01 | lesser child | greater child)`.
+/// Because the children are sorted, proofs only need to contain the sibling hashes, not their positions.
+///
+/// When a level has an odd number of nodes, the last one is moved up unchanged to the next level.
+pub struct MerkleTreeHasher<A>
+where
+    A: CryptoApi,
+{
+    algorithm: MerkleHashAlgorithm,
+    _phantom: PhantomData<A>,
+}
+
+impl<A> MerkleTreeHasher<A>
+where
+    A: CryptoApi,
+{
+    pub fn new(algorithm: MerkleHashAlgorithm) -> Self {
+        MerkleTreeHasher {
+            algorithm,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn keccak256() -> Self {
+        Self::new(MerkleHashAlgorithm::Keccak256)
+    }
+
+    pub fn sha256() -> Self {
+        Self::new(MerkleHashAlgorithm::Sha256)
+    }
+
+    fn hash(&self, data: &ManagedBuffer<A>) -> MerkleHash<A> {
+        let crypto = CryptoWrapper::<A>::new();
+        match self.algorithm {
+            MerkleHashAlgorithm::Keccak256 => crypto.keccak256(data),
+            MerkleHashAlgorithm::Sha256 => crypto.sha256(data),
+        }
+    }
+
+    pub fn hash_leaf(&self, data: &ManagedBuffer<A>) -> MerkleHash<A> {
+        let mut prefixed = ManagedBuffer::new_from_bytes(&[MERKLE_LEAF_PREFIX]);
+        prefixed.append(data);
+        self.hash(&prefixed)
+    }
+
+    pub fn hash_pair(&self, first: &MerkleHash<A>, second: &MerkleHash<A>) -> MerkleHash<A> {
+        let (lesser, greater) = if first.to_byte_array() <= second.to_byte_array() {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        let mut concatenated = ManagedBuffer::new_from_bytes(&[MERKLE_NODE_PREFIX]);
+        concatenated.append(lesser.as_managed_buffer());
+        concatenated.append(greater.as_managed_buffer());
+        self.hash(&concatenated)
+    }
+
+    /// Builds the tree level by level, starting from the hashed leaves.
+    ///
+    /// Returns `None` if there are no leaves.
+    pub fn root_from_leaves(
+        &self,
+        leaf_hashes: &ManagedVec<A, MerkleHash<A>>,
+    ) -> Option<MerkleHash<A>> {
+        if leaf_hashes.is_empty() {
+            return None;
+        }
+
+        let mut level = leaf_hashes.clone();
+        while level.len() > 1 {
+            let mut next_level = ManagedVec::new();
+            let mut i = 0;
+            while i + 1 < level.len() {
+                next_level.push(self.hash_pair(&level.get(i), &level.get(i + 1)));
+                i += 2;
+            }
+            if i < level.len() {
+                next_level.push((*level.get(i)).clone());
+            }
+            level = next_level;
+        }
+        Some((*level.get(0)).clone())
+    }
+
+    /// The root of the tree that contains the leaf, according to the proof.
+    pub fn root_from_proof(
+        &self,
+        leaf_hash: &MerkleHash<A>,
+        proof: &ManagedVec<A, MerkleHash<A>>,
+    ) -> MerkleHash<A> {
+        let mut current = leaf_hash.clone();
+        for sibling in proof.iter() {
+            current = self.hash_pair(&current, &sibling);
+        }
+        current
+    }
+
+    /// Checks that the leaf belongs to the tree with the given root.
+    pub fn verify_proof(
+        &self,
+        root: &MerkleHash<A>,
+        leaf_hash: &MerkleHash<A>,
+        proof: &ManagedVec<A, MerkleHash<A>>,
+    ) -> bool {
+        &self.root_from_proof(leaf_hash, proof) == root
+    }
+}
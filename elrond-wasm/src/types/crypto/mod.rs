@@ -1,3 +1,8 @@
+mod merkle_tree;
 mod message_hash_type;
 
+pub use merkle_tree::{
+    MerkleHash, MerkleHashAlgorithm, MerkleTreeHasher, MERKLE_HASH_LEN, MERKLE_LEAF_PREFIX,
+    MERKLE_NODE_PREFIX,
+};
 pub use message_hash_type::MessageHashType;
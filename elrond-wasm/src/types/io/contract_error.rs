@@ -0,0 +1,87 @@
+use alloc::vec::Vec;
+
+use crate::{
+    abi::ErrorAbi,
+    api::ManagedTypeApi,
+    contract_base::IntoSignalError,
+    formatter::{FormatBuffer, SCDisplay},
+    types::ManagedBufferCachedBuilder,
+};
+
+/// Signaled messages start with the error code, e.g. `E101: amount too low`.
+pub const CONTRACT_ERROR_CODE_PREFIX: &[u8] = b"E";
+pub const CONTRACT_ERROR_CODE_SEPARATOR: &[u8] = b": ";
+pub const CONTRACT_ERROR_PLACEHOLDER: &[u8] = b"{}";
+
+/// Implemented via `#[derive(ContractError)]`, for enums listing the errors that a contract can signal.
+///
+/// Each error has a stable code and a message template.
+/// The code is signaled together with the message, so clients can identify errors without relying on the message text.
+pub trait ContractError: Copy {
+    fn error_code(&self) -> u32;
+
+    /// Each `{}` in the template is replaced by an argument, when signaling the error.
+    fn error_message_template(&self) -> &'static str;
+
+    /// All the errors declared by the type, as they appear in the ABI.
+    fn error_abis() -> Vec<ErrorAbi>;
+}
+
+/// Builds the message signaled for a `ContractError`, filling in the template placeholders one by one.
+pub struct ContractErrorMessageBuilder<M: ManagedTypeApi> {
+    buffer: ManagedBufferCachedBuilder<M>,
+    remaining_template: &'static [u8],
+}
+
+impl<M: ManagedTypeApi> ContractErrorMessageBuilder<M> {
+    pub fn new<E: ContractError>(error: &E) -> Self {
+        let mut buffer = ManagedBufferCachedBuilder::new_from_slice(CONTRACT_ERROR_CODE_PREFIX);
+        buffer.append_display(&error.error_code());
+        buffer.append_bytes(CONTRACT_ERROR_CODE_SEPARATOR);
+        ContractErrorMessageBuilder {
+            buffer,
+            remaining_template: error.error_message_template().as_bytes(),
+        }
+    }
+
+    /// Replaces the next placeholder with the argument.
+    /// Arguments left without a placeholder are ignored.
+    pub fn append_arg<T: SCDisplay>(&mut self, arg: &T) {
+        if let Some(position) = find_placeholder(self.remaining_template) {
+            self.buffer
+                .append_bytes(&self.remaining_template[..position]);
+            self.buffer.append_display(arg);
+            self.remaining_template =
+                &self.remaining_template[position + CONTRACT_ERROR_PLACEHOLDER.len()..];
+        }
+    }
+
+    pub fn signal_error(mut self) -> ! {
+        self.buffer.append_bytes(self.remaining_template);
+        self.buffer
+            .into_managed_buffer()
+            .signal_error_with_message()
+    }
+}
+
+fn find_placeholder(template: &[u8]) -> Option<usize> {
+    template
+        .windows(CONTRACT_ERROR_PLACEHOLDER.len())
+        .position(|window| window == CONTRACT_ERROR_PLACEHOLDER)
+}
+
+/// Retrieves the code from the message of an error signaled via a `ContractError`.
+/// Returns `None` for all other error messages.
+pub fn parse_contract_error_code(message: &[u8]) -> Option<u32> {
+    let rest = message.strip_prefix(CONTRACT_ERROR_CODE_PREFIX)?;
+    let digits_len = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+    if digits_len == 0 || !rest[digits_len..].starts_with(CONTRACT_ERROR_CODE_SEPARATOR) {
+        return None;
+    }
+
+    let mut code = 0u32;
+    for digit in &rest[..digits_len] {
+        code = code.checked_mul(10)?.checked_add((digit - b'0') as u32)?;
+    }
+    Some(code)
+}
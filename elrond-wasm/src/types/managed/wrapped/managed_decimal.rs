@@ -0,0 +1,392 @@
+use core::{
+    cmp::Ordering,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use alloc::string::ToString;
+use elrond_codec::{
+    CodecFromSelf, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
+    NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+};
+
+use crate::{
+    abi::{TypeAbi, TypeContents, TypeDescription, TypeDescriptionContainer, TypeName},
+    api::ManagedTypeApi,
+    formatter::{FormatByteReceiver, SCDisplay},
+    types::BigUint,
+};
+
+use super::ManagedDecimalVar;
+
+/// Specifies what happens with the digits that do not fit in the result scale.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoundingMode {
+    /// Truncates the extra digits.
+    Down,
+    /// Rounds up whenever there are non-zero extra digits.
+    Up,
+    /// Rounds to the nearest value, halfway cases are rounded up.
+    HalfUp,
+}
+
+/// Fixed-point decimal number, with the number of decimals known at compile time.
+///
+/// It is stored as an integer number of units, e.g. with 18 decimals, `1.5` is stored as `1500000000000000000`.
+/// This also means that it is encoded exactly like the token amounts with the same number of decimals.
+///
+/// Multiplication and division truncate the extra decimals, use `checked_mul` and `checked_div` to control rounding.
+#[derive(Clone, Debug)]
+pub struct ManagedDecimal<M: ManagedTypeApi, const DECIMALS: usize> {
+    raw_units: BigUint<M>,
+}
+
+pub(crate) fn scaling_factor<M: ManagedTypeApi>(decimals: usize) -> BigUint<M> {
+    BigUint::from(10u32).pow(decimals as u32)
+}
+
+pub(crate) fn div_with_rounding<M: ManagedTypeApi>(
+    numerator: BigUint<M>,
+    denominator: &BigUint<M>,
+    rounding: RoundingMode,
+) -> BigUint<M> {
+    match rounding {
+        RoundingMode::Down => numerator / denominator,
+        RoundingMode::Up => (numerator + (denominator - 1u32)) / denominator,
+        RoundingMode::HalfUp => (numerator + (denominator / 2u32)) / denominator,
+    }
+}
+
+pub(crate) fn rescale_raw_units<M: ManagedTypeApi>(
+    raw_units: &BigUint<M>,
+    from_decimals: usize,
+    to_decimals: usize,
+    rounding: RoundingMode,
+) -> BigUint<M> {
+    if to_decimals >= from_decimals {
+        raw_units * &scaling_factor(to_decimals - from_decimals)
+    } else {
+        div_with_rounding(
+            raw_units.clone(),
+            &scaling_factor(from_decimals - to_decimals),
+            rounding,
+        )
+    }
+}
+
+/// Describes a decimal type as transparent over the backing number type.
+pub(crate) fn provide_decimal_type_description<TDC, T>(
+    accumulator: &mut TDC,
+    type_name: TypeName,
+    docs: &'static [&'static str],
+) where
+    TDC: TypeDescriptionContainer,
+    T: TypeAbi,
+{
+    if !accumulator.contains_type(&type_name) {
+        accumulator.insert(
+            type_name.clone(),
+            TypeDescription {
+                docs,
+                name: type_name,
+                contents: TypeContents::Transparent(T::type_name()),
+                version: None,
+            },
+        );
+    }
+}
+
+/// Formats as `<integer part>.<fractional part>`, with all the decimals, e.g. `1.500000`.
+pub(crate) fn format_decimal<M: ManagedTypeApi, F: FormatByteReceiver>(
+    raw_units: &BigUint<M>,
+    decimals: usize,
+    f: &mut F,
+) {
+    if decimals == 0 {
+        SCDisplay::fmt(raw_units, f);
+        return;
+    }
+
+    let factor = scaling_factor::<M>(decimals);
+    SCDisplay::fmt(&(raw_units / &factor), f);
+    f.append_bytes(b".");
+
+    let fractional_part = raw_units % &factor;
+    let mut num_digits = 0;
+    let mut remaining = fractional_part.clone();
+    while remaining > 0u32 {
+        remaining /= 10u32;
+        num_digits += 1;
+    }
+    for _ in num_digits..decimals {
+        f.append_bytes(b"0");
+    }
+    if num_digits > 0 {
+        SCDisplay::fmt(&fractional_part, f);
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> ManagedDecimal<M, DECIMALS> {
+    /// The argument is the value multiplied by `10^DECIMALS`.
+    pub fn from_raw_units(raw_units: BigUint<M>) -> Self {
+        ManagedDecimal { raw_units }
+    }
+
+    pub fn from_integer(integer: BigUint<M>) -> Self {
+        ManagedDecimal {
+            raw_units: integer * &scaling_factor(DECIMALS),
+        }
+    }
+
+    pub fn zero() -> Self {
+        ManagedDecimal {
+            raw_units: BigUint::zero(),
+        }
+    }
+
+    pub fn raw_units(&self) -> &BigUint<M> {
+        &self.raw_units
+    }
+
+    pub fn into_raw_units(self) -> BigUint<M> {
+        self.raw_units
+    }
+
+    pub fn scale(&self) -> usize {
+        DECIMALS
+    }
+
+    /// The integer part, without the decimals.
+    pub fn trunc(&self) -> BigUint<M> {
+        &self.raw_units / &scaling_factor(DECIMALS)
+    }
+
+    pub fn rescale<const NEW_DECIMALS: usize>(
+        &self,
+        rounding: RoundingMode,
+    ) -> ManagedDecimal<M, NEW_DECIMALS> {
+        ManagedDecimal::from_raw_units(rescale_raw_units(
+            &self.raw_units,
+            DECIMALS,
+            NEW_DECIMALS,
+            rounding,
+        ))
+    }
+
+    pub fn into_var(self) -> ManagedDecimalVar<M> {
+        ManagedDecimalVar::from_raw_units(self.raw_units, DECIMALS)
+    }
+
+    /// Never fails, since there is no upper bound. Provided for symmetry.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self::from_raw_units(&self.raw_units + &other.raw_units))
+    }
+
+    /// Fails if the result would be negative.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if self.raw_units < other.raw_units {
+            return None;
+        }
+        Some(Self::from_raw_units(&self.raw_units - &other.raw_units))
+    }
+
+    /// Never fails, since there is no upper bound. Provided for symmetry.
+    pub fn checked_mul(&self, other: &Self, rounding: RoundingMode) -> Option<Self> {
+        Some(Self::from_raw_units(div_with_rounding(
+            &self.raw_units * &other.raw_units,
+            &scaling_factor(DECIMALS),
+            rounding,
+        )))
+    }
+
+    /// Fails on division by zero.
+    pub fn checked_div(&self, other: &Self, rounding: RoundingMode) -> Option<Self> {
+        if other.raw_units == 0u32 {
+            return None;
+        }
+        Some(Self::from_raw_units(div_with_rounding(
+            &self.raw_units * &scaling_factor(DECIMALS),
+            &other.raw_units,
+            rounding,
+        )))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Default for ManagedDecimal<M, DECIMALS> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> PartialEq for ManagedDecimal<M, DECIMALS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw_units == other.raw_units
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Eq for ManagedDecimal<M, DECIMALS> {}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> PartialOrd for ManagedDecimal<M, DECIMALS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Ord for ManagedDecimal<M, DECIMALS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.raw_units.cmp(&other.raw_units)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Add for ManagedDecimal<M, DECIMALS> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_raw_units(self.raw_units + other.raw_units)
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Add<&'b ManagedDecimal<M, DECIMALS>>
+    for &'a ManagedDecimal<M, DECIMALS>
+{
+    type Output = ManagedDecimal<M, DECIMALS>;
+
+    fn add(self, other: &ManagedDecimal<M, DECIMALS>) -> ManagedDecimal<M, DECIMALS> {
+        ManagedDecimal::from_raw_units(&self.raw_units + &other.raw_units)
+    }
+}
+
+/// Signals an error if the result would be negative.
+impl<M: ManagedTypeApi, const DECIMALS: usize> Sub for ManagedDecimal<M, DECIMALS> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::from_raw_units(self.raw_units - other.raw_units)
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Sub<&'b ManagedDecimal<M, DECIMALS>>
+    for &'a ManagedDecimal<M, DECIMALS>
+{
+    type Output = ManagedDecimal<M, DECIMALS>;
+
+    fn sub(self, other: &ManagedDecimal<M, DECIMALS>) -> ManagedDecimal<M, DECIMALS> {
+        ManagedDecimal::from_raw_units(&self.raw_units - &other.raw_units)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Mul for ManagedDecimal<M, DECIMALS> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        &self * &other
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Mul<&'b ManagedDecimal<M, DECIMALS>>
+    for &'a ManagedDecimal<M, DECIMALS>
+{
+    type Output = ManagedDecimal<M, DECIMALS>;
+
+    fn mul(self, other: &ManagedDecimal<M, DECIMALS>) -> ManagedDecimal<M, DECIMALS> {
+        ManagedDecimal::from_raw_units(
+            &self.raw_units * &other.raw_units / &scaling_factor(DECIMALS),
+        )
+    }
+}
+
+/// Signals an error on division by zero.
+impl<M: ManagedTypeApi, const DECIMALS: usize> Div for ManagedDecimal<M, DECIMALS> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        &self / &other
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Div<&'b ManagedDecimal<M, DECIMALS>>
+    for &'a ManagedDecimal<M, DECIMALS>
+{
+    type Output = ManagedDecimal<M, DECIMALS>;
+
+    fn div(self, other: &ManagedDecimal<M, DECIMALS>) -> ManagedDecimal<M, DECIMALS> {
+        ManagedDecimal::from_raw_units(
+            &self.raw_units * &scaling_factor(DECIMALS) / &other.raw_units,
+        )
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> TopEncode for ManagedDecimal<M, DECIMALS> {
+    #[inline]
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.raw_units.top_encode_or_handle_err(output, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> TopDecode for ManagedDecimal<M, DECIMALS> {
+    #[inline]
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(Self::from_raw_units(BigUint::top_decode_or_handle_err(
+            input, h,
+        )?))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> NestedEncode for ManagedDecimal<M, DECIMALS> {
+    #[inline]
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.raw_units.dep_encode_or_handle_err(dest, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> NestedDecode for ManagedDecimal<M, DECIMALS> {
+    #[inline]
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(Self::from_raw_units(BigUint::dep_decode_or_handle_err(
+            input, h,
+        )?))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> CodecFromSelf for ManagedDecimal<M, DECIMALS> {}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> TypeAbi for ManagedDecimal<M, DECIMALS> {
+    fn type_name() -> TypeName {
+        let mut repr = TypeName::from("ManagedDecimal<");
+        repr.push_str(DECIMALS.to_string().as_str());
+        repr.push('>');
+        repr
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        provide_decimal_type_description::<TDC, BigUint<M>>(
+            accumulator,
+            Self::type_name(),
+            &[
+                "Unsigned fixed-point decimal number, with the number of decimals given in the type name.",
+                "Encoded as the value multiplied by 10 to the power of the number of decimals.",
+            ],
+        );
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> SCDisplay for ManagedDecimal<M, DECIMALS> {
+    fn fmt<F: FormatByteReceiver>(&self, f: &mut F) {
+        format_decimal(&self.raw_units, DECIMALS, f);
+    }
+}
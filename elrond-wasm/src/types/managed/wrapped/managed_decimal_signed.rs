@@ -0,0 +1,344 @@
+use core::{
+    cmp::Ordering,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use alloc::string::ToString;
+use elrond_codec::{
+    CodecFromSelf, DecodeErrorHandler, EncodeErrorHandler, NestedDecode, NestedDecodeInput,
+    NestedEncode, NestedEncodeOutput, TopDecode, TopDecodeInput, TopEncode, TopEncodeOutput,
+};
+
+use crate::{
+    abi::{TypeAbi, TypeDescriptionContainer, TypeName},
+    api::ManagedTypeApi,
+    formatter::{FormatByteReceiver, SCDisplay},
+    types::{BigInt, BigUint, Sign},
+};
+
+use super::{
+    managed_decimal::{
+        div_with_rounding, format_decimal, provide_decimal_type_description, rescale_raw_units,
+        scaling_factor,
+    },
+    ManagedDecimal, RoundingMode,
+};
+
+/// Signed fixed-point decimal number, with the number of decimals known at compile time.
+///
+/// Same as `ManagedDecimal`, but backed by a `BigInt`, so it is encoded exactly like a `BigInt` holding the units.
+///
+/// Rounding applies to the absolute value, i.e. `RoundingMode::Down` rounds towards zero,
+/// and `RoundingMode::Up` away from zero.
+#[derive(Clone, Debug)]
+pub struct ManagedDecimalSigned<M: ManagedTypeApi, const DECIMALS: usize> {
+    raw_units: BigInt<M>,
+}
+
+fn with_sign<M: ManagedTypeApi>(negative: bool, magnitude: BigUint<M>) -> BigInt<M> {
+    let sign = if negative { Sign::Minus } else { Sign::Plus };
+    BigInt::from_biguint(sign, magnitude)
+}
+
+fn signed_div_with_rounding<M: ManagedTypeApi>(
+    numerator: BigInt<M>,
+    denominator: &BigInt<M>,
+    rounding: RoundingMode,
+) -> BigInt<M> {
+    let negative = numerator.sign().is_minus() != denominator.sign().is_minus();
+    with_sign(
+        negative,
+        div_with_rounding(numerator.magnitude(), &denominator.magnitude(), rounding),
+    )
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> ManagedDecimalSigned<M, DECIMALS> {
+    /// The argument is the value multiplied by `10^DECIMALS`.
+    pub fn from_raw_units(raw_units: BigInt<M>) -> Self {
+        ManagedDecimalSigned { raw_units }
+    }
+
+    pub fn from_integer(integer: BigInt<M>) -> Self {
+        ManagedDecimalSigned {
+            raw_units: integer * scaling_factor::<M>(DECIMALS),
+        }
+    }
+
+    pub fn zero() -> Self {
+        ManagedDecimalSigned {
+            raw_units: BigInt::zero(),
+        }
+    }
+
+    pub fn raw_units(&self) -> &BigInt<M> {
+        &self.raw_units
+    }
+
+    pub fn into_raw_units(self) -> BigInt<M> {
+        self.raw_units
+    }
+
+    pub fn scale(&self) -> usize {
+        DECIMALS
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.raw_units.sign().is_minus()
+    }
+
+    /// The absolute value.
+    pub fn magnitude(&self) -> ManagedDecimal<M, DECIMALS> {
+        ManagedDecimal::from_raw_units(self.raw_units.magnitude())
+    }
+
+    /// The integer part, without the decimals. Rounds towards zero.
+    pub fn trunc(&self) -> BigInt<M> {
+        &self.raw_units / &scaling_factor::<M>(DECIMALS)
+    }
+
+    pub fn rescale<const NEW_DECIMALS: usize>(
+        &self,
+        rounding: RoundingMode,
+    ) -> ManagedDecimalSigned<M, NEW_DECIMALS> {
+        ManagedDecimalSigned::from_raw_units(with_sign(
+            self.is_negative(),
+            rescale_raw_units(
+                &self.raw_units.magnitude(),
+                DECIMALS,
+                NEW_DECIMALS,
+                rounding,
+            ),
+        ))
+    }
+
+    /// Never fails, since there are no bounds. Provided for symmetry.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self::from_raw_units(&self.raw_units + &other.raw_units))
+    }
+
+    /// Never fails, since there are no bounds. Provided for symmetry.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(Self::from_raw_units(&self.raw_units - &other.raw_units))
+    }
+
+    /// Never fails, since there are no bounds. Provided for symmetry.
+    pub fn checked_mul(&self, other: &Self, rounding: RoundingMode) -> Option<Self> {
+        Some(Self::from_raw_units(signed_div_with_rounding(
+            &self.raw_units * &other.raw_units,
+            &BigInt::from(scaling_factor::<M>(DECIMALS)),
+            rounding,
+        )))
+    }
+
+    /// Fails on division by zero.
+    pub fn checked_div(&self, other: &Self, rounding: RoundingMode) -> Option<Self> {
+        if other.raw_units == 0 {
+            return None;
+        }
+        Some(Self::from_raw_units(signed_div_with_rounding(
+            &self.raw_units * &scaling_factor::<M>(DECIMALS),
+            &other.raw_units,
+            rounding,
+        )))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> From<ManagedDecimal<M, DECIMALS>>
+    for ManagedDecimalSigned<M, DECIMALS>
+{
+    fn from(value: ManagedDecimal<M, DECIMALS>) -> Self {
+        Self::from_raw_units(BigInt::from(value.into_raw_units()))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Default for ManagedDecimalSigned<M, DECIMALS> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> PartialEq for ManagedDecimalSigned<M, DECIMALS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw_units == other.raw_units
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Eq for ManagedDecimalSigned<M, DECIMALS> {}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> PartialOrd for ManagedDecimalSigned<M, DECIMALS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Ord for ManagedDecimalSigned<M, DECIMALS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.raw_units.cmp(&other.raw_units)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Neg for ManagedDecimalSigned<M, DECIMALS> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_raw_units(-self.raw_units)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Add for ManagedDecimalSigned<M, DECIMALS> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_raw_units(self.raw_units + other.raw_units)
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Add<&'b ManagedDecimalSigned<M, DECIMALS>>
+    for &'a ManagedDecimalSigned<M, DECIMALS>
+{
+    type Output = ManagedDecimalSigned<M, DECIMALS>;
+
+    fn add(self, other: &ManagedDecimalSigned<M, DECIMALS>) -> ManagedDecimalSigned<M, DECIMALS> {
+        ManagedDecimalSigned::from_raw_units(&self.raw_units + &other.raw_units)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Sub for ManagedDecimalSigned<M, DECIMALS> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::from_raw_units(self.raw_units - other.raw_units)
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Sub<&'b ManagedDecimalSigned<M, DECIMALS>>
+    for &'a ManagedDecimalSigned<M, DECIMALS>
+{
+    type Output = ManagedDecimalSigned<M, DECIMALS>;
+
+    fn sub(self, other: &ManagedDecimalSigned<M, DECIMALS>) -> ManagedDecimalSigned<M, DECIMALS> {
+        ManagedDecimalSigned::from_raw_units(&self.raw_units - &other.raw_units)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> Mul for ManagedDecimalSigned<M, DECIMALS> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        &self * &other
+    }
+}
+
+/// Truncates towards zero.
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Mul<&'b ManagedDecimalSigned<M, DECIMALS>>
+    for &'a ManagedDecimalSigned<M, DECIMALS>
+{
+    type Output = ManagedDecimalSigned<M, DECIMALS>;
+
+    fn mul(self, other: &ManagedDecimalSigned<M, DECIMALS>) -> ManagedDecimalSigned<M, DECIMALS> {
+        ManagedDecimalSigned::from_raw_units(
+            &self.raw_units * &other.raw_units / scaling_factor::<M>(DECIMALS),
+        )
+    }
+}
+
+/// Signals an error on division by zero.
+impl<M: ManagedTypeApi, const DECIMALS: usize> Div for ManagedDecimalSigned<M, DECIMALS> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        &self / &other
+    }
+}
+
+/// Truncates towards zero.
+impl<'a, 'b, M: ManagedTypeApi, const DECIMALS: usize> Div<&'b ManagedDecimalSigned<M, DECIMALS>>
+    for &'a ManagedDecimalSigned<M, DECIMALS>
+{
+    type Output = ManagedDecimalSigned<M, DECIMALS>;
+
+    fn div(self, other: &ManagedDecimalSigned<M, DECIMALS>) -> ManagedDecimalSigned<M, DECIMALS> {
+        ManagedDecimalSigned::from_raw_units(
+            &(&self.raw_units * &scaling_factor::<M>(DECIMALS)) / &other.raw_units,
+        )
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> TopEncode for ManagedDecimalSigned<M, DECIMALS> {
+    #[inline]
+    fn top_encode_or_handle_err<O, H>(&self, output: O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: TopEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.raw_units.top_encode_or_handle_err(output, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> TopDecode for ManagedDecimalSigned<M, DECIMALS> {
+    #[inline]
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: TopDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(Self::from_raw_units(BigInt::top_decode_or_handle_err(
+            input, h,
+        )?))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> NestedEncode for ManagedDecimalSigned<M, DECIMALS> {
+    #[inline]
+    fn dep_encode_or_handle_err<O, H>(&self, dest: &mut O, h: H) -> Result<(), H::HandledErr>
+    where
+        O: NestedEncodeOutput,
+        H: EncodeErrorHandler,
+    {
+        self.raw_units.dep_encode_or_handle_err(dest, h)
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> NestedDecode for ManagedDecimalSigned<M, DECIMALS> {
+    #[inline]
+    fn dep_decode_or_handle_err<I, H>(input: &mut I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: NestedDecodeInput,
+        H: DecodeErrorHandler,
+    {
+        Ok(Self::from_raw_units(BigInt::dep_decode_or_handle_err(
+            input, h,
+        )?))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> CodecFromSelf for ManagedDecimalSigned<M, DECIMALS> {}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> TypeAbi for ManagedDecimalSigned<M, DECIMALS> {
+    fn type_name() -> TypeName {
+        let mut repr = TypeName::from("ManagedDecimalSigned<");
+        repr.push_str(DECIMALS.to_string().as_str());
+        repr.push('>');
+        repr
+    }
+
+    fn provide_type_descriptions<TDC: TypeDescriptionContainer>(accumulator: &mut TDC) {
+        provide_decimal_type_description::<TDC, BigInt<M>>(
+            accumulator,
+            Self::type_name(),
+            &[
+                "Signed fixed-point decimal number, with the number of decimals given in the type name.",
+                "Encoded as the value multiplied by 10 to the power of the number of decimals.",
+            ],
+        );
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> SCDisplay for ManagedDecimalSigned<M, DECIMALS> {
+    fn fmt<F: FormatByteReceiver>(&self, f: &mut F) {
+        if self.is_negative() {
+            f.append_bytes(b"-");
+        }
+        format_decimal(&self.raw_units.magnitude(), DECIMALS, f);
+    }
+}
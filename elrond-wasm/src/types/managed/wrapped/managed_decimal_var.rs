@@ -0,0 +1,229 @@
+use core::{
+    cmp::Ordering,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use crate::{
+    api::ManagedTypeApi,
+    formatter::{FormatByteReceiver, SCDisplay},
+    types::BigUint,
+};
+
+use super::{
+    managed_decimal::{div_with_rounding, format_decimal, rescale_raw_units, scaling_factor},
+    ManagedDecimal, RoundingMode,
+};
+
+use crate as elrond_wasm; // needed by the codec and TypeAbi generated code
+use crate::derive::TypeAbi;
+use elrond_codec::elrond_codec_derive::{NestedDecode, NestedEncode, TopDecode, TopEncode};
+
+/// Fixed-point decimal number, with the number of decimals only known at runtime,
+/// e.g. when working with the decimals of arbitrary tokens.
+///
+/// The number of decimals is serialized together with the value.
+///
+/// Addition and subtraction yield a result with the larger of the 2 scales, so they are always exact.
+/// Multiplication and division keep the scale of the left operand and truncate the extra decimals,
+/// use `checked_mul` and `checked_div` to control rounding.
+#[derive(TopEncode, NestedEncode, TopDecode, NestedDecode, TypeAbi, Clone, Debug)]
+pub struct ManagedDecimalVar<M: ManagedTypeApi> {
+    raw_units: BigUint<M>,
+    decimals: usize,
+}
+
+impl<M: ManagedTypeApi> ManagedDecimalVar<M> {
+    /// The argument is the value multiplied by `10^decimals`.
+    pub fn from_raw_units(raw_units: BigUint<M>, decimals: usize) -> Self {
+        ManagedDecimalVar {
+            raw_units,
+            decimals,
+        }
+    }
+
+    pub fn from_integer(integer: BigUint<M>, decimals: usize) -> Self {
+        ManagedDecimalVar {
+            raw_units: integer * &scaling_factor(decimals),
+            decimals,
+        }
+    }
+
+    pub fn raw_units(&self) -> &BigUint<M> {
+        &self.raw_units
+    }
+
+    pub fn into_raw_units(self) -> BigUint<M> {
+        self.raw_units
+    }
+
+    pub fn scale(&self) -> usize {
+        self.decimals
+    }
+
+    /// The integer part, without the decimals.
+    pub fn trunc(&self) -> BigUint<M> {
+        &self.raw_units / &scaling_factor(self.decimals)
+    }
+
+    pub fn rescale(&self, decimals: usize, rounding: RoundingMode) -> Self {
+        ManagedDecimalVar::from_raw_units(
+            rescale_raw_units(&self.raw_units, self.decimals, decimals, rounding),
+            decimals,
+        )
+    }
+
+    pub fn to_const<const DECIMALS: usize>(
+        &self,
+        rounding: RoundingMode,
+    ) -> ManagedDecimal<M, DECIMALS> {
+        ManagedDecimal::from_raw_units(rescale_raw_units(
+            &self.raw_units,
+            self.decimals,
+            DECIMALS,
+            rounding,
+        ))
+    }
+
+    /// Upscaling is always exact, so no rounding is needed.
+    fn raw_units_upscaled(&self, decimals: usize) -> BigUint<M> {
+        rescale_raw_units(&self.raw_units, self.decimals, decimals, RoundingMode::Down)
+    }
+
+    /// Never fails, since there is no upper bound. Provided for symmetry.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let decimals = self.decimals.max(other.decimals);
+        Some(Self::from_raw_units(
+            self.raw_units_upscaled(decimals) + other.raw_units_upscaled(decimals),
+            decimals,
+        ))
+    }
+
+    /// Fails if the result would be negative.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        let decimals = self.decimals.max(other.decimals);
+        let self_raw_units = self.raw_units_upscaled(decimals);
+        let other_raw_units = other.raw_units_upscaled(decimals);
+        if self_raw_units < other_raw_units {
+            return None;
+        }
+        Some(Self::from_raw_units(
+            self_raw_units - other_raw_units,
+            decimals,
+        ))
+    }
+
+    /// Never fails, since there is no upper bound. Provided for symmetry.
+    pub fn checked_mul(&self, other: &Self, rounding: RoundingMode) -> Option<Self> {
+        Some(Self::from_raw_units(
+            div_with_rounding(
+                &self.raw_units * &other.raw_units,
+                &scaling_factor(other.decimals),
+                rounding,
+            ),
+            self.decimals,
+        ))
+    }
+
+    /// Fails on division by zero.
+    pub fn checked_div(&self, other: &Self, rounding: RoundingMode) -> Option<Self> {
+        if other.raw_units == 0u32 {
+            return None;
+        }
+        Some(Self::from_raw_units(
+            div_with_rounding(
+                &self.raw_units * &scaling_factor(other.decimals),
+                &other.raw_units,
+                rounding,
+            ),
+            self.decimals,
+        ))
+    }
+}
+
+impl<M: ManagedTypeApi, const DECIMALS: usize> From<ManagedDecimal<M, DECIMALS>>
+    for ManagedDecimalVar<M>
+{
+    fn from(value: ManagedDecimal<M, DECIMALS>) -> Self {
+        value.into_var()
+    }
+}
+
+/// Values are compared regardless of scale, e.g. `1.5 == 1.50`.
+impl<M: ManagedTypeApi> PartialEq for ManagedDecimalVar<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<M: ManagedTypeApi> Eq for ManagedDecimalVar<M> {}
+
+impl<M: ManagedTypeApi> PartialOrd for ManagedDecimalVar<M> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<M: ManagedTypeApi> Ord for ManagedDecimalVar<M> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.decimals == other.decimals {
+            return self.raw_units.cmp(&other.raw_units);
+        }
+        let decimals = self.decimals.max(other.decimals);
+        self.raw_units_upscaled(decimals)
+            .cmp(&other.raw_units_upscaled(decimals))
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi> Add<&'b ManagedDecimalVar<M>> for &'a ManagedDecimalVar<M> {
+    type Output = ManagedDecimalVar<M>;
+
+    fn add(self, other: &ManagedDecimalVar<M>) -> ManagedDecimalVar<M> {
+        let decimals = self.decimals.max(other.decimals);
+        ManagedDecimalVar::from_raw_units(
+            self.raw_units_upscaled(decimals) + other.raw_units_upscaled(decimals),
+            decimals,
+        )
+    }
+}
+
+/// Signals an error if the result would be negative.
+impl<'a, 'b, M: ManagedTypeApi> Sub<&'b ManagedDecimalVar<M>> for &'a ManagedDecimalVar<M> {
+    type Output = ManagedDecimalVar<M>;
+
+    fn sub(self, other: &ManagedDecimalVar<M>) -> ManagedDecimalVar<M> {
+        let decimals = self.decimals.max(other.decimals);
+        ManagedDecimalVar::from_raw_units(
+            self.raw_units_upscaled(decimals) - other.raw_units_upscaled(decimals),
+            decimals,
+        )
+    }
+}
+
+impl<'a, 'b, M: ManagedTypeApi> Mul<&'b ManagedDecimalVar<M>> for &'a ManagedDecimalVar<M> {
+    type Output = ManagedDecimalVar<M>;
+
+    fn mul(self, other: &ManagedDecimalVar<M>) -> ManagedDecimalVar<M> {
+        ManagedDecimalVar::from_raw_units(
+            &self.raw_units * &other.raw_units / &scaling_factor(other.decimals),
+            self.decimals,
+        )
+    }
+}
+
+/// Signals an error on division by zero.
+impl<'a, 'b, M: ManagedTypeApi> Div<&'b ManagedDecimalVar<M>> for &'a ManagedDecimalVar<M> {
+    type Output = ManagedDecimalVar<M>;
+
+    fn div(self, other: &ManagedDecimalVar<M>) -> ManagedDecimalVar<M> {
+        ManagedDecimalVar::from_raw_units(
+            &self.raw_units * &scaling_factor(other.decimals) / &other.raw_units,
+            self.decimals,
+        )
+    }
+}
+
+impl<M: ManagedTypeApi> SCDisplay for ManagedDecimalVar<M> {
+    fn fmt<F: FormatByteReceiver>(&self, f: &mut F) {
+        format_decimal(&self.raw_units, self.decimals, f);
+    }
+}